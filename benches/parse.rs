@@ -0,0 +1,45 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use libnmea0183::{Nmea0183, Nmea0183Ref};
+
+const SENTENCES: [&str; 4] = [
+    "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47",
+    "$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A",
+    "$GPGSV,3,1,11,03,03,111,00,04,15,270,00,06,01,010,00,13,06,292,00*74",
+    "!AIVDM,1,1,,A,13aEOK?P00PD2wVMdLDRhgvL289?,0*26",
+];
+
+fn owned(c: &mut Criterion) {
+    let lines: Vec<String> = SENTENCES.iter().map(|s| s.to_string()).collect();
+    c.bench_function("owned parse", |b| {
+        b.iter(|| {
+            for line in &lines {
+                black_box(Nmea0183::from_string(black_box(line)).ok());
+            }
+        })
+    });
+    c.bench_function("owned gga position", |b| {
+        b.iter(|| match Nmea0183::from_string(black_box(&lines[0])) {
+            Ok(Nmea0183::GGA(gga)) => black_box(gga.position().ok()),
+            _ => None,
+        })
+    });
+}
+
+fn borrowed(c: &mut Criterion) {
+    c.bench_function("borrowed parse", |b| {
+        b.iter(|| {
+            for line in SENTENCES {
+                black_box(Nmea0183Ref::parse(black_box(line)).ok());
+            }
+        })
+    });
+    c.bench_function("borrowed gga position", |b| {
+        b.iter(|| match Nmea0183Ref::parse(black_box(SENTENCES[0])) {
+            Ok(Nmea0183Ref::GGA(gga)) => black_box(gga.position().ok()),
+            _ => None,
+        })
+    });
+}
+
+criterion_group!(benches, owned, borrowed);
+criterion_main!(benches);
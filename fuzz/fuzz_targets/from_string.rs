@@ -0,0 +1,277 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use libnmea0183::{Nmea0183, Nmea0183Ref};
+
+fuzz_target!(|data: &[u8]| {
+    let line = String::from_utf8_lossy(data).to_string();
+    if let Ok(nmea) = Nmea0183::from_string(&line) {
+        let borrowed = Nmea0183Ref::parse(&line).expect("borrowed parse disagrees");
+        assert_eq!(nmea.to_sentence(), borrowed.to_sentence());
+        assert_eq!(nmea.to_sentence(), borrowed.into_owned().to_sentence());
+        match nmea {
+            Nmea0183::AAM(s) => {
+                let _ = s.arrival_status();
+                let _ = s.arrival_radius();
+                let _ = s.waypoint_id();
+            }
+            Nmea0183::APB(s) => {
+                let _ = s.cross_track_error();
+                let _ = s.arrival_status();
+                let _ = s.bearing_origin_to_destination();
+                let _ = s.destination_id();
+                let _ = s.bearing_to_destination();
+                let _ = s.heading_to_steer();
+            }
+            Nmea0183::BOD(s) => {
+                let _ = s.bearing_true();
+                let _ = s.bearing_magnetic();
+                let _ = s.destination_id();
+                let _ = s.origin_id();
+            }
+            Nmea0183::BWC(s) => {
+                let _ = s.timestamp();
+                let _ = s.waypoint_position();
+                let _ = s.bearing_true();
+                let _ = s.bearing_magnetic();
+                let _ = s.distance();
+                let _ = s.waypoint_id();
+            }
+            Nmea0183::BWR(s) => {
+                let _ = s.timestamp();
+                let _ = s.waypoint_position();
+                let _ = s.bearing_true();
+                let _ = s.bearing_magnetic();
+                let _ = s.distance();
+                let _ = s.waypoint_id();
+            }
+            Nmea0183::BWW(s) => {
+                let _ = s.bearing_true();
+                let _ = s.bearing_magnetic();
+                let _ = s.to_waypoint_id();
+                let _ = s.from_waypoint_id();
+            }
+            Nmea0183::DBS(s) => {
+                let _ = s.depth();
+                let _ = s.offset();
+                let _ = s.maximum_range();
+            }
+            Nmea0183::DBT(s) => {
+                let _ = s.depth();
+            }
+            Nmea0183::DPT(s) => {
+                let _ = s.depth();
+                let _ = s.offset();
+                let _ = s.maximum_range();
+            }
+            Nmea0183::DTM(s) => {
+                let _ = s.local_datum();
+                let _ = s.local_datum_subdivision();
+                let _ = s.latitude_offset();
+                let _ = s.longitude_offset();
+                let _ = s.altitude_offset();
+                let _ = s.reference_datum();
+            }
+            Nmea0183::GBS(s) => {
+                let _ = s.latitude_error();
+                let _ = s.failed_satellite();
+                let _ = s.probability_of_missed_detection();
+                let _ = s.bias_estimate();
+            }
+            Nmea0183::GGA(s) => {
+                let _ = s.timestamp();
+                let _ = s.position();
+                let _ = s.quality();
+                let _ = s.number_of_satellites();
+                let _ = s.hdop();
+                let _ = s.height();
+                let _ = s.geoid_separation();
+                let _ = s.differential_age();
+                let _ = s.reference_station();
+            }
+            Nmea0183::GLL(s) => {
+                let _ = s.position();
+                let _ = s.timestamp();
+                let _ = s.is_valid();
+                let _ = s.faa_mode();
+            }
+            Nmea0183::GNS(s) => {
+                let _ = s.position();
+                let _ = s.timestamp();
+                let _ = s.mode_indicators();
+                let _ = s.system_mode(255);
+                let _ = s.number_of_satellites();
+                let _ = s.hdop();
+                let _ = s.altitude();
+                let _ = s.geoid_separation();
+                let _ = s.differential_age();
+                let _ = s.reference_station();
+                let _ = s.nav_status();
+            }
+            Nmea0183::GRS(s) => {
+                let _ = s.mode();
+                let _ = s.residuals();
+            }
+            Nmea0183::GSA(s) => {
+                let _ = s.mode();
+                let _ = s.fix_type();
+                let _ = s.prn(11);
+                let _ = s.prns();
+                let _ = s.pdop();
+                let _ = s.hdop();
+                let _ = s.vdop();
+                let _ = s.system_id();
+            }
+            Nmea0183::GST(s) => {
+                let _ = s.rms();
+                let _ = s.semi_major_error();
+                let _ = s.orientation();
+                let _ = s.altitude_error();
+            }
+            Nmea0183::GSV(s) => {
+                let _ = s.sentence_total();
+                let _ = s.sentence_number();
+                let _ = s.number_of_satellites();
+                for n in 0..4 {
+                    let _ = s.satellite(n);
+                }
+            }
+            Nmea0183::GXA(s) => {
+                let _ = s.position();
+                let _ = s.waypoint_id();
+                let _ = s.satellite_number();
+            }
+            Nmea0183::HDG(s) => {
+                let _ = s.magnetic_heading();
+                let _ = s.magnetic_deviation();
+                let _ = s.magnetic_variation();
+            }
+            Nmea0183::HDM(s) => {
+                let _ = s.heading();
+            }
+            Nmea0183::HDT(s) => {
+                let _ = s.heading();
+            }
+            Nmea0183::MDA(s) => {
+                let _ = s.pressure();
+                let _ = s.air_temperature();
+                let _ = s.water_temperature();
+                let _ = s.relative_humidity();
+                let _ = s.absolute_humidity();
+                let _ = s.dew_point();
+                let _ = s.wind_direction_true();
+                let _ = s.wind_direction_magnetic();
+                let _ = s.wind_speed();
+            }
+            Nmea0183::MTW(s) => {
+                let _ = s.temperature();
+            }
+            Nmea0183::MWD(s) => {
+                let _ = s.direction_true();
+                let _ = s.direction_magnetic();
+                let _ = s.wind_speed();
+            }
+            Nmea0183::MWV(s) => {
+                let _ = s.angle_relative();
+                let _ = s.angle_true();
+                let _ = s.wind_speed();
+            }
+            Nmea0183::RMB(s) => {
+                let _ = s.cross_track_error();
+                let _ = s.destination_position();
+                let _ = s.range_to_destination();
+                let _ = s.bearing_to_destination();
+                let _ = s.closing_velocity();
+                let _ = s.arrived();
+            }
+            Nmea0183::RMC(s) => {
+                let _ = s.timestamp();
+                let _ = s.is_valid();
+                let _ = s.position();
+                let _ = s.sog();
+                let _ = s.track_made_good();
+                let _ = s.magnetic_variation();
+                let _ = s.faa_mode();
+                let _ = s.nav_status();
+            }
+            Nmea0183::ROT(s) => {
+                let _ = s.rate_of_turn();
+                let _ = s.is_valid();
+            }
+            Nmea0183::RSA(s) => {
+                let _ = s.starboard_rudder();
+                let _ = s.port_rudder();
+            }
+            Nmea0183::RTE(s) => {
+                let _ = s.sentence_total();
+                let _ = s.route_type();
+                let _ = s.route_id();
+                let _ = s.waypoints();
+            }
+            Nmea0183::TRF(s) => {
+                let _ = s.date();
+                let _ = s.position();
+                let _ = s.update_distance();
+                let _ = s.is_valid();
+            }
+            Nmea0183::VDM(s) => {
+                let _ = s.total_sentences();
+                let _ = s.sentence_number();
+                let _ = s.sentence_id();
+                let _ = s.channel();
+                let _ = s.message();
+                let _ = s.decode();
+            }
+            Nmea0183::VDO(s) => {
+                let _ = s.total_sentences();
+                let _ = s.sentence_number();
+                let _ = s.sentence_id();
+                let _ = s.channel();
+                let _ = s.message();
+                let _ = s.decode();
+            }
+            Nmea0183::VHW(s) => {
+                let _ = s.heading_true();
+                let _ = s.heading_magnetic();
+                let _ = s.water_speed();
+            }
+            Nmea0183::VLW(s) => {
+                let _ = s.cumulative_dtw();
+                let _ = s.dtw_since_reset();
+                let _ = s.dog_cumulative();
+                let _ = s.dog_since_reset();
+            }
+            Nmea0183::VTG(s) => {
+                let _ = s.cog_true();
+                let _ = s.cog_mag();
+                let _ = s.sog();
+                let _ = s.faa_mode();
+            }
+            Nmea0183::VWR(s) => {
+                let _ = s.wind_direction();
+            }
+            Nmea0183::VWT(s) => {
+                let _ = s.wind_direction();
+                let _ = s.wind_speed();
+            }
+            Nmea0183::WPL(s) => {
+                let _ = s.position();
+                let _ = s.waypoint_id();
+            }
+            Nmea0183::XDR(s) => {
+                for n in 0..4 {
+                    let _ = s.measurements::<f32>(n);
+                }
+            }
+            Nmea0183::XTE(s) => {
+                let _ = s.is_valid();
+                let _ = s.cross_track_error();
+            }
+            Nmea0183::ZDA(s) => {
+                let _ = s.timestamp();
+                let _ = s.local_time();
+            }
+            _ => {}
+        }
+    }
+});
@@ -0,0 +1,299 @@
+use crate::ais::payload::AisPayload;
+use crate::base::*;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+pub const MAX_FRAGMENTS: usize = 9;
+
+pub trait AisFragment {
+    fn total_sentences(&self) -> UsizeError;
+    fn sentence_number(&self) -> UsizeError;
+    fn sentence_id(&self) -> UsizeError;
+    fn channel(&self) -> Result<char, NmeaError>;
+    fn payload(&self) -> Result<AisPayload, NmeaError>;
+    fn is_own_vessel(&self) -> bool;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DropReason {
+    Timeout,
+    Superseded,
+    Inconsistent,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DroppedGroup {
+    pub own_vessel: bool,
+    pub sequence_id: Option<usize>,
+    pub channel: Option<char>,
+    pub received: usize,
+    pub total: usize,
+    pub reason: DropReason,
+}
+
+type GroupKey = (bool, Option<usize>, Option<char>);
+
+#[derive(Debug)]
+struct PartialMessage {
+    fragments: Vec<Option<AisPayload>>,
+    started: Instant,
+}
+
+impl PartialMessage {
+    fn received(&self) -> usize {
+        self.fragments.iter().filter(|f| f.is_some()).count()
+    }
+}
+
+#[derive(Debug)]
+pub struct AisAssembler {
+    timeout: Duration,
+    pending: HashMap<GroupKey, PartialMessage>,
+    dropped: Vec<DroppedGroup>,
+}
+
+impl Default for AisAssembler {
+    fn default() -> AisAssembler {
+        AisAssembler::new(Duration::from_secs(10))
+    }
+}
+
+impl AisAssembler {
+    pub fn new(timeout: Duration) -> AisAssembler {
+        AisAssembler {
+            timeout,
+            pending: HashMap::new(),
+            dropped: Vec::new(),
+        }
+    }
+
+    pub fn push<F: AisFragment>(&mut self, fragment: &F) -> Result<Option<AisPayload>, NmeaError> {
+        self.push_at(fragment, Instant::now())
+    }
+
+    pub fn push_at<F: AisFragment>(
+        &mut self,
+        fragment: &F,
+        now: Instant,
+    ) -> Result<Option<AisPayload>, NmeaError> {
+        self.expire(now);
+        let total = fragment.total_sentences()?;
+        let number = fragment.sentence_number()?;
+        if total == 0 || total > MAX_FRAGMENTS {
+            return Err(NmeaError::InvalidField {
+                index: 0,
+                raw: total.to_string(),
+            });
+        }
+        if number == 0 || number > total {
+            return Err(NmeaError::InvalidField {
+                index: 1,
+                raw: number.to_string(),
+            });
+        }
+        let payload = fragment.payload()?;
+        if total == 1 {
+            return Ok(Some(payload));
+        }
+
+        let key = (
+            fragment.is_own_vessel(),
+            fragment.sentence_id().ok(),
+            fragment.channel().ok(),
+        );
+        if let Some(partial) = self.pending.get(&key) {
+            let reason = if partial.fragments.len() != total {
+                Some(DropReason::Inconsistent)
+            } else if partial.fragments[number - 1].is_some() {
+                Some(DropReason::Superseded)
+            } else {
+                None
+            };
+            if let Some(reason) = reason {
+                self.drop_group(key, reason);
+            }
+        }
+
+        let partial = self.pending.entry(key).or_insert_with(|| PartialMessage {
+            fragments: vec![None; total],
+            started: now,
+        });
+        partial.fragments[number - 1] = Some(payload);
+        if partial.received() < total {
+            return Ok(None);
+        }
+
+        match self.pending.remove(&key) {
+            Some(partial) => {
+                let mut fragments = partial.fragments.into_iter().flatten();
+                let mut payload = fragments.next().unwrap_or_default();
+                for fragment in fragments {
+                    payload.append(&fragment);
+                }
+                Ok(Some(payload))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn expire(&mut self, now: Instant) {
+        let expired = self
+            .pending
+            .iter()
+            .filter(|(_, p)| now.saturating_duration_since(p.started) > self.timeout)
+            .map(|(k, _)| *k)
+            .collect::<Vec<_>>();
+        for key in expired {
+            self.drop_group(key, DropReason::Timeout);
+        }
+    }
+
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn take_dropped(&mut self) -> Vec<DroppedGroup> {
+        std::mem::take(&mut self.dropped)
+    }
+
+    fn drop_group(&mut self, key: GroupKey, reason: DropReason) {
+        if let Some(partial) = self.pending.remove(&key) {
+            self.dropped.push(DroppedGroup {
+                own_vessel: key.0,
+                sequence_id: key.1,
+                channel: key.2,
+                received: partial.received(),
+                total: partial.fragments.len(),
+                reason,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ais::{messages::AisMessage, vdm::Vdm};
+
+    const FIRST: &str =
+        "!AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C";
+    const SECOND: &str = "!AIVDM,2,2,1,A,88888888880,2*25";
+
+    fn vdm(line: &str) -> Vdm {
+        Vdm::new(Nmea0183Base::from_string(&line.to_string()).unwrap())
+    }
+
+    fn fragment(total: usize, number: usize, id: usize) -> Vdm {
+        Vdm::builder()
+            .total_sentences(total)
+            .sentence_number(number)
+            .sentence_id(id)
+            .channel('A')
+            .message(String::from("88888888880"))
+            .fill_bits(2)
+            .build()
+    }
+
+    fn assert_static_data(payload: AisPayload) {
+        match AisMessage::decode(&payload).unwrap() {
+            AisMessage::StaticAndVoyageData(data) => {
+                assert_eq!(data.mmsi, 351759000);
+                assert_eq!(data.imo, 9134270);
+                assert_eq!(data.callsign, "3FOF8");
+                assert_eq!(data.shipname, "EVER DIADEM");
+                assert_eq!(data.ship_type, 70);
+                assert_eq!(data.destination, "NEW YORK");
+                assert_eq!(data.dimensions.to_bow, 225);
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    #[test]
+    fn reassembles_static_and_voyage_data() {
+        let mut assembler = AisAssembler::default();
+        assert_eq!(assembler.push(&vdm(FIRST)).unwrap(), None);
+        assert_eq!(assembler.pending(), 1);
+        let payload = assembler.push(&vdm(SECOND)).unwrap().unwrap();
+        assert_eq!(payload.bit_length(), 424);
+        assert_eq!(assembler.pending(), 0);
+        assert_static_data(payload);
+    }
+
+    #[test]
+    fn accepts_fragments_out_of_order() {
+        let mut assembler = AisAssembler::default();
+        assert_eq!(assembler.push(&vdm(SECOND)).unwrap(), None);
+        assert_static_data(assembler.push(&vdm(FIRST)).unwrap().unwrap());
+    }
+
+    #[test]
+    fn single_fragments_pass_through() {
+        let mut assembler = AisAssembler::default();
+        let line = "!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C";
+        let payload = assembler.push(&vdm(line)).unwrap().unwrap();
+        assert_eq!(payload.message_type(), 1);
+        assert_eq!(assembler.pending(), 0);
+    }
+
+    #[test]
+    fn expired_groups_are_dropped() {
+        let mut assembler = AisAssembler::new(Duration::from_secs(1));
+        let start = Instant::now();
+        assert_eq!(assembler.push_at(&vdm(FIRST), start).unwrap(), None);
+        assembler.expire(start + Duration::from_secs(2));
+        assert_eq!(assembler.pending(), 0);
+        let dropped = assembler.take_dropped();
+        assert_eq!(
+            dropped,
+            [DroppedGroup {
+                own_vessel: false,
+                sequence_id: Some(1),
+                channel: Some('A'),
+                received: 1,
+                total: 2,
+                reason: DropReason::Timeout,
+            }]
+        );
+        assert!(assembler.take_dropped().is_empty());
+        let late = assembler.push_at(&vdm(SECOND), start + Duration::from_secs(2));
+        assert_eq!(late.unwrap(), None);
+    }
+
+    #[test]
+    fn repeated_and_inconsistent_fragments_restart_the_group() {
+        let mut assembler = AisAssembler::default();
+        assembler.push(&fragment(3, 1, 4)).unwrap();
+        assembler.push(&fragment(3, 1, 4)).unwrap();
+        assembler.push(&fragment(2, 2, 4)).unwrap();
+        let reasons = assembler
+            .take_dropped()
+            .into_iter()
+            .map(|d| d.reason)
+            .collect::<Vec<_>>();
+        assert_eq!(reasons, [DropReason::Superseded, DropReason::Inconsistent]);
+        assert_eq!(assembler.pending(), 1);
+    }
+
+    #[test]
+    fn groups_are_kept_apart_by_sequence_id() {
+        let mut assembler = AisAssembler::default();
+        assert_eq!(assembler.push(&fragment(2, 1, 1)).unwrap(), None);
+        assert_eq!(assembler.push(&fragment(2, 1, 2)).unwrap(), None);
+        assert_eq!(assembler.pending(), 2);
+        assert!(assembler.push(&fragment(2, 2, 2)).unwrap().is_some());
+        assert_eq!(assembler.pending(), 1);
+    }
+
+    #[test]
+    fn rejects_invalid_fragment_numbers() {
+        let mut assembler = AisAssembler::default();
+        for (total, number) in [(0, 1), (10, 1), (2, 0), (2, 3)] {
+            assert!(matches!(
+                assembler.push(&fragment(total, number, 1)),
+                Err(NmeaError::InvalidField { .. })
+            ));
+        }
+        assert_eq!(assembler.pending(), 0);
+    }
+}
@@ -0,0 +1,1378 @@
+use crate::ais::payload::{AisPayload, BinaryData};
+use crate::base::*;
+use liblatlon::Position;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum NavigationStatus {
+    UnderWayUsingEngine,
+    AtAnchor,
+    NotUnderCommand,
+    RestrictedManoeuverability,
+    ConstrainedByDraught,
+    Moored,
+    Aground,
+    EngagedInFishing,
+    UnderWaySailing,
+    AisSartActive,
+    NotDefined,
+    Reserved(u8),
+}
+
+impl From<u8> for NavigationStatus {
+    fn from(value: u8) -> NavigationStatus {
+        match value {
+            0 => NavigationStatus::UnderWayUsingEngine,
+            1 => NavigationStatus::AtAnchor,
+            2 => NavigationStatus::NotUnderCommand,
+            3 => NavigationStatus::RestrictedManoeuverability,
+            4 => NavigationStatus::ConstrainedByDraught,
+            5 => NavigationStatus::Moored,
+            6 => NavigationStatus::Aground,
+            7 => NavigationStatus::EngagedInFishing,
+            8 => NavigationStatus::UnderWaySailing,
+            14 => NavigationStatus::AisSartActive,
+            15 => NavigationStatus::NotDefined,
+            n => NavigationStatus::Reserved(n),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Dimensions {
+    pub to_bow: u16,
+    pub to_stern: u16,
+    pub to_port: u8,
+    pub to_starboard: u8,
+}
+
+impl Dimensions {
+    fn read(payload: &AisPayload, start: usize) -> Dimensions {
+        Dimensions {
+            to_bow: payload.unsigned(start, 9) as u16,
+            to_stern: payload.unsigned(start + 9, 9) as u16,
+            to_port: payload.unsigned(start + 18, 6) as u8,
+            to_starboard: payload.unsigned(start + 24, 6) as u8,
+        }
+    }
+
+    pub fn length(&self) -> Distance {
+        Distance::from_meters((self.to_bow + self.to_stern) as f32)
+    }
+
+    pub fn beam(&self) -> Distance {
+        Distance::from_meters((self.to_port as u16 + self.to_starboard as u16) as f32)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Eta {
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Area {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_position"))]
+    pub north_east: Position,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_position"))]
+    pub south_west: Position,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PositionReport {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub status: NavigationStatus,
+    pub rate_of_turn: Option<f32>,
+    pub sog: Option<Speed>,
+    pub accuracy: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize_optional_position")
+    )]
+    pub position: Option<Position>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize_optional_degrees")
+    )]
+    pub cog: Option<f32>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize_optional_degrees")
+    )]
+    pub heading: Option<u16>,
+    pub second: u8,
+    pub maneuver: u8,
+    pub raim: bool,
+    pub radio: u32,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BaseStationReport {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub accuracy: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize_optional_position")
+    )]
+    pub position: Option<Position>,
+    pub epfd: u8,
+    pub raim: bool,
+    pub radio: u32,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct StaticAndVoyageData {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub ais_version: u8,
+    pub imo: u32,
+    pub callsign: String,
+    pub shipname: String,
+    pub ship_type: u8,
+    pub dimensions: Dimensions,
+    pub epfd: u8,
+    pub eta: Eta,
+    pub draught: Distance,
+    pub destination: String,
+    pub dte: bool,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BinaryAddressed {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub sequence_number: u8,
+    pub destination_mmsi: u32,
+    pub retransmit: bool,
+    pub dac: u16,
+    pub fid: u8,
+    pub data: BinaryData,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BinaryAcknowledge {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub acknowledgements: Vec<(u32, u8)>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BinaryBroadcast {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub dac: u16,
+    pub fid: u8,
+    pub data: BinaryData,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SarAircraftPosition {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub altitude: Option<Distance>,
+    pub sog: Option<Speed>,
+    pub accuracy: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize_optional_position")
+    )]
+    pub position: Option<Position>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize_optional_degrees")
+    )]
+    pub cog: Option<f32>,
+    pub second: u8,
+    pub dte: bool,
+    pub assigned: bool,
+    pub raim: bool,
+    pub radio: u32,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct UtcDateInquiry {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub destination_mmsi: u32,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AddressedSafety {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub sequence_number: u8,
+    pub destination_mmsi: u32,
+    pub retransmit: bool,
+    pub text: String,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SafetyBroadcast {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub text: String,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct InterrogationRequest {
+    pub mmsi: u32,
+    pub message_type: u8,
+    pub slot_offset: u16,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Interrogation {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub requests: Vec<InterrogationRequest>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SlotAssignment {
+    pub mmsi: u32,
+    pub offset: u16,
+    pub increment: u16,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AssignmentModeCommand {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub assignments: Vec<SlotAssignment>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DgnssBroadcast {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize_optional_position")
+    )]
+    pub position: Option<Position>,
+    pub data: BinaryData,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ClassBPositionReport {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub sog: Option<Speed>,
+    pub accuracy: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize_optional_position")
+    )]
+    pub position: Option<Position>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize_optional_degrees")
+    )]
+    pub cog: Option<f32>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize_optional_degrees")
+    )]
+    pub heading: Option<u16>,
+    pub second: u8,
+    pub carrier_sense: bool,
+    pub display: bool,
+    pub dsc: bool,
+    pub band: bool,
+    pub message_22: bool,
+    pub assigned: bool,
+    pub raim: bool,
+    pub radio: u32,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ExtendedClassBPositionReport {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub sog: Option<Speed>,
+    pub accuracy: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize_optional_position")
+    )]
+    pub position: Option<Position>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize_optional_degrees")
+    )]
+    pub cog: Option<f32>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize_optional_degrees")
+    )]
+    pub heading: Option<u16>,
+    pub second: u8,
+    pub shipname: String,
+    pub ship_type: u8,
+    pub dimensions: Dimensions,
+    pub epfd: u8,
+    pub raim: bool,
+    pub dte: bool,
+    pub assigned: bool,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SlotReservation {
+    pub offset: u16,
+    pub number: u8,
+    pub timeout: u8,
+    pub increment: u16,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DataLinkManagement {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub reservations: Vec<SlotReservation>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AidToNavigationReport {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub aid_type: u8,
+    pub name: String,
+    pub accuracy: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize_optional_position")
+    )]
+    pub position: Option<Position>,
+    pub dimensions: Dimensions,
+    pub epfd: u8,
+    pub second: u8,
+    pub off_position: bool,
+    pub raim: bool,
+    pub virtual_aid: bool,
+    pub assigned: bool,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ChannelManagement {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub channel_a: u16,
+    pub channel_b: u16,
+    pub tx_rx_mode: u8,
+    pub low_power: bool,
+    pub area: Option<Area>,
+    pub destinations: Option<(u32, u32)>,
+    pub band_a: bool,
+    pub band_b: bool,
+    pub zone_size: u8,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GroupAssignmentCommand {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub area: Option<Area>,
+    pub station_type: u8,
+    pub ship_type: u8,
+    pub tx_rx_mode: u8,
+    pub interval: u8,
+    pub quiet: u8,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum StaticDataPart {
+    A {
+        shipname: String,
+    },
+    B {
+        ship_type: u8,
+        vendor_id: String,
+        model: u8,
+        serial: u32,
+        callsign: String,
+        dimensions: Option<Dimensions>,
+        mothership_mmsi: Option<u32>,
+    },
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct StaticDataReport {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub part: StaticDataPart,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SlotBinary {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub destination_mmsi: Option<u32>,
+    pub application_id: Option<(u16, u8)>,
+    pub data: BinaryData,
+    pub radio: Option<u32>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LongRangeBroadcast {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub accuracy: bool,
+    pub raim: bool,
+    pub status: NavigationStatus,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize_optional_position")
+    )]
+    pub position: Option<Position>,
+    pub sog: Option<Speed>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize_optional_degrees")
+    )]
+    pub cog: Option<f32>,
+    pub gnss: bool,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum AisMessage {
+    PositionReport(PositionReport),
+    BaseStationReport(BaseStationReport),
+    StaticAndVoyageData(StaticAndVoyageData),
+    BinaryAddressed(BinaryAddressed),
+    BinaryAcknowledge(BinaryAcknowledge),
+    BinaryBroadcast(BinaryBroadcast),
+    SarAircraftPosition(SarAircraftPosition),
+    UtcDateInquiry(UtcDateInquiry),
+    AddressedSafety(AddressedSafety),
+    SafetyBroadcast(SafetyBroadcast),
+    Interrogation(Interrogation),
+    AssignmentModeCommand(AssignmentModeCommand),
+    DgnssBroadcast(DgnssBroadcast),
+    ClassBPositionReport(ClassBPositionReport),
+    ExtendedClassBPositionReport(ExtendedClassBPositionReport),
+    DataLinkManagement(DataLinkManagement),
+    AidToNavigationReport(AidToNavigationReport),
+    ChannelManagement(ChannelManagement),
+    GroupAssignmentCommand(GroupAssignmentCommand),
+    StaticDataReport(StaticDataReport),
+    SingleSlotBinary(SlotBinary),
+    MultipleSlotBinary(SlotBinary),
+    LongRangeBroadcast(LongRangeBroadcast),
+}
+
+impl AisMessage {
+    pub fn decode(payload: &AisPayload) -> Result<AisMessage, NmeaError> {
+        payload.require(38)?;
+        let message_type = payload.message_type();
+        let repeat = payload.unsigned(6, 2) as u8;
+        let mmsi = payload.unsigned(8, 30) as u32;
+        let p = payload;
+        let message = match message_type {
+            1..=3 => {
+                p.require(168)?;
+                AisMessage::PositionReport(PositionReport {
+                    message_type,
+                    repeat,
+                    mmsi,
+                    status: NavigationStatus::from(p.unsigned(38, 4) as u8),
+                    rate_of_turn: rate_of_turn(p.signed(42, 8)),
+                    sog: speed(p.unsigned(50, 10)),
+                    accuracy: p.flag(60),
+                    position: position(p.signed(61, 28), p.signed(89, 27), 600000.0),
+                    cog: course(p.unsigned(116, 12)),
+                    heading: heading(p.unsigned(128, 9)),
+                    second: p.unsigned(137, 6) as u8,
+                    maneuver: p.unsigned(143, 2) as u8,
+                    raim: p.flag(148),
+                    radio: p.unsigned(149, 19) as u32,
+                })
+            }
+            4 | 11 => {
+                p.require(168)?;
+                AisMessage::BaseStationReport(BaseStationReport {
+                    message_type,
+                    repeat,
+                    mmsi,
+                    year: p.unsigned(38, 14) as u16,
+                    month: p.unsigned(52, 4) as u8,
+                    day: p.unsigned(56, 5) as u8,
+                    hour: p.unsigned(61, 5) as u8,
+                    minute: p.unsigned(66, 6) as u8,
+                    second: p.unsigned(72, 6) as u8,
+                    accuracy: p.flag(78),
+                    position: position(p.signed(79, 28), p.signed(107, 27), 600000.0),
+                    epfd: p.unsigned(134, 4) as u8,
+                    raim: p.flag(148),
+                    radio: p.unsigned(149, 19) as u32,
+                })
+            }
+            5 => {
+                p.require(420)?;
+                AisMessage::StaticAndVoyageData(StaticAndVoyageData {
+                    message_type,
+                    repeat,
+                    mmsi,
+                    ais_version: p.unsigned(38, 2) as u8,
+                    imo: p.unsigned(40, 30) as u32,
+                    callsign: p.text(70, 42),
+                    shipname: p.text(112, 120),
+                    ship_type: p.unsigned(232, 8) as u8,
+                    dimensions: Dimensions::read(p, 240),
+                    epfd: p.unsigned(270, 4) as u8,
+                    eta: Eta {
+                        month: p.unsigned(274, 4) as u8,
+                        day: p.unsigned(278, 5) as u8,
+                        hour: p.unsigned(283, 5) as u8,
+                        minute: p.unsigned(288, 6) as u8,
+                    },
+                    draught: Distance::from_meters(p.unsigned(294, 8) as f32 / 10.0),
+                    destination: p.text(302, 120),
+                    dte: p.flag(422),
+                })
+            }
+            6 => {
+                p.require(88)?;
+                AisMessage::BinaryAddressed(BinaryAddressed {
+                    message_type,
+                    repeat,
+                    mmsi,
+                    sequence_number: p.unsigned(38, 2) as u8,
+                    destination_mmsi: p.unsigned(40, 30) as u32,
+                    retransmit: p.flag(70),
+                    dac: p.unsigned(72, 10) as u16,
+                    fid: p.unsigned(82, 6) as u8,
+                    data: p.binary(88),
+                })
+            }
+            7 | 13 => {
+                p.require(72)?;
+                let count = ((p.bit_length() - 40) / 32).min(4);
+                AisMessage::BinaryAcknowledge(BinaryAcknowledge {
+                    message_type,
+                    repeat,
+                    mmsi,
+                    acknowledgements: (0..count)
+                        .map(|n| {
+                            (
+                                p.unsigned(40 + n * 32, 30) as u32,
+                                p.unsigned(70 + n * 32, 2) as u8,
+                            )
+                        })
+                        .collect(),
+                })
+            }
+            8 => {
+                p.require(56)?;
+                AisMessage::BinaryBroadcast(BinaryBroadcast {
+                    message_type,
+                    repeat,
+                    mmsi,
+                    dac: p.unsigned(40, 10) as u16,
+                    fid: p.unsigned(50, 6) as u8,
+                    data: p.binary(56),
+                })
+            }
+            9 => {
+                p.require(168)?;
+                let altitude = p.unsigned(38, 12);
+                AisMessage::SarAircraftPosition(SarAircraftPosition {
+                    message_type,
+                    repeat,
+                    mmsi,
+                    altitude: if altitude == 4095 {
+                        None
+                    } else {
+                        Some(Distance::from_meters(altitude as f32))
+                    },
+                    sog: match p.unsigned(50, 10) {
+                        1023 => None,
+                        knots => Some(Speed::from_knots(knots as f32)),
+                    },
+                    accuracy: p.flag(60),
+                    position: position(p.signed(61, 28), p.signed(89, 27), 600000.0),
+                    cog: course(p.unsigned(116, 12)),
+                    second: p.unsigned(128, 6) as u8,
+                    dte: p.flag(142),
+                    assigned: p.flag(146),
+                    raim: p.flag(147),
+                    radio: p.unsigned(148, 20) as u32,
+                })
+            }
+            10 => {
+                p.require(70)?;
+                AisMessage::UtcDateInquiry(UtcDateInquiry {
+                    message_type,
+                    repeat,
+                    mmsi,
+                    destination_mmsi: p.unsigned(40, 30) as u32,
+                })
+            }
+            12 => {
+                p.require(72)?;
+                AisMessage::AddressedSafety(AddressedSafety {
+                    message_type,
+                    repeat,
+                    mmsi,
+                    sequence_number: p.unsigned(38, 2) as u8,
+                    destination_mmsi: p.unsigned(40, 30) as u32,
+                    retransmit: p.flag(70),
+                    text: p.text(72, p.bit_length() - 72),
+                })
+            }
+            14 => {
+                p.require(40)?;
+                AisMessage::SafetyBroadcast(SafetyBroadcast {
+                    message_type,
+                    repeat,
+                    mmsi,
+                    text: p.text(40, p.bit_length() - 40),
+                })
+            }
+            15 => {
+                p.require(88)?;
+                let mut requests = vec![InterrogationRequest {
+                    mmsi: p.unsigned(40, 30) as u32,
+                    message_type: p.unsigned(70, 6) as u8,
+                    slot_offset: p.unsigned(76, 12) as u16,
+                }];
+                if p.bit_length() >= 110 {
+                    requests.push(InterrogationRequest {
+                        mmsi: requests[0].mmsi,
+                        message_type: p.unsigned(90, 6) as u8,
+                        slot_offset: p.unsigned(96, 12) as u16,
+                    });
+                }
+                if p.bit_length() >= 158 {
+                    requests.push(InterrogationRequest {
+                        mmsi: p.unsigned(110, 30) as u32,
+                        message_type: p.unsigned(140, 6) as u8,
+                        slot_offset: p.unsigned(146, 12) as u16,
+                    });
+                }
+                AisMessage::Interrogation(Interrogation {
+                    message_type,
+                    repeat,
+                    mmsi,
+                    requests,
+                })
+            }
+            16 => {
+                p.require(92)?;
+                let count = if p.bit_length() >= 144 { 2 } else { 1 };
+                AisMessage::AssignmentModeCommand(AssignmentModeCommand {
+                    message_type,
+                    repeat,
+                    mmsi,
+                    assignments: (0..count)
+                        .map(|n| SlotAssignment {
+                            mmsi: p.unsigned(40 + n * 52, 30) as u32,
+                            offset: p.unsigned(70 + n * 52, 12) as u16,
+                            increment: p.unsigned(82 + n * 52, 10) as u16,
+                        })
+                        .collect(),
+                })
+            }
+            17 => {
+                p.require(80)?;
+                AisMessage::DgnssBroadcast(DgnssBroadcast {
+                    message_type,
+                    repeat,
+                    mmsi,
+                    position: position(p.signed(40, 18), p.signed(58, 17), 600.0),
+                    data: p.binary(80),
+                })
+            }
+            18 => {
+                p.require(168)?;
+                AisMessage::ClassBPositionReport(ClassBPositionReport {
+                    message_type,
+                    repeat,
+                    mmsi,
+                    sog: speed(p.unsigned(46, 10)),
+                    accuracy: p.flag(56),
+                    position: position(p.signed(57, 28), p.signed(85, 27), 600000.0),
+                    cog: course(p.unsigned(112, 12)),
+                    heading: heading(p.unsigned(124, 9)),
+                    second: p.unsigned(133, 6) as u8,
+                    carrier_sense: p.flag(141),
+                    display: p.flag(142),
+                    dsc: p.flag(143),
+                    band: p.flag(144),
+                    message_22: p.flag(145),
+                    assigned: p.flag(146),
+                    raim: p.flag(147),
+                    radio: p.unsigned(148, 20) as u32,
+                })
+            }
+            19 => {
+                p.require(312)?;
+                AisMessage::ExtendedClassBPositionReport(ExtendedClassBPositionReport {
+                    message_type,
+                    repeat,
+                    mmsi,
+                    sog: speed(p.unsigned(46, 10)),
+                    accuracy: p.flag(56),
+                    position: position(p.signed(57, 28), p.signed(85, 27), 600000.0),
+                    cog: course(p.unsigned(112, 12)),
+                    heading: heading(p.unsigned(124, 9)),
+                    second: p.unsigned(133, 6) as u8,
+                    shipname: p.text(143, 120),
+                    ship_type: p.unsigned(263, 8) as u8,
+                    dimensions: Dimensions::read(p, 271),
+                    epfd: p.unsigned(301, 4) as u8,
+                    raim: p.flag(305),
+                    dte: p.flag(306),
+                    assigned: p.flag(307),
+                })
+            }
+            20 => {
+                p.require(70)?;
+                let count = ((p.bit_length() - 40) / 30).min(4);
+                AisMessage::DataLinkManagement(DataLinkManagement {
+                    message_type,
+                    repeat,
+                    mmsi,
+                    reservations: (0..count)
+                        .map(|n| SlotReservation {
+                            offset: p.unsigned(40 + n * 30, 12) as u16,
+                            number: p.unsigned(52 + n * 30, 4) as u8,
+                            timeout: p.unsigned(56 + n * 30, 3) as u8,
+                            increment: p.unsigned(59 + n * 30, 11) as u16,
+                        })
+                        .filter(|r| r.offset != 0 || r.number != 0)
+                        .collect(),
+                })
+            }
+            21 => {
+                p.require(272)?;
+                let extension_bits = (p.bit_length() - 272).min(88);
+                AisMessage::AidToNavigationReport(AidToNavigationReport {
+                    message_type,
+                    repeat,
+                    mmsi,
+                    aid_type: p.unsigned(38, 5) as u8,
+                    name: p.text(43, 120) + p.text(272, extension_bits).as_str(),
+                    accuracy: p.flag(163),
+                    position: position(p.signed(164, 28), p.signed(192, 27), 600000.0),
+                    dimensions: Dimensions::read(p, 219),
+                    epfd: p.unsigned(249, 4) as u8,
+                    second: p.unsigned(253, 6) as u8,
+                    off_position: p.flag(259),
+                    raim: p.flag(268),
+                    virtual_aid: p.flag(269),
+                    assigned: p.flag(270),
+                })
+            }
+            22 => {
+                p.require(168)?;
+                let addressed = p.flag(139);
+                AisMessage::ChannelManagement(ChannelManagement {
+                    message_type,
+                    repeat,
+                    mmsi,
+                    channel_a: p.unsigned(40, 12) as u16,
+                    channel_b: p.unsigned(52, 12) as u16,
+                    tx_rx_mode: p.unsigned(64, 4) as u8,
+                    low_power: p.flag(68),
+                    area: if addressed {
+                        None
+                    } else {
+                        area(p, 69, 87, 104, 122)
+                    },
+                    destinations: if addressed {
+                        Some((p.unsigned(69, 30) as u32, p.unsigned(104, 30) as u32))
+                    } else {
+                        None
+                    },
+                    band_a: p.flag(140),
+                    band_b: p.flag(141),
+                    zone_size: p.unsigned(142, 3) as u8,
+                })
+            }
+            23 => {
+                p.require(160)?;
+                AisMessage::GroupAssignmentCommand(GroupAssignmentCommand {
+                    message_type,
+                    repeat,
+                    mmsi,
+                    area: area(p, 40, 58, 75, 93),
+                    station_type: p.unsigned(110, 4) as u8,
+                    ship_type: p.unsigned(114, 8) as u8,
+                    tx_rx_mode: p.unsigned(144, 2) as u8,
+                    interval: p.unsigned(146, 4) as u8,
+                    quiet: p.unsigned(150, 4) as u8,
+                })
+            }
+            24 => {
+                p.require(160)?;
+                let part = match p.unsigned(38, 2) {
+                    0 => StaticDataPart::A {
+                        shipname: p.text(40, 120),
+                    },
+                    1 => {
+                        let auxiliary = mmsi / 10000000 == 98;
+                        StaticDataPart::B {
+                            ship_type: p.unsigned(40, 8) as u8,
+                            vendor_id: p.text(48, 18),
+                            model: p.unsigned(66, 4) as u8,
+                            serial: p.unsigned(70, 20) as u32,
+                            callsign: p.text(90, 42),
+                            dimensions: if auxiliary {
+                                None
+                            } else {
+                                Some(Dimensions::read(p, 132))
+                            },
+                            mothership_mmsi: if auxiliary {
+                                Some(p.unsigned(132, 30) as u32)
+                            } else {
+                                None
+                            },
+                        }
+                    }
+                    n => {
+                        return Err(NmeaError::InvalidField {
+                            index: 4,
+                            raw: format!("part number {n}"),
+                        })
+                    }
+                };
+                AisMessage::StaticDataReport(StaticDataReport {
+                    message_type,
+                    repeat,
+                    mmsi,
+                    part,
+                })
+            }
+            25 | 26 => {
+                p.require(40)?;
+                let addressed = p.flag(38);
+                let structured = p.flag(39);
+                let mut start = 40;
+                let destination_mmsi = if addressed {
+                    start += 32;
+                    Some(p.unsigned(40, 30) as u32)
+                } else {
+                    None
+                };
+                let application_id = if structured {
+                    start += 16;
+                    Some((
+                        p.unsigned(start - 16, 10) as u16,
+                        p.unsigned(start - 6, 6) as u8,
+                    ))
+                } else {
+                    None
+                };
+                let multiple = message_type == 26;
+                let radio_bits = if multiple { 20 } else { 0 };
+                p.require(start + radio_bits)?;
+                let mut data = p.binary(start);
+                if multiple {
+                    let data_bits = p.bit_length() - start - radio_bits;
+                    data.data.truncate(data_bits.div_ceil(8));
+                    if !data_bits.is_multiple_of(8) {
+                        if let Some(last) = data.data.last_mut() {
+                            *last &= 0xFF << (8 - data_bits % 8);
+                        }
+                    }
+                    data.bit_length = data_bits;
+                }
+                let slot = SlotBinary {
+                    message_type,
+                    repeat,
+                    mmsi,
+                    destination_mmsi,
+                    application_id,
+                    data,
+                    radio: if multiple {
+                        Some(p.unsigned(p.bit_length() - 20, 20) as u32)
+                    } else {
+                        None
+                    },
+                };
+                if multiple {
+                    AisMessage::MultipleSlotBinary(slot)
+                } else {
+                    AisMessage::SingleSlotBinary(slot)
+                }
+            }
+            27 => {
+                p.require(96)?;
+                AisMessage::LongRangeBroadcast(LongRangeBroadcast {
+                    message_type,
+                    repeat,
+                    mmsi,
+                    accuracy: p.flag(38),
+                    raim: p.flag(39),
+                    status: NavigationStatus::from(p.unsigned(40, 4) as u8),
+                    position: position(p.signed(44, 18), p.signed(62, 17), 600.0),
+                    sog: match p.unsigned(79, 6) {
+                        63 => None,
+                        knots => Some(Speed::from_knots(knots as f32)),
+                    },
+                    cog: match p.unsigned(85, 9) {
+                        511 => None,
+                        degrees => Some(degrees as f32),
+                    },
+                    gnss: !p.flag(94),
+                })
+            }
+            n => return Err(NmeaError::UnknownMessageType(n)),
+        };
+        Ok(message)
+    }
+
+    pub fn message_type(&self) -> u8 {
+        match self {
+            AisMessage::PositionReport(m) => m.message_type,
+            AisMessage::BaseStationReport(m) => m.message_type,
+            AisMessage::StaticAndVoyageData(m) => m.message_type,
+            AisMessage::BinaryAddressed(m) => m.message_type,
+            AisMessage::BinaryAcknowledge(m) => m.message_type,
+            AisMessage::BinaryBroadcast(m) => m.message_type,
+            AisMessage::SarAircraftPosition(m) => m.message_type,
+            AisMessage::UtcDateInquiry(m) => m.message_type,
+            AisMessage::AddressedSafety(m) => m.message_type,
+            AisMessage::SafetyBroadcast(m) => m.message_type,
+            AisMessage::Interrogation(m) => m.message_type,
+            AisMessage::AssignmentModeCommand(m) => m.message_type,
+            AisMessage::DgnssBroadcast(m) => m.message_type,
+            AisMessage::ClassBPositionReport(m) => m.message_type,
+            AisMessage::ExtendedClassBPositionReport(m) => m.message_type,
+            AisMessage::DataLinkManagement(m) => m.message_type,
+            AisMessage::AidToNavigationReport(m) => m.message_type,
+            AisMessage::ChannelManagement(m) => m.message_type,
+            AisMessage::GroupAssignmentCommand(m) => m.message_type,
+            AisMessage::StaticDataReport(m) => m.message_type,
+            AisMessage::SingleSlotBinary(m) => m.message_type,
+            AisMessage::MultipleSlotBinary(m) => m.message_type,
+            AisMessage::LongRangeBroadcast(m) => m.message_type,
+        }
+    }
+
+    pub fn mmsi(&self) -> u32 {
+        match self {
+            AisMessage::PositionReport(m) => m.mmsi,
+            AisMessage::BaseStationReport(m) => m.mmsi,
+            AisMessage::StaticAndVoyageData(m) => m.mmsi,
+            AisMessage::BinaryAddressed(m) => m.mmsi,
+            AisMessage::BinaryAcknowledge(m) => m.mmsi,
+            AisMessage::BinaryBroadcast(m) => m.mmsi,
+            AisMessage::SarAircraftPosition(m) => m.mmsi,
+            AisMessage::UtcDateInquiry(m) => m.mmsi,
+            AisMessage::AddressedSafety(m) => m.mmsi,
+            AisMessage::SafetyBroadcast(m) => m.mmsi,
+            AisMessage::Interrogation(m) => m.mmsi,
+            AisMessage::AssignmentModeCommand(m) => m.mmsi,
+            AisMessage::DgnssBroadcast(m) => m.mmsi,
+            AisMessage::ClassBPositionReport(m) => m.mmsi,
+            AisMessage::ExtendedClassBPositionReport(m) => m.mmsi,
+            AisMessage::DataLinkManagement(m) => m.mmsi,
+            AisMessage::AidToNavigationReport(m) => m.mmsi,
+            AisMessage::ChannelManagement(m) => m.mmsi,
+            AisMessage::GroupAssignmentCommand(m) => m.mmsi,
+            AisMessage::StaticDataReport(m) => m.mmsi,
+            AisMessage::SingleSlotBinary(m) => m.mmsi,
+            AisMessage::MultipleSlotBinary(m) => m.mmsi,
+            AisMessage::LongRangeBroadcast(m) => m.mmsi,
+        }
+    }
+
+    pub fn position(&self) -> Option<Position> {
+        match self {
+            AisMessage::PositionReport(m) => m.position,
+            AisMessage::BaseStationReport(m) => m.position,
+            AisMessage::SarAircraftPosition(m) => m.position,
+            AisMessage::DgnssBroadcast(m) => m.position,
+            AisMessage::ClassBPositionReport(m) => m.position,
+            AisMessage::ExtendedClassBPositionReport(m) => m.position,
+            AisMessage::AidToNavigationReport(m) => m.position,
+            AisMessage::LongRangeBroadcast(m) => m.position,
+            _ => None,
+        }
+    }
+}
+
+fn position(longitude: i64, latitude: i64, scale: f64) -> Option<Position> {
+    let longitude = longitude as f64 / scale;
+    let latitude = latitude as f64 / scale;
+    if longitude.abs() > 180.0 || latitude.abs() > 90.0 {
+        None
+    } else {
+        Some(position_from_degrees(latitude, longitude))
+    }
+}
+
+fn area(
+    payload: &AisPayload,
+    ne_longitude: usize,
+    ne_latitude: usize,
+    sw_longitude: usize,
+    sw_latitude: usize,
+) -> Option<Area> {
+    Some(Area {
+        north_east: position(
+            payload.signed(ne_longitude, 18),
+            payload.signed(ne_latitude, 17),
+            600.0,
+        )?,
+        south_west: position(
+            payload.signed(sw_longitude, 18),
+            payload.signed(sw_latitude, 17),
+            600.0,
+        )?,
+    })
+}
+
+fn speed(raw: u64) -> Option<Speed> {
+    match raw {
+        1023 => None,
+        tenths => Some(Speed::from_knots(tenths as f32 / 10.0)),
+    }
+}
+
+fn course(raw: u64) -> Option<f32> {
+    match raw {
+        3600.. => None,
+        tenths => Some(tenths as f32 / 10.0),
+    }
+}
+
+fn heading(raw: u64) -> Option<u16> {
+    match raw {
+        360.. => None,
+        degrees => Some(degrees as u16),
+    }
+}
+
+fn rate_of_turn(raw: i64) -> Option<f32> {
+    match raw {
+        -126..=126 => {
+            let rate = (raw as f32 / 4.733).powi(2);
+            Some(if raw < 0 { -rate } else { rate })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(payload: &str, fill_bits: u8) -> Result<AisMessage, NmeaError> {
+        AisMessage::decode(&AisPayload::from_armored(payload, fill_bits).unwrap())
+    }
+
+    fn assert_position(position: Option<Position>, latitude: f64, longitude: f64) {
+        let position = position.unwrap();
+        assert!(
+            (position.latitude() - latitude).abs() < 1e-6,
+            "{position:?}"
+        );
+        assert!(
+            (position.longitude() - longitude).abs() < 1e-6,
+            "{position:?}"
+        );
+    }
+
+    #[test]
+    fn decodes_position_report() {
+        let message = decode("177KQJ5000G?tO`K>RA1wUbN0TKH", 0).unwrap();
+        assert_eq!(message.message_type(), 1);
+        assert_eq!(message.mmsi(), 477553000);
+        assert_position(message.position(), 47.582833, -122.345833);
+        match message {
+            AisMessage::PositionReport(report) => {
+                assert_eq!(report.status, NavigationStatus::Moored);
+                assert_eq!(report.rate_of_turn, Some(0.0));
+                assert_eq!(report.sog.unwrap().as_knots(), 0.0);
+                assert_eq!(report.cog, Some(51.0));
+                assert_eq!(report.heading, Some(181));
+                assert_eq!(report.second, 15);
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    #[test]
+    fn decodes_base_station_report() {
+        match decode("403OviQuMGCqWrRO9>E6fE700@GO", 0).unwrap() {
+            AisMessage::BaseStationReport(report) => {
+                assert_eq!(report.mmsi, 3669702);
+                assert_eq!((report.year, report.month, report.day), (2007, 5, 14));
+                assert_eq!((report.hour, report.minute, report.second), (19, 57, 39));
+                assert!(report.accuracy);
+                assert_eq!(report.epfd, 7);
+                assert_position(report.position, 36.883767, -76.352362);
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    #[test]
+    fn decodes_class_b_position_report() {
+        match decode("B5NJ;PP005l4ot5Isbl03wsUkP06", 0).unwrap() {
+            AisMessage::ClassBPositionReport(report) => {
+                assert_eq!(report.mmsi, 367430530);
+                assert_eq!(report.cog, Some(0.0));
+                assert_eq!(report.heading, None);
+                assert_eq!(report.second, 55);
+                assert!(report.carrier_sense && report.dsc && report.band);
+                assert_position(report.position, 37.785035, -122.26732);
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    #[test]
+    fn decodes_static_and_voyage_data() {
+        let payload = "55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp888888888880";
+        match decode(payload, 2).unwrap() {
+            AisMessage::StaticAndVoyageData(data) => {
+                assert_eq!(data.mmsi, 351759000);
+                assert_eq!(data.imo, 9134270);
+                assert_eq!(data.callsign, "3FOF8");
+                assert_eq!(data.shipname, "EVER DIADEM");
+                assert_eq!(data.ship_type, 70);
+                assert_eq!(
+                    data.dimensions,
+                    Dimensions {
+                        to_bow: 225,
+                        to_stern: 70,
+                        to_port: 1,
+                        to_starboard: 31,
+                    }
+                );
+                assert_eq!(data.dimensions.length().as_meters(), 295.0);
+                assert_eq!(data.epfd, 1);
+                assert_eq!(
+                    data.eta,
+                    Eta {
+                        month: 5,
+                        day: 15,
+                        hour: 14,
+                        minute: 0,
+                    }
+                );
+                assert!((data.draught.as_meters() - 12.2).abs() < 1e-4);
+                assert_eq!(data.destination, "NEW YORK");
+                assert!(!data.dte);
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    #[test]
+    fn decodes_sar_aircraft_position() {
+        match decode("91b55wi;hbOS@OdQAC062Ch2089h", 0).unwrap() {
+            AisMessage::SarAircraftPosition(report) => {
+                assert_eq!(report.mmsi, 111232511);
+                assert_eq!(report.altitude.unwrap().as_meters(), 303.0);
+                assert!((report.sog.unwrap().as_knots() - 42.0).abs() < 1e-3);
+                assert!(!report.accuracy);
+                assert_position(report.position, 58.144, -6.278843);
+                assert_eq!(report.cog, Some(154.5));
+                assert_eq!(report.second, 15);
+                assert!(report.dte);
+                assert!(!report.raim);
+                assert_eq!(report.radio, 33392);
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    #[test]
+    fn decodes_extended_class_b_position_report() {
+        match decode("C5N3SRgPEnJGEBT>NhWAwwo862PaLELTBJ:V00000000S0D:R220", 0).unwrap() {
+            AisMessage::ExtendedClassBPositionReport(report) => {
+                assert_eq!(report.mmsi, 367059850);
+                assert!((report.sog.unwrap().as_knots() - 8.7).abs() < 1e-3);
+                assert_position(report.position, 29.543695, -88.810392);
+                assert_eq!(report.cog, Some(335.9));
+                assert_eq!(report.heading, None);
+                assert_eq!(report.second, 46);
+                assert_eq!(report.shipname, "CAPT.J.RIMES");
+                assert_eq!(report.ship_type, 70);
+                assert_eq!(report.dimensions.length().as_meters(), 26.0);
+                assert_eq!(report.dimensions.beam().as_meters(), 8.0);
+                assert_eq!(report.epfd, 1);
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    #[test]
+    fn decodes_aid_to_navigation_report() {
+        match decode("E>kb9O9aS@7PUh10dh19@;0Tah2cWrfP:l?M`00003vP100", 0).unwrap() {
+            AisMessage::AidToNavigationReport(report) => {
+                assert_eq!(report.mmsi, 993692028);
+                assert_eq!(report.aid_type, 19);
+                assert_eq!(report.name, "SF OAK BAY BR VAIS E");
+                assert_position(report.position, 37.805622, -122.369867);
+                assert_eq!(report.epfd, 7);
+                assert_eq!(report.second, 61);
+                assert!(!report.off_position);
+                assert!(report.virtual_aid);
+                assert!(!report.assigned);
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    #[test]
+    fn decodes_static_data_report_parts() {
+        match decode("H42O55i18tMET00000000000000", 2).unwrap() {
+            AisMessage::StaticDataReport(StaticDataReport {
+                mmsi,
+                part: StaticDataPart::A { shipname },
+                ..
+            }) => {
+                assert_eq!(mmsi, 271041815);
+                assert_eq!(shipname, "PROGUY");
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+        match decode("H42O55lti4hhhilD3nink000?050", 0).unwrap() {
+            AisMessage::StaticDataReport(StaticDataReport {
+                mmsi,
+                part:
+                    StaticDataPart::B {
+                        ship_type,
+                        vendor_id,
+                        model,
+                        serial,
+                        callsign,
+                        dimensions,
+                        mothership_mmsi,
+                    },
+                ..
+            }) => {
+                assert_eq!(mmsi, 271041815);
+                assert_eq!(ship_type, 60);
+                assert_eq!(vendor_id, "1D0");
+                assert_eq!((model, serial), (12, 199796));
+                assert_eq!(callsign, "TC6163");
+                assert_eq!(
+                    dimensions,
+                    Some(Dimensions {
+                        to_bow: 0,
+                        to_stern: 15,
+                        to_port: 0,
+                        to_starboard: 5,
+                    })
+                );
+                assert_eq!(mothership_mmsi, None);
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    #[test]
+    fn decodes_long_range_broadcast() {
+        match decode("KCQ9r=hrFUnH7P00", 0).unwrap() {
+            AisMessage::LongRangeBroadcast(report) => {
+                assert_eq!(report.mmsi, 236091959);
+                assert!(!report.accuracy && !report.raim);
+                assert_eq!(report.status, NavigationStatus::RestrictedManoeuverability);
+                assert_position(report.position, 87.065, -154.201667);
+                assert_eq!(report.sog.unwrap().as_knots(), 0.0);
+                assert_eq!(report.cog, Some(0.0));
+                assert!(report.gnss);
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    #[test]
+    fn unavailable_values_decode_to_none() {
+        assert!(speed(1023).is_none());
+        assert_eq!(course(3600), None);
+        assert_eq!(heading(511), None);
+        assert_eq!(rate_of_turn(-128), None);
+        assert_eq!(position(181 * 600000, 91 * 600000, 600000.0), None);
+        assert_position(
+            position(-(151 * 600000 + 12555), -(33 * 600000 + 52128), 600000.0),
+            -(33.0 + 0.052128 / 0.6),
+            -(151.0 + 0.012555 / 0.6),
+        );
+    }
+
+    #[test]
+    fn short_payloads_are_truncated() {
+        assert!(matches!(decode("1", 0), Err(NmeaError::Truncated)));
+        assert!(matches!(
+            decode("177KQJ5000G?tO`K>RA1wUbN0", 0),
+            Err(NmeaError::Truncated)
+        ));
+        assert!(matches!(
+            decode(
+                "55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8",
+                0
+            ),
+            Err(NmeaError::Truncated)
+        ));
+    }
+}
@@ -1 +1,5 @@
+pub mod assembler;
+pub mod messages;
+pub mod payload;
 pub mod vdm;
+pub mod vdo;
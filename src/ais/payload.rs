@@ -0,0 +1,139 @@
+use crate::base::*;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AisPayload {
+    sixbits: Vec<u8>,
+    fill_bits: u8,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BinaryData {
+    pub bit_length: usize,
+    pub data: Vec<u8>,
+}
+
+impl AisPayload {
+    pub fn from_armored(payload: &str, fill_bits: u8) -> Result<AisPayload, NmeaError> {
+        let mut sixbits = Vec::with_capacity(payload.len());
+        for c in payload.bytes() {
+            let value = match c {
+                b'0'..=b'W' => c - b'0',
+                b'`'..=b'w' => c - b'0' - 8,
+                _ => {
+                    return Err(NmeaError::InvalidField {
+                        index: 4,
+                        raw: payload.to_string(),
+                    })
+                }
+            };
+            sixbits.push(value);
+        }
+        if fill_bits > 5 || (sixbits.is_empty() && fill_bits > 0) {
+            return Err(NmeaError::InvalidField {
+                index: 5,
+                raw: fill_bits.to_string(),
+            });
+        }
+        Ok(AisPayload { sixbits, fill_bits })
+    }
+
+    pub fn append(&mut self, fragment: &AisPayload) {
+        if self.fill_bits == 0 {
+            self.sixbits.extend_from_slice(&fragment.sixbits);
+            self.fill_bits = fragment.fill_bits;
+        } else {
+            let bits = (0..self.bit_length())
+                .map(|n| self.bit(n))
+                .chain((0..fragment.bit_length()).map(|n| fragment.bit(n)))
+                .collect::<Vec<u8>>();
+            self.sixbits = bits
+                .chunks(6)
+                .map(|c| (0..6).fold(0, |acc, n| (acc << 1) | c.get(n).copied().unwrap_or(0)))
+                .collect();
+            self.fill_bits = ((6 - bits.len() % 6) % 6) as u8;
+        }
+    }
+
+    pub fn bit_length(&self) -> usize {
+        self.sixbits.len() * 6 - self.fill_bits as usize
+    }
+
+    pub fn fill_bits(&self) -> u8 {
+        self.fill_bits
+    }
+
+    pub fn to_armored(&self) -> String {
+        self.sixbits
+            .iter()
+            .map(|v| {
+                if *v < 40 {
+                    (v + b'0') as char
+                } else {
+                    (v + b'0' + 8) as char
+                }
+            })
+            .collect()
+    }
+
+    pub fn message_type(&self) -> u8 {
+        self.unsigned(0, 6) as u8
+    }
+
+    pub fn bit(&self, n: usize) -> u8 {
+        if n < self.bit_length() {
+            (self.sixbits[n / 6] >> (5 - n % 6)) & 1
+        } else {
+            0
+        }
+    }
+
+    pub fn flag(&self, n: usize) -> bool {
+        self.bit(n) == 1
+    }
+
+    pub fn unsigned(&self, start: usize, length: usize) -> u64 {
+        (start..start + length.min(64)).fold(0, |acc, n| (acc << 1) | self.bit(n) as u64)
+    }
+
+    pub fn signed(&self, start: usize, length: usize) -> i64 {
+        let length = length.clamp(1, 64);
+        let value = self.unsigned(start, length);
+        if length < 64 && value >> (length - 1) == 1 {
+            value as i64 - (1 << length)
+        } else {
+            value as i64
+        }
+    }
+
+    pub fn text(&self, start: usize, length: usize) -> String {
+        let mut text = String::new();
+        for n in 0..length / 6 {
+            let value = self.unsigned(start + n * 6, 6) as u8;
+            if value == 0 {
+                break;
+            }
+            text.push(if value < 32 { value + 64 } else { value } as char);
+        }
+        text.trim_end().to_string()
+    }
+
+    pub fn binary(&self, start: usize) -> BinaryData {
+        let bit_length = self.bit_length().saturating_sub(start);
+        let data = (0..bit_length.div_ceil(8))
+            .map(|n| {
+                let remaining = (bit_length - n * 8).min(8);
+                (self.unsigned(start + n * 8, remaining) << (8 - remaining)) as u8
+            })
+            .collect();
+        BinaryData { bit_length, data }
+    }
+
+    pub fn require(&self, bits: usize) -> Result<(), NmeaError> {
+        if self.bit_length() < bits {
+            Err(NmeaError::Truncated)
+        } else {
+            Ok(())
+        }
+    }
+}
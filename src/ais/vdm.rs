@@ -1,15 +1,34 @@
+use crate::ais::{assembler::AisFragment, messages::AisMessage, payload::AisPayload};
 use crate::base::*;
 
 #[derive(Debug)]
-pub struct Vdm {
-    base: Nmea0183Base,
+pub struct Vdm<B = DefaultFields> {
+    base: B,
 }
 
 impl Vdm {
-    pub fn new(base: Nmea0183Base) -> Vdm {
+    pub fn builder() -> VdmBuilder {
+        VdmBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Vdm<B> {
+    pub fn new(base: B) -> Vdm<B> {
         Vdm { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
+    pub fn is_own_vessel(&self) -> bool {
+        self.base.message() == "VDO"
+    }
+
     pub fn total_sentences(&self) -> UsizeError {
         self.base.parameter(0)
     }
@@ -23,14 +42,146 @@ impl Vdm {
     }
 
     pub fn channel(&self) -> Result<char, NmeaError> {
-        if let Some(c) = self.base.parameters[3].chars().nth(0) {
-            Ok(c)
-        } else {
-            Err(NmeaError("Invalid data".to_string()))
-        }
+        self.base.field_char(3)
     }
 
     pub fn message(&self) -> Result<String, NmeaError> {
-        Ok(self.base.parameters[4].clone())
+        Ok(self.base.field(4)?.to_string())
+    }
+
+    pub fn fill_bits(&self) -> U8Error {
+        self.base.parameter(5)
+    }
+
+    pub fn payload(&self) -> Result<AisPayload, NmeaError> {
+        AisPayload::from_armored(self.base.field(4)?, self.fill_bits()?)
+    }
+
+    pub fn decode(&self) -> Result<AisMessage, NmeaError> {
+        if self.total_sentences()? != 1 {
+            return Err(NmeaError::Truncated);
+        }
+        AisMessage::decode(&self.payload()?)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Vdm<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Vdm", 9)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("is_own_vessel", &self.is_own_vessel())?;
+        state.serialize_field("total_sentences", &self.total_sentences().ok())?;
+        state.serialize_field("sentence_number", &self.sentence_number().ok())?;
+        state.serialize_field("sentence_id", &self.sentence_id().ok())?;
+        state.serialize_field("channel", &self.channel().ok())?;
+        state.serialize_field("message", &self.message().ok())?;
+        state.serialize_field("fill_bits", &self.fill_bits().ok())?;
+        state.serialize_field("decoded", &self.decode().ok())?;
+        state.end()
+    }
+}
+
+impl<B: SentenceFields> AisFragment for Vdm<B> {
+    fn total_sentences(&self) -> UsizeError {
+        Vdm::total_sentences(self)
+    }
+
+    fn sentence_number(&self) -> UsizeError {
+        Vdm::sentence_number(self)
+    }
+
+    fn sentence_id(&self) -> UsizeError {
+        Vdm::sentence_id(self)
+    }
+
+    fn channel(&self) -> Result<char, NmeaError> {
+        Vdm::channel(self)
+    }
+
+    fn payload(&self) -> Result<AisPayload, NmeaError> {
+        Vdm::payload(self)
+    }
+
+    fn is_own_vessel(&self) -> bool {
+        Vdm::is_own_vessel(self)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct VdmBuilder {
+    talker: String,
+    total_sentences: Option<usize>,
+    sentence_number: Option<usize>,
+    sentence_id: Option<usize>,
+    channel: Option<char>,
+    message: String,
+    fill_bits: Option<u8>,
+    own_vessel: bool,
+}
+
+impl VdmBuilder {
+    pub fn new() -> VdmBuilder {
+        VdmBuilder {
+            talker: String::from("AI"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn total_sentences(mut self, total_sentences: usize) -> Self {
+        self.total_sentences = Some(total_sentences);
+        self
+    }
+
+    pub fn sentence_number(mut self, sentence_number: usize) -> Self {
+        self.sentence_number = Some(sentence_number);
+        self
+    }
+
+    pub fn sentence_id(mut self, sentence_id: usize) -> Self {
+        self.sentence_id = Some(sentence_id);
+        self
+    }
+
+    pub fn channel(mut self, channel: char) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    pub fn message(mut self, message: String) -> Self {
+        self.message = message;
+        self
+    }
+
+    pub fn fill_bits(mut self, fill_bits: u8) -> Self {
+        self.fill_bits = Some(fill_bits);
+        self
+    }
+
+    pub(crate) fn own_vessel(mut self) -> Self {
+        self.own_vessel = true;
+        self
+    }
+
+    pub fn build(self) -> Vdm {
+        let parameters = vec![
+            format_value(self.total_sentences),
+            format_value(self.sentence_number),
+            format_value(self.sentence_id),
+            format_value(self.channel),
+            self.message,
+            format_value(self.fill_bits),
+        ];
+        Vdm::new(Nmea0183Base::from_parts(
+            format!("!{}", self.talker).as_str(),
+            if self.own_vessel { "VDO" } else { "VDM" },
+            parameters,
+        ))
     }
 }
@@ -0,0 +1,167 @@
+use crate::ais::{assembler::AisFragment, payload::AisPayload, vdm::Vdm, vdm::VdmBuilder};
+use crate::base::*;
+use std::ops::Deref;
+
+#[derive(Debug)]
+pub struct Vdo<B = DefaultFields>(Vdm<B>);
+
+impl Vdo {
+    pub fn builder() -> VdoBuilder {
+        VdoBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Vdo<B> {
+    pub fn new(base: B) -> Vdo<B> {
+        Vdo(Vdm::new(base))
+    }
+
+    pub fn into_vdm(self) -> Vdm<B> {
+        self.0
+    }
+}
+
+impl<B> Deref for Vdo<B> {
+    type Target = Vdm<B>;
+
+    fn deref(&self) -> &Vdm<B> {
+        &self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Vdo<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<B: SentenceFields> AisFragment for Vdo<B> {
+    fn total_sentences(&self) -> UsizeError {
+        self.0.total_sentences()
+    }
+
+    fn sentence_number(&self) -> UsizeError {
+        self.0.sentence_number()
+    }
+
+    fn sentence_id(&self) -> UsizeError {
+        self.0.sentence_id()
+    }
+
+    fn channel(&self) -> Result<char, NmeaError> {
+        self.0.channel()
+    }
+
+    fn payload(&self) -> Result<AisPayload, NmeaError> {
+        self.0.payload()
+    }
+
+    fn is_own_vessel(&self) -> bool {
+        self.0.is_own_vessel()
+    }
+}
+
+#[derive(Debug)]
+pub struct VdoBuilder(VdmBuilder);
+
+impl Default for VdoBuilder {
+    fn default() -> VdoBuilder {
+        VdoBuilder::new()
+    }
+}
+
+impl VdoBuilder {
+    pub fn new() -> VdoBuilder {
+        VdoBuilder(VdmBuilder::new().own_vessel())
+    }
+
+    pub fn talker(self, talker: &str) -> Self {
+        VdoBuilder(self.0.talker(talker))
+    }
+
+    pub fn total_sentences(self, total_sentences: usize) -> Self {
+        VdoBuilder(self.0.total_sentences(total_sentences))
+    }
+
+    pub fn sentence_number(self, sentence_number: usize) -> Self {
+        VdoBuilder(self.0.sentence_number(sentence_number))
+    }
+
+    pub fn sentence_id(self, sentence_id: usize) -> Self {
+        VdoBuilder(self.0.sentence_id(sentence_id))
+    }
+
+    pub fn channel(self, channel: char) -> Self {
+        VdoBuilder(self.0.channel(channel))
+    }
+
+    pub fn message(self, message: String) -> Self {
+        VdoBuilder(self.0.message(message))
+    }
+
+    pub fn fill_bits(self, fill_bits: u8) -> Self {
+        VdoBuilder(self.0.fill_bits(fill_bits))
+    }
+
+    pub fn build(self) -> Vdo {
+        Vdo(self.0.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ais::assembler::AisAssembler, Nmea0183};
+
+    const OWN_VESSEL: &str = "!AIVDO,1,1,,,B5NJ;PP005l4ot5Isbl03wsUkP06,0*35";
+
+    #[test]
+    fn delegates_to_vdm() {
+        let vdo = match Nmea0183::from_string(&OWN_VESSEL.to_string()).unwrap() {
+            Nmea0183::VDO(vdo) => vdo,
+            other => panic!("unexpected {other:?}"),
+        };
+        assert!(vdo.is_own_vessel());
+        assert_eq!(vdo.total_sentences().unwrap(), 1);
+        assert!(vdo.channel().is_err());
+        assert_eq!(vdo.decode().unwrap().mmsi(), 367430530);
+        assert_eq!(vdo.to_sentence(), format!("{OWN_VESSEL}\r\n"));
+    }
+
+    #[test]
+    fn builder_writes_vdo() {
+        let vdo = Vdo::builder()
+            .total_sentences(1)
+            .sentence_number(1)
+            .message(String::from("B5NJ;PP005l4ot5Isbl03wsUkP06"))
+            .fill_bits(0)
+            .build();
+        assert_eq!(vdo.to_sentence(), format!("{OWN_VESSEL}\r\n"));
+        assert!(!Vdm::builder().build().is_own_vessel());
+    }
+
+    #[test]
+    fn own_vessel_fragments_are_kept_apart() {
+        let vdm = Vdm::builder()
+            .total_sentences(2)
+            .sentence_number(1)
+            .sentence_id(3)
+            .message(String::from(
+                "55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8",
+            ))
+            .fill_bits(0)
+            .build();
+        let vdo = Vdo::builder()
+            .total_sentences(2)
+            .sentence_number(2)
+            .sentence_id(3)
+            .message(String::from("88888888880"))
+            .fill_bits(2)
+            .build();
+        let mut assembler = AisAssembler::default();
+        assert_eq!(assembler.push(&vdm).unwrap(), None);
+        assert_eq!(assembler.push(&vdo).unwrap(), None);
+        assert_eq!(assembler.pending(), 2);
+    }
+}
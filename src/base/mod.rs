@@ -1,179 +1,419 @@
+use crate::sentence_type::SentenceType;
+use crate::talker::TalkerId;
+#[cfg(feature = "std")]
 use chrono::prelude::*;
+use core::str::FromStr;
+#[cfg(feature = "std")]
 use liblatlon::Position;
-use std::{io, str::FromStr};
+#[cfg(feature = "std")]
+use std::io;
 
 pub type F32Error = Result<f32, NmeaError>;
 pub type F64Error = Result<f64, NmeaError>;
 pub type U8Error = Result<u8, NmeaError>;
 pub type UsizeError = Result<usize, NmeaError>;
 
+#[cfg(feature = "std")]
 pub type NaiveDateError = Result<NaiveDate, NmeaError>;
+#[cfg(feature = "std")]
 pub type NaiveTimeError = Result<NaiveTime, NmeaError>;
+#[cfg(feature = "std")]
 pub type NaiveDateTimeError = Result<NaiveDateTime, NmeaError>;
+#[cfg(feature = "std")]
 pub type PositionError = Result<Position, NmeaError>;
 
-pub const INSUFFICIENT_NUMBER_OF_PARAMETERS: &str =
-    "Insufficient number of parameters in sentence.";
+#[cfg(feature = "std")]
+pub type DefaultFields = Nmea0183Base;
+#[cfg(not(feature = "std"))]
+pub type DefaultFields = SentenceRef<'static>;
+
+#[cfg(feature = "std")]
+pub type RawText = String;
+#[cfg(not(feature = "std"))]
+pub type RawText = FieldText;
+
+pub const MAX_SENTENCE_LENGTH: usize = 82;
+pub const MAX_FIELDS: usize = 128;
+pub const MAX_FIELD_TEXT: usize = 16;
 
 pub const KPH_TO_KNOTS: f32 = 0.539957;
 pub const MPH_TO_KNOTS: f32 = 0.868976;
 pub const KPH_TO_MPH: f32 = 1.60934;
 
 #[derive(Debug)]
-pub struct NmeaError(pub String);
+pub enum NmeaError {
+    BadChecksum {
+        expected: u8,
+        actual: u8,
+    },
+    MalformedChecksum(RawText),
+    MissingChecksum,
+    MissingField(usize),
+    InvalidField {
+        index: usize,
+        raw: RawText,
+    },
+    UnknownUnits {
+        index: usize,
+        raw: RawText,
+    },
+    DataInvalid,
+    NotPresent,
+    IndexOutOfRange(usize),
+    UnknownMessageType(u8),
+    Truncated,
+    LineTooLong(usize),
+    TooManyFields(usize),
+    UnknownHeader,
+    DatagramTooLarge(usize),
+    MissingDelimiter,
+    #[cfg(feature = "std")]
+    Io(io::Error),
+}
 
-impl std::fmt::Display for NmeaError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+impl core::fmt::Display for NmeaError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            NmeaError::BadChecksum { expected, actual } => write!(
+                f,
+                "Bad checksum: expected {:02X}, found {:02X}",
+                expected, actual
+            ),
+            NmeaError::MalformedChecksum(raw) => write!(f, "Malformed checksum \"{}\"", raw),
+            NmeaError::MissingChecksum => write!(f, "Missing checksum"),
+            NmeaError::MissingField(index) => write!(f, "Missing field {}", index),
+            NmeaError::InvalidField { index, raw } => {
+                write!(f, "Could not parse field {} \"{}\"", index, raw)
+            }
+            NmeaError::UnknownUnits { index, raw } => {
+                write!(f, "Unknown units in field {} \"{}\"", index, raw)
+            }
+            NmeaError::DataInvalid => write!(f, "Data flagged as not valid"),
+            NmeaError::NotPresent => write!(f, "Value not present in sentence"),
+            NmeaError::IndexOutOfRange(index) => write!(f, "Index {} out of range", index),
+            NmeaError::UnknownMessageType(t) => write!(f, "Unknown message type {}", t),
+            NmeaError::Truncated => write!(f, "Truncated sentence"),
+            NmeaError::LineTooLong(length) => {
+                write!(f, "Line of {} characters exceeds the length limit", length)
+            }
+            NmeaError::TooManyFields(count) => write!(
+                f,
+                "Sentence has {} fields, more than the {} supported",
+                count, MAX_FIELDS
+            ),
+            NmeaError::MissingDelimiter => write!(f, "Sentence does not start with '$' or '!'"),
+            NmeaError::UnknownHeader => write!(f, "Datagram does not start with a known header"),
+            NmeaError::DatagramTooLarge(length) => {
+                write!(f, "Datagram of {} bytes exceeds the size limit", length)
+            }
+            #[cfg(feature = "std")]
+            NmeaError::Io(e) => write!(f, "{}", e),
+        }
     }
 }
 
-impl std::error::Error for NmeaError {}
+impl core::error::Error for NmeaError {
+    #[cfg(feature = "std")]
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            NmeaError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
-#[derive(Debug, Clone)]
-pub struct Nmea0183Base {
-    pub sender: String,
-    pub message: String,
-    pub parameters: Vec<String>,
-    pub checksum: u8,
+#[cfg(feature = "std")]
+impl From<io::Error> for NmeaError {
+    fn from(e: io::Error) -> NmeaError {
+        NmeaError::Io(e)
+    }
 }
 
-impl Nmea0183Base {
-    pub fn new() -> Nmea0183Base {
-        Nmea0183Base {
-            sender: String::new(),
-            message: String::new(),
-            parameters: Vec::new(),
-            checksum: 0,
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ChecksumPolicy {
+    #[default]
+    Strict,
+    Lenient,
+    Permissive,
+}
+
+impl ChecksumPolicy {
+    pub fn check(&self, expected: Option<u8>, actual: u8) -> Result<ChecksumStatus, NmeaError> {
+        match (self, expected) {
+            (ChecksumPolicy::Strict, None) => Err(NmeaError::MissingChecksum),
+            (_, None) => Ok(ChecksumStatus::Missing),
+            (_, Some(expected)) if expected == actual => Ok(ChecksumStatus::Valid),
+            (ChecksumPolicy::Permissive, Some(_)) => Ok(ChecksumStatus::Mismatch),
+            (_, Some(expected)) => Err(NmeaError::BadChecksum { expected, actual }),
         }
     }
+}
 
-    pub fn from_reader(reader: &mut Box<dyn io::BufRead>) -> Result<Nmea0183Base, io::Error> {
-        loop {
-            let mut buffer = String::new();
-            match reader.read_line(&mut buffer) {
-                Ok(n) => {
-                    if n > 0 {
-                        return Nmea0183Base::from_string(&buffer);
-                    } else {
-                        return Err(io::Error::new(std::io::ErrorKind::UnexpectedEof, "EOF"));
-                    }
-                }
-                Err(e) => return Err(e),
-            }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ChecksumStatus {
+    Valid,
+    Missing,
+    Mismatch,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FieldText {
+    bytes: [u8; MAX_FIELD_TEXT],
+    length: usize,
+}
+
+impl FieldText {
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.length]).unwrap_or_default()
+    }
+}
+
+impl From<&str> for FieldText {
+    fn from(text: &str) -> FieldText {
+        let mut length = text.len().min(MAX_FIELD_TEXT);
+        while !text.is_char_boundary(length) {
+            length -= 1;
         }
+        let mut bytes = [0; MAX_FIELD_TEXT];
+        bytes[..length].copy_from_slice(&text.as_bytes()[..length]);
+        FieldText { bytes, length }
     }
+}
 
-    pub fn from_string(line: &String) -> Result<Nmea0183Base, io::Error> {
-        let mut parts = line
-            .trim()
-            .split('*')
-            .map(|x| String::from(x))
-            .collect::<Vec<String>>();
-        let checksum = parts.pop().unwrap();
-        let mut parts = parts[0]
-            .trim()
-            .split(',')
-            .map(|x| String::from(x))
-            .collect::<Vec<String>>();
-        if parts.len() == 0 {
-            return Err(io::Error::new(
-                std::io::ErrorKind::InvalidData,
-                line.to_string(),
-            ));
-        };
-        let introducer = parts.remove(0);
-        let talker = &introducer[..3];
-        let message = &introducer[3..];
-        let nmea = Nmea0183Base {
-            sender: String::from(talker),
-            message: String::from(message),
-            parameters: parts,
-            checksum: u8::from_str_radix(checksum.as_str(), 16).unwrap(),
-        };
-        Ok(nmea)
+impl core::fmt::Display for FieldText {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
     }
+}
+
+pub trait SentenceFields {
+    fn delimiter(&self) -> char;
+    fn sender(&self) -> &str;
+    fn message(&self) -> &str;
+    fn checksum(&self) -> u8;
+    fn checksum_status(&self) -> ChecksumStatus;
+    fn field_count(&self) -> usize;
+    fn field(&self, n: usize) -> Result<&str, NmeaError>;
 
-    pub fn is_valid(&self) -> bool {
-        self.calculate_checksum() == self.checksum
+    fn talker_id(&self) -> Option<TalkerId> {
+        TalkerId::from_code(self.sender())
     }
 
-    pub fn parameter<T: FromStr>(&self, n: usize) -> Result<T, NmeaError> {
-        if self.parameters.len() > n {
-            match self.parameters[n].parse::<T>() {
-                Ok(n) => Ok(n),
-                Err(_) => Err(NmeaError(format!("Could not parse {}", self.parameters[n]))),
-            }
+    fn sentence_type(&self) -> Option<SentenceType> {
+        if self.is_proprietary() {
+            return None;
+        }
+        SentenceType::from_message(self.message())
+    }
+
+    fn is_proprietary(&self) -> bool {
+        self.sender() == "P"
+    }
+
+    fn manufacturer(&self) -> Option<&str> {
+        if self.is_proprietary() {
+            self.message().get(..3)
         } else {
-            Err(NmeaError(INSUFFICIENT_NUMBER_OF_PARAMETERS.to_string()))
+            None
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.calculate_checksum() == self.checksum()
+    }
+
+    fn required_field(&self, n: usize) -> Result<&str, NmeaError> {
+        match self.field(n)? {
+            "" => Err(NmeaError::MissingField(n)),
+            p => Ok(p),
         }
     }
 
-    pub fn naive_time(&self, n: usize) -> NaiveTimeError {
-        match NaiveTime::parse_from_str(self.parameters[n].as_str(), "%H%M%S%.f") {
-            Err(e) => return Err(NmeaError(e.to_string())),
+    fn field_str(&self, n: usize) -> &str {
+        self.field(n).unwrap_or_default()
+    }
+
+    fn field_char(&self, n: usize) -> Result<char, NmeaError> {
+        self.required_field(n)?
+            .chars()
+            .next()
+            .ok_or(NmeaError::MissingField(n))
+    }
+
+    fn invalid_field(&self, n: usize) -> NmeaError {
+        NmeaError::InvalidField {
+            index: n,
+            raw: RawText::from(self.field_str(n)),
+        }
+    }
+
+    fn unknown_units(&self, n: usize) -> NmeaError {
+        NmeaError::UnknownUnits {
+            index: n,
+            raw: RawText::from(self.field_str(n)),
+        }
+    }
+
+    fn parameter<T: FromStr>(&self, n: usize) -> Result<T, NmeaError> {
+        match self.required_field(n)?.parse::<T>() {
+            Ok(n) => Ok(n),
+            Err(_) => Err(self.invalid_field(n)),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn naive_time(&self, n: usize) -> NaiveTimeError {
+        match NaiveTime::parse_from_str(self.required_field(n)?, "%H%M%S%.f") {
+            Err(_) => Err(self.invalid_field(n)),
             Ok(t) => Ok(t),
         }
     }
 
-    pub fn naive_date(&self, n: usize) -> NaiveDateError {
-        match NaiveDate::parse_from_str(self.parameters[n].as_str(), "%d%m%y") {
-            Err(e) => return Err(NmeaError(e.to_string())),
+    #[cfg(feature = "std")]
+    fn naive_date(&self, n: usize) -> NaiveDateError {
+        match NaiveDate::parse_from_str(self.required_field(n)?, "%d%m%y") {
+            Err(_) => Err(self.invalid_field(n)),
             Ok(t) => Ok(t),
         }
     }
 
-    pub fn position(&self, n: usize) -> PositionError {
-        let latitude = if self.parameters[n + 1] == "N" {
-            self.parameter::<f64>(n)?
-        } else {
-            -self.parameter::<f64>(n)?
-        };
-        let longitude = if self.parameters[n + 3] == "E" {
-            self.parameter::<f64>(n + 2)?
-        } else {
-            -self.parameter::<f64>(n + 2)?
+    fn coordinates(&self, n: usize) -> Result<(f64, f64), NmeaError> {
+        let latitude = self.parameter::<f64>(n)?;
+        let longitude = self.parameter::<f64>(n + 2)?;
+        let latitude = degrees_from_nmea(latitude);
+        let longitude = degrees_from_nmea(longitude);
+        let latitude = match self.field_str(n + 1) {
+            "N" => latitude,
+            "S" => -latitude,
+            _ => return Err(self.invalid_field(n + 1)),
         };
-        let latitude_degrees = if latitude >= 0.0 {
-            (latitude / 100.0).floor()
-        } else {
-            (latitude / 100.0).ceil()
+        let longitude = match self.field_str(n + 3) {
+            "E" => longitude,
+            "W" => -longitude,
+            _ => return Err(self.invalid_field(n + 3)),
         };
-        let longitude_degrees = if longitude >= 0.0 {
-            (longitude / 100.0).floor()
-        } else {
-            (longitude / 100.0).ceil()
-        };
-        let latitude_minutes = (latitude - latitude_degrees * 100.0).abs();
-        let longitude_minutes = (longitude - longitude_degrees * 100.0).abs();
-        Ok(Position::from_degrees_decimal_minutes(
-            latitude_degrees as i8,
-            latitude_minutes,
-            longitude_degrees as i8,
-            longitude_minutes,
-        ))
+        Ok((latitude, longitude))
     }
 
-    pub fn calculate_checksum(&self) -> u8 {
+    #[cfg(feature = "std")]
+    fn position(&self, n: usize) -> PositionError {
+        let (latitude, longitude) = self.coordinates(n)?;
+        Ok(position_from_degrees(latitude, longitude))
+    }
+
+    fn calculate_checksum(&self) -> u8 {
         let mut checksum = 0;
-        for c in self.sender.as_bytes() {
+        for c in self.sender().as_bytes() {
             checksum ^= c;
         }
-        for c in self.message.as_bytes() {
+        for c in self.message().as_bytes() {
             checksum ^= c;
         }
-        for p in &self.parameters {
-            for c in p.as_bytes() {
+        for n in 0..self.field_count() {
+            checksum ^= b',';
+            for c in self.field_str(n).as_bytes() {
                 checksum ^= c;
             }
         }
         checksum
     }
 
+    #[cfg(feature = "std")]
+    fn to_sentence(&self) -> String {
+        let mut sentence = String::new();
+        sentence.push(self.delimiter());
+        sentence.push_str(self.sender());
+        sentence.push_str(self.message());
+        for n in 0..self.field_count() {
+            sentence.push(',');
+            sentence.push_str(self.field_str(n));
+        }
+        format!("{}*{:02X}\r\n", sentence, self.calculate_checksum())
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Nmea0183Base {
+    pub delimiter: char,
+    pub sender: String,
+    pub message: String,
+    pub parameters: Vec<String>,
+    pub checksum: u8,
+    pub checksum_status: ChecksumStatus,
+}
+
+#[cfg(feature = "std")]
+impl Nmea0183Base {
+    pub fn new() -> Nmea0183Base {
+        Nmea0183Base {
+            delimiter: '$',
+            sender: String::new(),
+            message: String::new(),
+            parameters: Vec::new(),
+            checksum: 0,
+            checksum_status: ChecksumStatus::Missing,
+        }
+    }
+
+    pub fn from_parts(sender: &str, message: &str, parameters: Vec<String>) -> Nmea0183Base {
+        let (delimiter, sender) = match sender.strip_prefix('!') {
+            Some(sender) => ('!', sender),
+            None => ('$', sender.trim_start_matches('$')),
+        };
+        let mut base = Nmea0183Base {
+            delimiter,
+            sender: String::from(sender),
+            message: String::from(message),
+            parameters,
+            checksum: 0,
+            checksum_status: ChecksumStatus::Valid,
+        };
+        base.checksum = base.calculate_checksum();
+        base
+    }
+
+    pub fn from_reader(reader: &mut Box<dyn io::BufRead>) -> Result<Nmea0183Base, NmeaError> {
+        Nmea0183Base::from_reader_with(reader, ChecksumPolicy::default())
+    }
+
+    pub fn from_reader_with(
+        reader: &mut Box<dyn io::BufRead>,
+        policy: ChecksumPolicy,
+    ) -> Result<Nmea0183Base, NmeaError> {
+        loop {
+            let mut buffer = String::new();
+            match reader.read_line(&mut buffer) {
+                Ok(n) => {
+                    if n > 0 {
+                        return Nmea0183Base::from_string_with(&buffer, policy);
+                    } else {
+                        return Err(NmeaError::Io(io::Error::new(
+                            std::io::ErrorKind::UnexpectedEof,
+                            "EOF",
+                        )));
+                    }
+                }
+                Err(e) => return Err(NmeaError::Io(e)),
+            }
+        }
+    }
+
+    pub fn from_string(line: &String) -> Result<Nmea0183Base, NmeaError> {
+        Nmea0183Base::from_string_with(line, ChecksumPolicy::default())
+    }
+
+    pub fn from_string_with(line: &str, policy: ChecksumPolicy) -> Result<Nmea0183Base, NmeaError> {
+        SentenceRef::parse_with(line, policy).map(|s| s.to_base())
+    }
+
     pub fn to_string(&self) -> Option<String> {
         if self.is_valid() {
             let retval = format!(
-                "{}{}{}*{:02X}",
+                "{}{}{}{}*{:02X}",
+                self.delimiter,
                 self.sender,
                 self.message,
                 self.parameters.iter().fold(String::new(), |acc, x| {
@@ -194,6 +434,386 @@ impl Nmea0183Base {
     }
 }
 
+#[cfg(feature = "std")]
+impl SentenceFields for Nmea0183Base {
+    fn delimiter(&self) -> char {
+        self.delimiter
+    }
+
+    fn sender(&self) -> &str {
+        &self.sender
+    }
+
+    fn message(&self) -> &str {
+        &self.message
+    }
+
+    fn checksum(&self) -> u8 {
+        self.checksum
+    }
+
+    fn checksum_status(&self) -> ChecksumStatus {
+        self.checksum_status
+    }
+
+    fn field_count(&self) -> usize {
+        self.parameters.len()
+    }
+
+    fn field(&self, n: usize) -> Result<&str, NmeaError> {
+        match self.parameters.get(n) {
+            Some(p) => Ok(p.as_str()),
+            None => Err(NmeaError::MissingField(n)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SentenceRef<'a> {
+    delimiter: char,
+    sender: &'a str,
+    message: &'a str,
+    parameters: &'a str,
+    ends: [u16; MAX_FIELDS],
+    count: usize,
+    checksum: u8,
+    checksum_status: ChecksumStatus,
+}
+
+impl<'a> SentenceRef<'a> {
+    pub fn parse(line: &'a str) -> Result<SentenceRef<'a>, NmeaError> {
+        SentenceRef::parse_with(line, ChecksumPolicy::default())
+    }
+
+    pub fn parse_with(line: &'a str, policy: ChecksumPolicy) -> Result<SentenceRef<'a>, NmeaError> {
+        let line = line.trim();
+        if line.len() > u16::MAX as usize {
+            return Err(NmeaError::LineTooLong(line.len() + 2));
+        }
+        let (body, checksum) = match line.split_once('*') {
+            Some((body, checksum)) if !checksum.trim().is_empty() => {
+                let checksum = u8::from_str_radix(checksum.trim(), 16)
+                    .map_err(|_| NmeaError::MalformedChecksum(RawText::from(checksum)))?;
+                (body, Some(checksum))
+            }
+            Some((body, _)) => (body, None),
+            None => (line, None),
+        };
+        let (introducer, parameters) = match body.trim().split_once(',') {
+            Some((introducer, parameters)) => (introducer, Some(parameters)),
+            None => (body.trim(), None),
+        };
+        let (delimiter, introducer) = match introducer.chars().next() {
+            Some(delimiter @ ('$' | '!')) => (delimiter, &introducer[1..]),
+            _ => return Err(NmeaError::MissingDelimiter),
+        };
+        let split = if introducer.starts_with('P') { 1 } else { 2 };
+        let sender = introducer.get(..split).ok_or(NmeaError::Truncated)?;
+        let message = introducer.get(split..).ok_or(NmeaError::Truncated)?;
+        if message.is_empty() {
+            return Err(NmeaError::Truncated);
+        }
+        let mut sentence = SentenceRef {
+            delimiter,
+            sender,
+            message,
+            parameters: parameters.unwrap_or_default(),
+            ends: [0; MAX_FIELDS],
+            count: 0,
+            checksum: 0,
+            checksum_status: ChecksumStatus::Valid,
+        };
+        if let Some(parameters) = parameters {
+            let count = parameters.bytes().filter(|b| *b == b',').count() + 1;
+            if count > MAX_FIELDS {
+                return Err(NmeaError::TooManyFields(count));
+            }
+            let commas = parameters.bytes().enumerate().filter(|(_, b)| *b == b',');
+            for end in commas.map(|(i, _)| i).chain([parameters.len()]) {
+                sentence.ends[sentence.count] = end as u16;
+                sentence.count += 1;
+            }
+        }
+        let actual = sentence.calculate_checksum();
+        sentence.checksum = checksum.unwrap_or(actual);
+        sentence.checksum_status = policy.check(checksum, actual)?;
+        Ok(sentence)
+    }
+
+    pub fn fields(&self) -> impl Iterator<Item = &'a str> + '_ {
+        (0..self.count).filter_map(|n| self.get(n))
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_base(&self) -> Nmea0183Base {
+        Nmea0183Base {
+            delimiter: self.delimiter,
+            sender: String::from(self.sender),
+            message: String::from(self.message),
+            parameters: self.fields().map(String::from).collect(),
+            checksum: self.checksum,
+            checksum_status: self.checksum_status,
+        }
+    }
+
+    fn get(&self, n: usize) -> Option<&'a str> {
+        let start = match n {
+            0 => 0,
+            n => *self.ends.get(n - 1)? as usize + 1,
+        };
+        let end = *self.ends[..self.count].get(n)? as usize;
+        self.parameters.get(start..end)
+    }
+}
+
+impl SentenceFields for SentenceRef<'_> {
+    fn delimiter(&self) -> char {
+        self.delimiter
+    }
+
+    fn sender(&self) -> &str {
+        self.sender
+    }
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn checksum(&self) -> u8 {
+        self.checksum
+    }
+
+    fn checksum_status(&self) -> ChecksumStatus {
+        self.checksum_status
+    }
+
+    fn field_count(&self) -> usize {
+        self.count
+    }
+
+    fn field(&self, n: usize) -> Result<&str, NmeaError> {
+        self.get(n).ok_or(NmeaError::MissingField(n))
+    }
+}
+
+pub(crate) fn degrees_from_nmea(value: f64) -> f64 {
+    let degrees = (value / 100.0) as i64 as f64;
+    degrees + (value - degrees * 100.0) / 60.0
+}
+
+#[cfg(feature = "std")]
+pub fn position_from_degrees(latitude: f64, longitude: f64) -> Position {
+    let (latitude_degrees, latitude_minutes) = degrees_and_minutes(latitude);
+    let (longitude_degrees, longitude_minutes) = degrees_and_minutes(longitude);
+    Position::from_degrees_decimal_minutes(
+        latitude_degrees,
+        latitude_minutes,
+        longitude_degrees,
+        longitude_minutes,
+    )
+}
+
+// Position takes whole degrees as an i8 and the sign from the degrees, so
+// anything past 127 degrees is carried in the minutes, and values between -1
+// and 0 pass negative minutes with zero degrees.
+#[cfg(feature = "std")]
+fn degrees_and_minutes(value: f64) -> (i8, f64) {
+    let degrees = value.trunc().clamp(-127.0, 127.0);
+    let minutes = (value - degrees) * 60.0;
+    if degrees == 0.0 {
+        (0, minutes)
+    } else {
+        (degrees as i8, minutes.abs())
+    }
+}
+
+#[cfg(feature = "std")]
+pub fn format_value<T: core::fmt::Display>(value: Option<T>) -> String {
+    match value {
+        Some(v) => v.to_string(),
+        None => String::new(),
+    }
+}
+
+#[cfg(feature = "std")]
+pub fn format_float(value: Option<f32>, precision: usize) -> String {
+    match value {
+        Some(v) => format!("{:.*}", precision, v),
+        None => String::new(),
+    }
+}
+
+#[cfg(feature = "std")]
+pub fn format_signed(
+    value: Option<f32>,
+    precision: usize,
+    positive: char,
+    negative: char,
+) -> [String; 2] {
+    match value {
+        Some(v) if v < 0.0 => [format!("{:.*}", precision, -v), negative.to_string()],
+        Some(v) => [format!("{:.*}", precision, v), positive.to_string()],
+        None => [String::new(), String::new()],
+    }
+}
+
+#[cfg(feature = "std")]
+pub fn format_time(time: Option<NaiveTime>) -> String {
+    match time {
+        Some(t) => t.format("%H%M%S%.3f").to_string(),
+        None => String::new(),
+    }
+}
+
+#[cfg(feature = "std")]
+pub fn format_date(date: Option<NaiveDate>) -> String {
+    match date {
+        Some(d) => d.format("%d%m%y").to_string(),
+        None => String::new(),
+    }
+}
+
+#[cfg(feature = "std")]
+pub fn format_position(position: Option<&Position>) -> [String; 4] {
+    match position {
+        Some(p) => {
+            let latitude = p.latitude();
+            let longitude = p.longitude();
+            [
+                format_coordinate(latitude, 2),
+                String::from(if latitude < 0.0 { "S" } else { "N" }),
+                format_coordinate(longitude, 3),
+                String::from(if longitude < 0.0 { "W" } else { "E" }),
+            ]
+        }
+        None => Default::default(),
+    }
+}
+
+#[cfg(feature = "std")]
+fn format_coordinate(degrees: f64, width: usize) -> String {
+    let ten_thousandths = (degrees.abs() * 600_000.0).round() as u64;
+    let minutes = ten_thousandths % 600_000;
+    format!(
+        "{:0width$}{:02}.{:04}",
+        ten_thousandths / 600_000,
+        minutes / 10_000,
+        minutes % 10_000
+    )
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+pub(crate) struct SerializedPosition {
+    latitude: f64,
+    longitude: f64,
+}
+
+#[cfg(feature = "serde")]
+impl From<&Position> for SerializedPosition {
+    fn from(position: &Position) -> SerializedPosition {
+        SerializedPosition {
+            latitude: position.latitude(),
+            longitude: position.longitude(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+pub(crate) fn serialize_position<S: serde::Serializer>(
+    position: &Position,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serde::Serialize::serialize(&SerializedPosition::from(position), serializer)
+}
+
+#[cfg(feature = "serde")]
+pub(crate) fn serialize_optional_position<S: serde::Serializer>(
+    position: &Option<Position>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serde::Serialize::serialize(&position.as_ref().map(SerializedPosition::from), serializer)
+}
+
+#[cfg(feature = "serde")]
+fn serialize_quantity<S: serde::Serializer>(
+    serializer: S,
+    value: f32,
+    unit: &str,
+) -> Result<S::Ok, S::Error> {
+    use serde::ser::SerializeStruct;
+    let mut state = serializer.serialize_struct("Quantity", 2)?;
+    state.serialize_field("value", &value)?;
+    state.serialize_field("unit", unit)?;
+    state.end()
+}
+
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Degrees(pub f32);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Degrees {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_quantity(serializer, self.0, "deg")
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Seconds(pub f32);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Seconds {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_quantity(serializer, self.0, "s")
+    }
+}
+
+#[cfg(feature = "serde")]
+pub(crate) fn serialize_degrees<S: serde::Serializer>(
+    degrees: &f32,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_quantity(serializer, *degrees, "deg")
+}
+
+#[cfg(feature = "serde")]
+pub(crate) fn serialize_optional_degrees<T: Copy + Into<f32>, S: serde::Serializer>(
+    degrees: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serde::Serialize::serialize(&degrees.map(|d| Degrees(d.into())), serializer)
+}
+
+#[cfg(feature = "serde")]
+pub(crate) fn deserialize_degrees<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<f32, D::Error> {
+    deserialize_quantity(deserializer, "deg")
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_quantity<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+    unit: &str,
+) -> Result<f32, D::Error> {
+    #[derive(serde::Deserialize)]
+    struct Quantity {
+        value: f32,
+        unit: String,
+    }
+    let quantity = <Quantity as serde::Deserialize>::deserialize(deserializer)?;
+    if quantity.unit != unit {
+        return Err(serde::de::Error::custom(format!(
+            "expected unit \"{}\", found \"{}\"",
+            unit, quantity.unit
+        )));
+    }
+    Ok(quantity.value)
+}
+
+#[derive(Debug, Clone, Copy)]
 pub struct Temperature {
     celsius: f32,
 }
@@ -217,7 +837,7 @@ impl Temperature {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct Distance {
     meters: f32,
 }
@@ -261,14 +881,17 @@ impl Distance {
         self.meters * Distance::METERS_2_MILES
     }
     pub fn as_feet(&self) -> f32 {
-        self.as_miles() / 5280.0
+        self.as_miles() * 5280.0
+    }
+    pub fn as_fathoms(&self) -> f32 {
+        self.as_feet() / 6.0
     }
     pub fn as_nautical_mile(&self) -> f32 {
         self.meters * Distance::METERS_2_NM
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct Speed {
     meters_per_second: f32,
 }
@@ -307,6 +930,7 @@ impl Speed {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
 pub struct Pressure {
     bar: f32,
 }
@@ -354,3 +978,360 @@ impl Pressure {
         self.bar / Pressure::KPA_2_BAR
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Temperature {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_quantity(serializer, self.celsius, "°C")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Temperature {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Temperature, D::Error> {
+        Ok(Temperature::from_celsius(deserialize_quantity(
+            deserializer,
+            "°C",
+        )?))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Distance {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_quantity(serializer, self.meters, "m")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Distance {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Distance, D::Error> {
+        Ok(Distance::from_meters(deserialize_quantity(
+            deserializer,
+            "m",
+        )?))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Speed {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_quantity(serializer, self.meters_per_second, "m/s")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Speed {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Speed, D::Error> {
+        Ok(Speed::from_mps(deserialize_quantity(deserializer, "m/s")?))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Pressure {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_quantity(serializer, self.as_kpa() * 1000.0, "Pa")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Pressure {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Pressure, D::Error> {
+        Ok(Pressure::from_kilo_pascals(
+            deserialize_quantity(deserializer, "Pa")? / 1000.0,
+        ))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::gps::gll::Gll;
+
+    #[test]
+    fn format_position_hemispheres() {
+        let position = position_from_degrees(48.1173, 11.516_666_666);
+        assert_eq!(
+            format_position(Some(&position)),
+            ["4807.0380", "N", "01131.0000", "E"]
+        );
+        assert_eq!(format_position(None), ["", "", "", ""]);
+    }
+
+    #[test]
+    fn format_position_carries_rounded_minutes() {
+        let position = position_from_degrees(47.0 + 59.99999 / 60.0, -(8.0 + 59.99999 / 60.0));
+        assert_eq!(
+            format_position(Some(&position)),
+            ["4800.0000", "N", "00900.0000", "W"]
+        );
+    }
+
+    #[test]
+    fn format_position_round_trips() {
+        let position = position_from_degrees(47.0 + 59.99999 / 60.0, 8.25);
+        let gll = Gll::builder().position(position).valid(true).build();
+        let parsed = Nmea0183Base::from_string(&gll.to_sentence()).unwrap();
+        let position = Gll::new(parsed).position().unwrap();
+        assert!((position.latitude() - 48.0).abs() < 1e-9);
+        assert!((position.longitude() - 8.25).abs() < 1e-9);
+    }
+
+    fn assert_position(position: Position, latitude: f64, longitude: f64) {
+        assert!(
+            (position.latitude() - latitude).abs() < 1e-9,
+            "{position:?}"
+        );
+        assert!(
+            (position.longitude() - longitude).abs() < 1e-9,
+            "{position:?}"
+        );
+    }
+
+    #[test]
+    fn position_from_degrees_keeps_sign_and_range() {
+        assert_position(
+            position_from_degrees(-33.8688, 151.2093),
+            -33.8688,
+            151.2093,
+        );
+        assert_position(position_from_degrees(-0.5, 0.5), -0.5, 0.5);
+        assert_position(position_from_degrees(0.5, -0.5), 0.5, -0.5);
+        assert_position(position_from_degrees(-89.999, -179.999), -89.999, -179.999);
+        assert_position(position_from_degrees(0.0, 0.0), 0.0, 0.0);
+    }
+
+    #[test]
+    fn parsed_positions_keep_sign_and_range() {
+        let parse = |line: &str| {
+            Nmea0183Base::from_string(&line.to_string())
+                .unwrap()
+                .position(0)
+                .unwrap()
+        };
+        assert_position(parse("$GPGLL,0030.00,S,00030.00,W,225444,A*24"), -0.5, -0.5);
+        assert_position(
+            parse("$GPGLL,3352.1280,S,15112.5580,E,225444,A*34"),
+            -33.8688,
+            151.2093,
+        );
+    }
+
+    #[test]
+    fn position_rejects_invalid_hemisphere() {
+        let empty =
+            Nmea0183Base::from_string(&"$GPGLL,4916.45,,12311.12,W,225444,A*7F".to_string())
+                .unwrap();
+        assert!(matches!(
+            empty.position(0),
+            Err(NmeaError::InvalidField { index: 1, .. })
+        ));
+        let garbage =
+            Nmea0183Base::from_string(&"$GPGLL,4916.45,N,12311.12,X,225444,A*3E".to_string())
+                .unwrap();
+        assert!(matches!(
+            garbage.position(0),
+            Err(NmeaError::InvalidField { index: 3, .. })
+        ));
+        let valid =
+            Nmea0183Base::from_string(&"$GPGLL,4916.45,N,12311.12,W,225444,A*31".to_string())
+                .unwrap();
+        assert!(valid.position(0).unwrap().longitude() < 0.0);
+    }
+
+    #[test]
+    fn sydney_round_trips_through_format_position() {
+        let position = position_from_degrees(-33.8688, 151.2093);
+        assert_eq!(
+            format_position(Some(&position)),
+            ["3352.1280", "S", "15112.5580", "E"]
+        );
+    }
+
+    #[test]
+    fn field_text_truncates_on_char_boundaries() {
+        assert_eq!(FieldText::from("274.07").as_str(), "274.07");
+        assert_eq!(FieldText::from("").to_string(), "");
+        let long = "0123456789abcdefghij";
+        assert_eq!(FieldText::from(long).as_str(), &long[..MAX_FIELD_TEXT]);
+        let wide = "\u{e9}".repeat(MAX_FIELD_TEXT);
+        let text = FieldText::from(wide.as_str());
+        assert_eq!(text.as_str(), "\u{e9}".repeat(MAX_FIELD_TEXT / 2));
+        assert_eq!(text, FieldText::from(text.as_str()));
+    }
+
+    #[test]
+    fn coordinates_need_no_allocation() {
+        let sentence = SentenceRef::parse("$GPGLL,0030.00,S,00030.00,W,225444,A*24").unwrap();
+        assert_eq!(sentence.coordinates(0).unwrap(), (-0.5, -0.5));
+        let sentence = SentenceRef::parse("$GPGLL,4916.45,N,12311.12,X,225444,A*3E").unwrap();
+        assert!(matches!(
+            sentence.coordinates(0),
+            Err(NmeaError::InvalidField { index: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn checksum_policies() {
+        use ChecksumPolicy::*;
+        assert_eq!(ChecksumPolicy::default(), Strict);
+        assert!(matches!(
+            Strict.check(None, 0x19),
+            Err(NmeaError::MissingChecksum)
+        ));
+        assert_eq!(Lenient.check(None, 0x19).unwrap(), ChecksumStatus::Missing);
+        assert_eq!(
+            Permissive.check(None, 0x19).unwrap(),
+            ChecksumStatus::Missing
+        );
+        for policy in [Strict, Lenient, Permissive] {
+            assert_eq!(
+                policy.check(Some(0x19), 0x19).unwrap(),
+                ChecksumStatus::Valid
+            );
+        }
+        assert!(matches!(
+            Lenient.check(Some(0x18), 0x19),
+            Err(NmeaError::BadChecksum {
+                expected: 0x18,
+                actual: 0x19,
+            })
+        ));
+        assert_eq!(
+            Permissive.check(Some(0x18), 0x19).unwrap(),
+            ChecksumStatus::Mismatch
+        );
+    }
+
+    #[test]
+    fn parsed_sentences_record_checksum_status() {
+        let parse = |line: &str, policy| Nmea0183Base::from_string_with(line, policy);
+        let missing = parse("$HEHDT,274.07,T", ChecksumPolicy::Lenient).unwrap();
+        assert_eq!(missing.checksum_status, ChecksumStatus::Missing);
+        assert!(missing.is_valid());
+        let missing = parse("$HEHDT,274.07,T*", ChecksumPolicy::Lenient).unwrap();
+        assert_eq!(missing.checksum_status, ChecksumStatus::Missing);
+
+        let mismatch = parse("$HEHDT,274.07,T*18", ChecksumPolicy::Permissive).unwrap();
+        assert_eq!(mismatch.checksum_status, ChecksumStatus::Mismatch);
+        assert_eq!(mismatch.checksum, 0x18);
+        assert!(!mismatch.is_valid());
+        assert_eq!(mismatch.to_sentence(), "$HEHDT,274.07,T*19\r\n");
+
+        let valid = parse("$HEHDT,274.07,T*19", ChecksumPolicy::Strict).unwrap();
+        assert_eq!(valid.checksum_status, ChecksumStatus::Valid);
+        assert!(parse("$HEHDT,274.07,T*18", ChecksumPolicy::Lenient).is_err());
+    }
+
+    #[test]
+    fn readers_apply_checksum_policy() {
+        let mut reader: Box<dyn io::BufRead> =
+            Box::new(io::Cursor::new("$HEHDT,274.07,T\r\n$HEHDT,274.07,T\r\n"));
+        assert!(matches!(
+            Nmea0183Base::from_reader(&mut reader),
+            Err(NmeaError::MissingChecksum)
+        ));
+        let base = Nmea0183Base::from_reader_with(&mut reader, ChecksumPolicy::Lenient).unwrap();
+        assert_eq!(base.checksum_status, ChecksumStatus::Missing);
+        assert!(matches!(
+            Nmea0183Base::from_reader(&mut reader),
+            Err(NmeaError::Io(e)) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+    }
+
+    #[test]
+    fn format_helpers() {
+        assert_eq!(format_value(Some('A')), "A");
+        assert_eq!(format_value::<u8>(None), "");
+        assert_eq!(format_float(Some(1.25), 1), "1.2");
+        assert_eq!(format_signed(Some(-3.1), 1, 'E', 'W'), ["3.1", "W"]);
+        assert_eq!(format_signed(Some(3.1), 1, 'E', 'W'), ["3.1", "E"]);
+        assert_eq!(
+            format_time(NaiveTime::from_hms_milli_opt(12, 35, 19, 500)),
+            "123519.500"
+        );
+        assert_eq!(format_date(NaiveDate::from_ymd_opt(1994, 3, 23)), "230394");
+    }
+
+    const PUBX: &str = "$PUBX,00,081350.00,4717.113210,N,00833.915187,E,546.589,G3,2.1,2.0,0.007,77.52,0.007,,0.92,1.19,0.77,9,0,0*5F";
+
+    #[test]
+    fn bad_checksum_reports_expected_and_actual() {
+        let error = Nmea0183Base::from_string(&"$HEHDT,274.07,T*18".to_string()).unwrap_err();
+        assert!(matches!(
+            error,
+            NmeaError::BadChecksum {
+                expected: 0x18,
+                actual: 0x19
+            }
+        ));
+        assert_eq!(error.to_string(), "Bad checksum: expected 18, found 19");
+    }
+
+    #[test]
+    fn parse_errors_are_categorized() {
+        let parse = |line: &str| Nmea0183Base::from_string(&line.to_string()).unwrap_err();
+        assert!(
+            matches!(parse("$HEHDT,274.07,T*G1"), NmeaError::MalformedChecksum(raw) if raw == "G1")
+        );
+        assert!(matches!(parse("$GP*17"), NmeaError::Truncated));
+        assert!(matches!(
+            parse("HEHDT,274.07,T*19"),
+            NmeaError::MissingDelimiter
+        ));
+        assert!(matches!(
+            parse("$HEHDT,274.07,T"),
+            NmeaError::MissingChecksum
+        ));
+    }
+
+    #[test]
+    fn field_errors_carry_index_and_raw_text() {
+        let base = Nmea0183Base::from_string(&"$HEHDT,27x,T*7C".to_string()).unwrap();
+        match base.parameter::<f32>(0) {
+            Err(NmeaError::InvalidField { index: 0, raw }) => assert_eq!(raw, "27x"),
+            other => panic!("unexpected {other:?}"),
+        }
+        assert!(matches!(base.field(2), Err(NmeaError::MissingField(2))));
+        assert!(matches!(
+            base.unknown_units(1),
+            NmeaError::UnknownUnits { index: 1, raw } if raw == "T"
+        ));
+    }
+
+    #[test]
+    fn reader_errors_wrap_io() {
+        let mut reader: Box<dyn io::BufRead> = Box::new(io::empty());
+        match Nmea0183Base::from_reader(&mut reader) {
+            Err(NmeaError::Io(e)) => assert_eq!(e.kind(), io::ErrorKind::UnexpectedEof),
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    #[test]
+    fn long_proprietary_lines_parse() {
+        assert!(PUBX.len() > MAX_SENTENCE_LENGTH);
+        let base = Nmea0183Base::from_string(&PUBX.to_string()).unwrap();
+        assert_eq!(base.manufacturer(), Some("UBX"));
+        assert_eq!(base.field_count(), 20);
+        assert_eq!(base.field_str(19), "0");
+        let sentence = SentenceRef::parse(PUBX).unwrap();
+        assert_eq!(sentence.fields().count(), 20);
+        assert_eq!(sentence.field_str(2), "4717.113210");
+    }
+
+    #[test]
+    fn too_many_fields_is_an_error() {
+        let line = |count: usize| {
+            let body = format!("GPXDR{}", ",1".repeat(count));
+            let checksum = body.bytes().fold(0, |c, b| c ^ b);
+            format!("${body}*{checksum:02X}")
+        };
+        assert!(matches!(
+            SentenceRef::parse(&line(MAX_FIELDS + 1)),
+            Err(NmeaError::TooManyFields(count)) if count == MAX_FIELDS + 1
+        ));
+        let line = line(MAX_FIELDS);
+        assert_eq!(SentenceRef::parse(&line).unwrap().field_count(), MAX_FIELDS);
+    }
+}
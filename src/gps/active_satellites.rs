@@ -0,0 +1,173 @@
+use crate::base::*;
+use crate::gps::gsa::Gsa;
+use crate::talker::TalkerId;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SystemSatellites {
+    pub talker: TalkerId,
+    pub system_id: Option<u8>,
+    pub prns: Vec<u16>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ActiveSatellites {
+    pub mode: Option<char>,
+    pub fix_type: Option<char>,
+    pub pdop: Option<f32>,
+    pub hdop: Option<f32>,
+    pub vdop: Option<f32>,
+    pub systems: Vec<SystemSatellites>,
+}
+
+impl ActiveSatellites {
+    pub fn system(&self, system_id: u8) -> Option<&SystemSatellites> {
+        self.systems
+            .iter()
+            .find(|system| system.system_id == Some(system_id))
+    }
+
+    pub fn prns(&self) -> impl Iterator<Item = (Option<u8>, u16)> + '_ {
+        self.systems
+            .iter()
+            .flat_map(|system| system.prns.iter().map(move |prn| (system.system_id, *prn)))
+    }
+
+    pub fn satellite_count(&self) -> usize {
+        self.systems.iter().map(|system| system.prns.len()).sum()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct GsaMerger {
+    current: Option<ActiveSatellites>,
+}
+
+impl GsaMerger {
+    pub fn new() -> GsaMerger {
+        GsaMerger::default()
+    }
+
+    pub fn push<B: SentenceFields>(
+        &mut self,
+        gsa: &Gsa<B>,
+    ) -> Result<Option<ActiveSatellites>, NmeaError> {
+        let talker = gsa.base().talker_id().ok_or(NmeaError::NotPresent)?;
+        let prns = gsa.prns()?;
+        let system_id = gsa
+            .system_id()
+            .ok()
+            .or_else(|| talker.gnss_system_id())
+            .or_else(|| system_from_prns(&prns));
+
+        let repeated = match &self.current {
+            Some(current) => current
+                .systems
+                .iter()
+                .any(|system| system.talker == talker && system.system_id == system_id),
+            None => false,
+        };
+        let finished = if repeated { self.current.take() } else { None };
+
+        let current = self.current.get_or_insert_with(ActiveSatellites::default);
+        current.mode = gsa.mode().ok().or(current.mode);
+        current.fix_type = gsa.fix_type().ok().or(current.fix_type);
+        current.pdop = gsa.pdop().ok().or(current.pdop);
+        current.hdop = gsa.hdop().ok().or(current.hdop);
+        current.vdop = gsa.vdop().ok().or(current.vdop);
+        current.systems.push(SystemSatellites {
+            talker,
+            system_id,
+            prns,
+        });
+        Ok(finished)
+    }
+
+    pub fn current(&self) -> Option<&ActiveSatellites> {
+        self.current.as_ref()
+    }
+
+    pub fn flush(&mut self) -> Option<ActiveSatellites> {
+        self.current.take()
+    }
+}
+
+fn system_from_prns(prns: &[u16]) -> Option<u8> {
+    let system = |prn: &u16| match prn {
+        1..=64 => Some(1),
+        65..=96 => Some(2),
+        _ => None,
+    };
+    let first = prns.first().and_then(system)?;
+    if prns.iter().all(|prn| system(prn) == Some(first)) {
+        Some(first)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn push(merger: &mut GsaMerger, line: &str) -> Option<ActiveSatellites> {
+        let gsa = Gsa::new(Nmea0183Base::from_string(&line.to_string()).unwrap());
+        merger.push(&gsa).unwrap()
+    }
+
+    #[test]
+    fn merges_systems_until_repeat() {
+        let mut merger = GsaMerger::new();
+        assert!(push(
+            &mut merger,
+            "$GNGSA,A,3,80,71,73,79,69,,,,,,,,1.83,1.09,1.47,2*09"
+        )
+        .is_none());
+        assert!(push(
+            &mut merger,
+            "$GNGSA,A,3,21,5,29,25,12,10,26,2,,,,,1.2,0.7,1.0,1*3A"
+        )
+        .is_none());
+        let current = merger.current().unwrap();
+        assert_eq!(current.satellite_count(), 13);
+        assert_eq!(
+            current.system(1).unwrap().prns,
+            [21, 5, 29, 25, 12, 10, 26, 2]
+        );
+        assert_eq!(current.hdop, Some(0.7));
+
+        let merged = push(
+            &mut merger,
+            "$GNGSA,A,3,80,71,73,79,69,,,,,,,,1.83,1.09,1.47,2*09",
+        )
+        .unwrap();
+        assert_eq!(merged.systems.len(), 2);
+        assert_eq!(merged.prns().next(), Some((Some(2), 80)));
+        assert_eq!(merger.flush().unwrap().satellite_count(), 5);
+        assert!(merger.flush().is_none());
+    }
+
+    #[test]
+    fn infers_system_without_id() {
+        let mut merger = GsaMerger::new();
+        push(
+            &mut merger,
+            "$GPGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39",
+        );
+        push(
+            &mut merger,
+            "$GNGSA,A,3,80,71,73,79,69,,,,,,,,1.83,1.09,1.47*17",
+        );
+        push(&mut merger, "$GNGSA,A,3,70,71,04,,,,,,,,,,2.5,1.3,2.1*2F");
+        let merged = merger.flush().unwrap();
+        let systems = merged
+            .systems
+            .iter()
+            .map(|s| s.system_id)
+            .collect::<Vec<_>>();
+        assert_eq!(systems, [Some(1), Some(2), None]);
+        assert_eq!(merged.system(1).unwrap().talker, TalkerId::GP);
+        assert_eq!(merged.mode, Some('A'));
+    }
+}
@@ -1,15 +1,204 @@
 use crate::base::*;
+use crate::inspect::Inspect;
+#[cfg(feature = "std")]
+use chrono::prelude::*;
+#[cfg(feature = "std")]
+use liblatlon::Position;
 
 #[derive(Debug)]
-pub struct Bwr {
-    base: Nmea0183Base,
+pub struct Bwr<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Bwr {
-    pub fn new(base: Nmea0183Base) -> Bwr {
+    pub fn builder() -> BwrBuilder {
+        BwrBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Bwr<B> {
+    pub fn new(base: B) -> Bwr<B> {
         Bwr { base }
     }
+
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
+    #[cfg(feature = "std")]
     pub fn timestamp(&self) -> NaiveTimeError {
         self.base.naive_time(0)
     }
+
+    #[cfg(feature = "std")]
+    pub fn waypoint_position(&self) -> PositionError {
+        self.base.position(1)
+    }
+
+    pub fn bearing_true(&self) -> F32Error {
+        if self.base.field_str(6) == "T" {
+            self.base.parameter(5)
+        } else {
+            Err(NmeaError::NotPresent)
+        }
+    }
+
+    pub fn bearing_magnetic(&self) -> F32Error {
+        if self.base.field_str(8) == "M" {
+            self.base.parameter(7)
+        } else {
+            Err(NmeaError::NotPresent)
+        }
+    }
+
+    pub fn distance(&self) -> Result<Distance, NmeaError> {
+        match self.base.field_str(10) {
+            "N" => Ok(Distance::from_nautical_miles(self.base.parameter(9)?)),
+            "K" => Ok(Distance::from_kilometers(self.base.parameter(9)?)),
+            _ => Err(self.base.unknown_units(10)),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub fn waypoint_id(&self) -> Result<String, NmeaError> {
+        Ok(self.base.field(11)?.to_string())
+    }
+
+    pub fn faa_mode(&self) -> Option<char> {
+        self.base.field_str(12).chars().next()
+    }
+}
+
+impl<B: SentenceFields> Inspect for Bwr<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Bwr<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Bwr", 8)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("timestamp", &self.timestamp().ok())?;
+        state.serialize_field(
+            "waypoint_position",
+            &self
+                .waypoint_position()
+                .ok()
+                .as_ref()
+                .map(SerializedPosition::from),
+        )?;
+        state.serialize_field("bearing_true", &self.bearing_true().ok().map(Degrees))?;
+        state.serialize_field(
+            "bearing_magnetic",
+            &self.bearing_magnetic().ok().map(Degrees),
+        )?;
+        state.serialize_field("distance", &self.distance().ok())?;
+        state.serialize_field("waypoint_id", &self.waypoint_id().ok())?;
+        state.serialize_field("faa_mode", &self.faa_mode())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct BwrBuilder {
+    talker: String,
+    time: Option<NaiveTime>,
+    waypoint_position: Option<Position>,
+    bearing_true: Option<f32>,
+    bearing_magnetic: Option<f32>,
+    distance: Option<Distance>,
+    waypoint_id: String,
+    faa_mode: Option<char>,
+}
+
+#[cfg(feature = "std")]
+impl BwrBuilder {
+    pub fn new() -> BwrBuilder {
+        BwrBuilder {
+            talker: String::from("GP"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn time(mut self, time: NaiveTime) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    pub fn waypoint_position(mut self, waypoint_position: Position) -> Self {
+        self.waypoint_position = Some(waypoint_position);
+        self
+    }
+
+    pub fn bearing_true(mut self, bearing_true: f32) -> Self {
+        self.bearing_true = Some(bearing_true);
+        self
+    }
+
+    pub fn bearing_magnetic(mut self, bearing_magnetic: f32) -> Self {
+        self.bearing_magnetic = Some(bearing_magnetic);
+        self
+    }
+
+    pub fn distance(mut self, distance: Distance) -> Self {
+        self.distance = Some(distance);
+        self
+    }
+
+    pub fn waypoint_id(mut self, waypoint_id: String) -> Self {
+        self.waypoint_id = waypoint_id;
+        self
+    }
+
+    pub fn faa_mode(mut self, faa_mode: char) -> Self {
+        self.faa_mode = Some(faa_mode);
+        self
+    }
+
+    pub fn build(self) -> Bwr {
+        let mut parameters = vec![format_time(self.time)];
+        parameters.extend(format_position(self.waypoint_position.as_ref()));
+        parameters.push(format_float(self.bearing_true, 1));
+        parameters.push(String::from("T"));
+        parameters.push(format_float(self.bearing_magnetic, 1));
+        parameters.push(String::from("M"));
+        parameters.push(format_float(self.distance.map(|d| d.as_nautical_mile()), 2));
+        parameters.push(String::from("N"));
+        parameters.push(self.waypoint_id);
+        if self.faa_mode.is_some() {
+            parameters.push(format_value(self.faa_mode));
+        }
+        Bwr::new(Nmea0183Base::from_parts(&self.talker, "BWR", parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_kilometer_distance() {
+        let line = "$GPBWR,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,K,EGLM*35";
+        let bwr = Bwr::new(Nmea0183Base::from_string(&line.to_string()).unwrap());
+        assert!((bwr.distance().unwrap().as_meters() - 4600.0).abs() < 1e-2);
+        assert_eq!(bwr.waypoint_id().unwrap(), "EGLM");
+        assert_eq!(bwr.faa_mode(), None);
+    }
 }
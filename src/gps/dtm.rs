@@ -0,0 +1,244 @@
+use crate::base::*;
+use crate::inspect::Inspect;
+
+#[derive(Debug)]
+pub struct Dtm<B = DefaultFields> {
+    base: B,
+}
+
+#[cfg(feature = "std")]
+impl Dtm {
+    pub fn builder() -> DtmBuilder {
+        DtmBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Dtm<B> {
+    pub fn new(base: B) -> Dtm<B> {
+        Dtm { base }
+    }
+
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
+    #[cfg(feature = "std")]
+    pub fn local_datum(&self) -> Result<String, NmeaError> {
+        Ok(self.base.required_field(0)?.to_string())
+    }
+
+    #[cfg(feature = "std")]
+    pub fn local_datum_subdivision(&self) -> Option<String> {
+        match self.base.field_str(1) {
+            "" => None,
+            subdivision => Some(subdivision.to_string()),
+        }
+    }
+
+    pub fn latitude_offset(&self) -> F32Error {
+        let offset: f32 = self.base.parameter(2)?;
+        match self.base.field_str(3) {
+            "N" => Ok(offset),
+            "S" => Ok(-offset),
+            _ => Err(self.base.invalid_field(3)),
+        }
+    }
+
+    pub fn longitude_offset(&self) -> F32Error {
+        let offset: f32 = self.base.parameter(4)?;
+        match self.base.field_str(5) {
+            "E" => Ok(offset),
+            "W" => Ok(-offset),
+            _ => Err(self.base.invalid_field(5)),
+        }
+    }
+
+    pub fn altitude_offset(&self) -> Result<Distance, NmeaError> {
+        Ok(Distance::from_meters(self.base.parameter(6)?))
+    }
+
+    #[cfg(feature = "std")]
+    pub fn reference_datum(&self) -> Result<String, NmeaError> {
+        Ok(self.base.required_field(7)?.to_string())
+    }
+}
+
+impl<B: SentenceFields> Inspect for Dtm<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Dtm<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Dtm", 7)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("local_datum", &self.local_datum().ok())?;
+        state.serialize_field("local_datum_subdivision", &self.local_datum_subdivision())?;
+        state.serialize_field(
+            "latitude_offset",
+            &self.latitude_offset().ok().map(|m| Degrees(m / 60.0)),
+        )?;
+        state.serialize_field(
+            "longitude_offset",
+            &self.longitude_offset().ok().map(|m| Degrees(m / 60.0)),
+        )?;
+        state.serialize_field("altitude_offset", &self.altitude_offset().ok())?;
+        state.serialize_field("reference_datum", &self.reference_datum().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct DtmBuilder {
+    talker: String,
+    local_datum: Option<String>,
+    local_datum_subdivision: Option<String>,
+    latitude_offset: Option<f32>,
+    longitude_offset: Option<f32>,
+    altitude_offset: Option<Distance>,
+    reference_datum: Option<String>,
+}
+
+#[cfg(feature = "std")]
+impl DtmBuilder {
+    pub fn new() -> DtmBuilder {
+        DtmBuilder {
+            talker: String::from("GP"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn local_datum(mut self, local_datum: &str) -> Self {
+        self.local_datum = Some(String::from(local_datum));
+        self
+    }
+
+    pub fn local_datum_subdivision(mut self, local_datum_subdivision: &str) -> Self {
+        self.local_datum_subdivision = Some(String::from(local_datum_subdivision));
+        self
+    }
+
+    pub fn latitude_offset(mut self, latitude_offset: f32) -> Self {
+        self.latitude_offset = Some(latitude_offset);
+        self
+    }
+
+    pub fn longitude_offset(mut self, longitude_offset: f32) -> Self {
+        self.longitude_offset = Some(longitude_offset);
+        self
+    }
+
+    pub fn altitude_offset(mut self, altitude_offset: Distance) -> Self {
+        self.altitude_offset = Some(altitude_offset);
+        self
+    }
+
+    pub fn reference_datum(mut self, reference_datum: &str) -> Self {
+        self.reference_datum = Some(String::from(reference_datum));
+        self
+    }
+
+    pub fn build(self) -> Dtm {
+        let parameters = vec![
+            format_value(self.local_datum),
+            format_value(self.local_datum_subdivision),
+            format_float(self.latitude_offset.map(f32::abs), 4),
+            format_value(
+                self.latitude_offset
+                    .map(|offset| if offset < 0.0 { 'S' } else { 'N' }),
+            ),
+            format_float(self.longitude_offset.map(f32::abs), 4),
+            format_value(
+                self.longitude_offset
+                    .map(|offset| if offset < 0.0 { 'W' } else { 'E' }),
+            ),
+            format_float(self.altitude_offset.map(|h| h.as_meters()), 1),
+            format_value(self.reference_datum),
+        ];
+        Dtm::new(Nmea0183Base::from_parts(&self.talker, "DTM", parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str) -> Dtm {
+        Dtm::new(Nmea0183Base::from_string(&line.to_string()).unwrap())
+    }
+
+    #[test]
+    fn parses_wgs84_datum() {
+        let dtm = parse("$GPDTM,W84,,0.0,N,0.0,E,0.0,W84*6F");
+        assert_eq!(dtm.local_datum().unwrap(), "W84");
+        assert_eq!(dtm.local_datum_subdivision(), None);
+        assert_eq!(dtm.latitude_offset().unwrap(), 0.0);
+        assert_eq!(dtm.longitude_offset().unwrap(), 0.0);
+        assert_eq!(dtm.altitude_offset().unwrap().as_meters(), 0.0);
+        assert_eq!(dtm.reference_datum().unwrap(), "W84");
+    }
+
+    #[test]
+    fn parses_signed_offsets() {
+        let dtm = parse("$GPDTM,999,,0.08,N,0.07,W,-47.7,W84*09");
+        assert_eq!(dtm.local_datum().unwrap(), "999");
+        assert_eq!(dtm.latitude_offset().unwrap(), 0.08);
+        assert_eq!(dtm.longitude_offset().unwrap(), -0.07);
+        assert_eq!(dtm.altitude_offset().unwrap().as_meters(), -47.7);
+    }
+
+    #[test]
+    fn builder_round_trip() {
+        let dtm = Dtm::builder()
+            .local_datum("999")
+            .local_datum_subdivision("CH")
+            .latitude_offset(-0.08)
+            .longitude_offset(0.07)
+            .altitude_offset(Distance::from_meters(-47.7))
+            .reference_datum("W84")
+            .build();
+        let fields = &dtm.base().parameters;
+        assert_eq!(
+            fields[..],
+            ["999", "CH", "0.0800", "S", "0.0700", "E", "-47.7", "W84"]
+        );
+        assert_eq!(dtm.local_datum_subdivision().unwrap(), "CH");
+        assert_eq!(dtm.latitude_offset().unwrap(), -0.08);
+        assert_eq!(dtm.longitude_offset().unwrap(), 0.07);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_with_units() {
+        let dtm = parse("$GPDTM,999,,0.08,N,0.07,W,-47.7,W84*09");
+        let value = serde_json::to_value(&dtm).unwrap();
+        assert_eq!(
+            value["latitude_offset"],
+            serde_json::json!({ "value": 0.08f32 / 60.0, "unit": "deg" })
+        );
+        assert_eq!(
+            value["longitude_offset"],
+            serde_json::json!({ "value": -0.07f32 / 60.0, "unit": "deg" })
+        );
+        assert_eq!(
+            value["altitude_offset"],
+            serde_json::json!({ "value": -47.7f32, "unit": "m" })
+        );
+    }
+}
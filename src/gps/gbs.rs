@@ -1,15 +1,228 @@
 use crate::base::*;
+use crate::inspect::Inspect;
+#[cfg(feature = "std")]
+use chrono::prelude::*;
 
 #[derive(Debug)]
-pub struct Gbs {
-    base: Nmea0183Base,
+pub struct Gbs<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Gbs {
-    pub fn new(base: Nmea0183Base) -> Gbs {
+    pub fn builder() -> GbsBuilder {
+        GbsBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Gbs<B> {
+    pub fn new(base: B) -> Gbs<B> {
         Gbs { base }
     }
+
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
+    #[cfg(feature = "std")]
     pub fn timestamp(&self) -> NaiveTimeError {
         self.base.naive_time(0)
     }
+
+    pub fn latitude_error(&self) -> Result<Distance, NmeaError> {
+        Ok(Distance::from_meters(self.base.parameter(1)?))
+    }
+
+    pub fn longitude_error(&self) -> Result<Distance, NmeaError> {
+        Ok(Distance::from_meters(self.base.parameter(2)?))
+    }
+
+    pub fn altitude_error(&self) -> Result<Distance, NmeaError> {
+        Ok(Distance::from_meters(self.base.parameter(3)?))
+    }
+
+    pub fn failed_satellite(&self) -> U8Error {
+        self.base.parameter(4)
+    }
+
+    pub fn probability_of_missed_detection(&self) -> F32Error {
+        self.base.parameter(5)
+    }
+
+    pub fn bias_estimate(&self) -> Result<Distance, NmeaError> {
+        Ok(Distance::from_meters(self.base.parameter(6)?))
+    }
+
+    pub fn bias_standard_deviation(&self) -> Result<Distance, NmeaError> {
+        Ok(Distance::from_meters(self.base.parameter(7)?))
+    }
+
+    pub fn system_id(&self) -> U8Error {
+        self.base.parameter(8)
+    }
+
+    pub fn signal_id(&self) -> U8Error {
+        self.base.parameter(9)
+    }
+}
+
+impl<B: SentenceFields> Inspect for Gbs<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Gbs<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Gbs", 11)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("timestamp", &self.timestamp().ok())?;
+        state.serialize_field("latitude_error", &self.latitude_error().ok())?;
+        state.serialize_field("longitude_error", &self.longitude_error().ok())?;
+        state.serialize_field("altitude_error", &self.altitude_error().ok())?;
+        state.serialize_field("failed_satellite", &self.failed_satellite().ok())?;
+        state.serialize_field(
+            "probability_of_missed_detection",
+            &self.probability_of_missed_detection().ok(),
+        )?;
+        state.serialize_field("bias_estimate", &self.bias_estimate().ok())?;
+        state.serialize_field(
+            "bias_standard_deviation",
+            &self.bias_standard_deviation().ok(),
+        )?;
+        state.serialize_field("system_id", &self.system_id().ok())?;
+        state.serialize_field("signal_id", &self.signal_id().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct GbsBuilder {
+    talker: String,
+    time: Option<NaiveTime>,
+    latitude_error: Option<Distance>,
+    longitude_error: Option<Distance>,
+    altitude_error: Option<Distance>,
+    failed_satellite: Option<u8>,
+    probability_of_missed_detection: Option<f32>,
+    bias_estimate: Option<Distance>,
+    bias_standard_deviation: Option<Distance>,
+    system_id: Option<u8>,
+    signal_id: Option<u8>,
+}
+
+#[cfg(feature = "std")]
+impl GbsBuilder {
+    pub fn new() -> GbsBuilder {
+        GbsBuilder {
+            talker: String::from("GP"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn time(mut self, time: NaiveTime) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    pub fn latitude_error(mut self, latitude_error: Distance) -> Self {
+        self.latitude_error = Some(latitude_error);
+        self
+    }
+
+    pub fn longitude_error(mut self, longitude_error: Distance) -> Self {
+        self.longitude_error = Some(longitude_error);
+        self
+    }
+
+    pub fn altitude_error(mut self, altitude_error: Distance) -> Self {
+        self.altitude_error = Some(altitude_error);
+        self
+    }
+
+    pub fn failed_satellite(mut self, failed_satellite: u8) -> Self {
+        self.failed_satellite = Some(failed_satellite);
+        self
+    }
+
+    pub fn probability_of_missed_detection(mut self, probability_of_missed_detection: f32) -> Self {
+        self.probability_of_missed_detection = Some(probability_of_missed_detection);
+        self
+    }
+
+    pub fn bias_estimate(mut self, bias_estimate: Distance) -> Self {
+        self.bias_estimate = Some(bias_estimate);
+        self
+    }
+
+    pub fn bias_standard_deviation(mut self, bias_standard_deviation: Distance) -> Self {
+        self.bias_standard_deviation = Some(bias_standard_deviation);
+        self
+    }
+
+    pub fn system_id(mut self, system_id: u8) -> Self {
+        self.system_id = Some(system_id);
+        self
+    }
+
+    pub fn signal_id(mut self, signal_id: u8) -> Self {
+        self.signal_id = Some(signal_id);
+        self
+    }
+
+    pub fn build(self) -> Gbs {
+        let mut parameters = vec![
+            format_time(self.time),
+            format_float(self.latitude_error.map(|d| d.as_meters()), 1),
+            format_float(self.longitude_error.map(|d| d.as_meters()), 1),
+            format_float(self.altitude_error.map(|d| d.as_meters()), 1),
+            format_value(self.failed_satellite),
+            format_float(self.probability_of_missed_detection, 3),
+            format_float(self.bias_estimate.map(|d| d.as_meters()), 1),
+            format_float(self.bias_standard_deviation.map(|d| d.as_meters()), 1),
+        ];
+        if self.system_id.is_some() || self.signal_id.is_some() {
+            parameters.push(format_value(self.system_id));
+            parameters.push(format_value(self.signal_id));
+        }
+        Gbs::new(Nmea0183Base::from_parts(&self.talker, "GBS", parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_raim_fault_detection() {
+        let line = "$GPGBS,235458.00,1.4,1.3,3.1,03,,-21.4,3.8,1,0*5A";
+        let gbs = Gbs::new(Nmea0183Base::from_string(&line.to_string()).unwrap());
+        assert_eq!(gbs.latitude_error().unwrap().as_meters(), 1.4);
+        assert_eq!(gbs.longitude_error().unwrap().as_meters(), 1.3);
+        assert_eq!(gbs.altitude_error().unwrap().as_meters(), 3.1);
+        assert_eq!(gbs.failed_satellite().unwrap(), 3);
+        assert!(matches!(
+            gbs.probability_of_missed_detection(),
+            Err(NmeaError::MissingField(5))
+        ));
+        assert_eq!(gbs.bias_estimate().unwrap().as_meters(), -21.4);
+        assert_eq!(gbs.bias_standard_deviation().unwrap().as_meters(), 3.8);
+        assert_eq!(gbs.system_id().unwrap(), 1);
+        assert_eq!(gbs.signal_id().unwrap(), 0);
+    }
 }
@@ -1,23 +1,50 @@
 use crate::base::*;
+use crate::inspect::Inspect;
+#[cfg(feature = "std")]
+use chrono::prelude::*;
+#[cfg(feature = "std")]
+use liblatlon::Position;
 
 #[derive(Debug)]
-pub struct Gga {
-    base: Nmea0183Base,
+pub struct Gga<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Gga {
-    pub fn new(base: Nmea0183Base) -> Gga {
+    pub fn builder() -> GgaBuilder {
+        GgaBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Gga<B> {
+    pub fn new(base: B) -> Gga<B> {
         Gga { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
+    #[cfg(feature = "std")]
     pub fn timestamp(&self) -> NaiveTimeError {
         self.base.naive_time(0)
     }
 
+    #[cfg(feature = "std")]
     pub fn position(&self) -> PositionError {
         self.base.position(1)
     }
 
+    pub fn coordinates(&self) -> Result<(f64, f64), NmeaError> {
+        self.base.coordinates(1)
+    }
+
     pub fn quality(&self) -> U8Error {
         self.base.parameter(5)
     }
@@ -30,15 +57,15 @@ impl Gga {
         self.base.parameter(7)
     }
 
-    pub fn height(&self) -> F64Error {
-        self.base.parameter(8)
+    pub fn height(&self) -> Result<Distance, NmeaError> {
+        Ok(Distance::from_meters(self.base.parameter(8)?))
     }
 
-    pub fn geoid_separation(&self) -> F64Error {
-        self.base.parameter(10)
+    pub fn geoid_separation(&self) -> Result<Distance, NmeaError> {
+        Ok(Distance::from_meters(self.base.parameter(10)?))
     }
 
-    pub fn differential_age(&self) -> F64Error {
+    pub fn differential_age(&self) -> F32Error {
         self.base.parameter(12)
     }
 
@@ -46,3 +73,135 @@ impl Gga {
         self.base.parameter(13)
     }
 }
+
+impl<B: SentenceFields> Inspect for Gga<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Gga<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Gga", 10)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("timestamp", &self.timestamp().ok())?;
+        state.serialize_field(
+            "position",
+            &self.position().ok().as_ref().map(SerializedPosition::from),
+        )?;
+        state.serialize_field("quality", &self.quality().ok())?;
+        state.serialize_field("number_of_satellites", &self.number_of_satellites().ok())?;
+        state.serialize_field("hdop", &self.hdop().ok())?;
+        state.serialize_field("height", &self.height().ok())?;
+        state.serialize_field("geoid_separation", &self.geoid_separation().ok())?;
+        state.serialize_field(
+            "differential_age",
+            &self.differential_age().ok().map(Seconds),
+        )?;
+        state.serialize_field("reference_station", &self.reference_station().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct GgaBuilder {
+    talker: String,
+    time: Option<NaiveTime>,
+    position: Option<Position>,
+    quality: Option<u8>,
+    number_of_satellites: Option<u8>,
+    hdop: Option<f32>,
+    height: Option<Distance>,
+    geoid_separation: Option<Distance>,
+    differential_age: Option<f32>,
+    reference_station: Option<usize>,
+}
+
+#[cfg(feature = "std")]
+impl GgaBuilder {
+    pub fn new() -> GgaBuilder {
+        GgaBuilder {
+            talker: String::from("GP"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn time(mut self, time: NaiveTime) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub fn quality(mut self, quality: u8) -> Self {
+        self.quality = Some(quality);
+        self
+    }
+
+    pub fn number_of_satellites(mut self, number_of_satellites: u8) -> Self {
+        self.number_of_satellites = Some(number_of_satellites);
+        self
+    }
+
+    pub fn hdop(mut self, hdop: f32) -> Self {
+        self.hdop = Some(hdop);
+        self
+    }
+
+    pub fn height(mut self, height: Distance) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn geoid_separation(mut self, geoid_separation: Distance) -> Self {
+        self.geoid_separation = Some(geoid_separation);
+        self
+    }
+
+    pub fn differential_age(mut self, differential_age: f32) -> Self {
+        self.differential_age = Some(differential_age);
+        self
+    }
+
+    pub fn reference_station(mut self, reference_station: usize) -> Self {
+        self.reference_station = Some(reference_station);
+        self
+    }
+
+    pub fn build(self) -> Gga {
+        let mut parameters = vec![format_time(self.time)];
+        parameters.extend(format_position(self.position.as_ref()));
+        parameters.push(format_value(self.quality));
+        parameters.push(match self.number_of_satellites {
+            Some(n) => format!("{n:02}"),
+            None => String::new(),
+        });
+        parameters.push(format_float(self.hdop, 1));
+        parameters.push(format_float(self.height.map(|h| h.as_meters()), 1));
+        parameters.push(String::from("M"));
+        parameters.push(format_float(
+            self.geoid_separation.map(|h| h.as_meters()),
+            1,
+        ));
+        parameters.push(String::from("M"));
+        parameters.push(format_float(self.differential_age, 1));
+        parameters.push(match self.reference_station {
+            Some(n) => format!("{n:04}"),
+            None => String::new(),
+        });
+        Gga::new(Nmea0183Base::from_parts(&self.talker, "GGA", parameters))
+    }
+}
@@ -1,28 +1,135 @@
 use crate::base::*;
+use crate::inspect::Inspect;
+#[cfg(feature = "std")]
+use chrono::prelude::*;
+#[cfg(feature = "std")]
+use liblatlon::Position;
 
 #[derive(Debug)]
-pub struct Gll {
-    base: Nmea0183Base,
+pub struct Gll<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Gll {
-    pub fn new(base: Nmea0183Base) -> Gll {
+    pub fn builder() -> GllBuilder {
+        GllBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Gll<B> {
+    pub fn new(base: B) -> Gll<B> {
         Gll { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
+    #[cfg(feature = "std")]
     pub fn position(&self) -> PositionError {
         self.base.position(0)
     }
 
+    pub fn coordinates(&self) -> Result<(f64, f64), NmeaError> {
+        self.base.coordinates(0)
+    }
+
+    #[cfg(feature = "std")]
     pub fn timestamp(&self) -> NaiveTimeError {
         self.base.naive_time(4)
     }
 
     pub fn is_valid(&self) -> bool {
-        self.base.parameters[5] == "A"
+        self.base.field_str(5) == "A"
     }
 
     pub fn faa_mode(&self) -> Option<char> {
-        self.base.parameters[6].chars().nth(0)
+        self.base.field_str(6).chars().next()
+    }
+}
+
+impl<B: SentenceFields> Inspect for Gll<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Gll<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Gll", 5)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field(
+            "position",
+            &self.position().ok().as_ref().map(SerializedPosition::from),
+        )?;
+        state.serialize_field("timestamp", &self.timestamp().ok())?;
+        state.serialize_field("is_valid", &self.is_valid())?;
+        state.serialize_field("faa_mode", &self.faa_mode())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct GllBuilder {
+    talker: String,
+    position: Option<Position>,
+    time: Option<NaiveTime>,
+    valid: bool,
+    faa_mode: Option<char>,
+}
+
+#[cfg(feature = "std")]
+impl GllBuilder {
+    pub fn new() -> GllBuilder {
+        GllBuilder {
+            talker: String::from("GP"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub fn time(mut self, time: NaiveTime) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    pub fn valid(mut self, valid: bool) -> Self {
+        self.valid = valid;
+        self
+    }
+
+    pub fn faa_mode(mut self, faa_mode: char) -> Self {
+        self.faa_mode = Some(faa_mode);
+        self
+    }
+
+    pub fn build(self) -> Gll {
+        let mut parameters = Vec::from(format_position(self.position.as_ref()));
+        parameters.push(format_time(self.time));
+        parameters.push(String::from(if self.valid { "A" } else { "V" }));
+        if self.faa_mode.is_some() {
+            parameters.push(format_value(self.faa_mode));
+        }
+        Gll::new(Nmea0183Base::from_parts(&self.talker, "GLL", parameters))
     }
 }
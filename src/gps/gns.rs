@@ -0,0 +1,315 @@
+use crate::base::*;
+use crate::inspect::Inspect;
+#[cfg(feature = "std")]
+use chrono::prelude::*;
+#[cfg(feature = "std")]
+use liblatlon::Position;
+
+#[derive(Debug)]
+pub struct Gns<B = DefaultFields> {
+    base: B,
+}
+
+#[cfg(feature = "std")]
+impl Gns {
+    pub fn builder() -> GnsBuilder {
+        GnsBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Gns<B> {
+    pub fn new(base: B) -> Gns<B> {
+        Gns { base }
+    }
+
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
+    #[cfg(feature = "std")]
+    pub fn timestamp(&self) -> NaiveTimeError {
+        self.base.naive_time(0)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn position(&self) -> PositionError {
+        self.base.position(1)
+    }
+
+    pub fn coordinates(&self) -> Result<(f64, f64), NmeaError> {
+        self.base.coordinates(1)
+    }
+
+    pub fn mode_count(&self) -> usize {
+        self.base.field_str(5).chars().count()
+    }
+
+    pub fn mode_indicator(&self, n: usize) -> Option<char> {
+        self.base.field_str(5).chars().nth(n)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn mode_indicators(&self) -> Vec<char> {
+        self.base.field_str(5).chars().collect()
+    }
+
+    pub fn system_mode(&self, system_id: u8) -> Option<char> {
+        self.mode_indicator(usize::from(system_id).checked_sub(1)?)
+    }
+
+    pub fn number_of_satellites(&self) -> U8Error {
+        self.base.parameter(6)
+    }
+
+    pub fn hdop(&self) -> F32Error {
+        self.base.parameter(7)
+    }
+
+    pub fn altitude(&self) -> Result<Distance, NmeaError> {
+        Ok(Distance::from_meters(self.base.parameter(8)?))
+    }
+
+    pub fn geoid_separation(&self) -> Result<Distance, NmeaError> {
+        Ok(Distance::from_meters(self.base.parameter(9)?))
+    }
+
+    pub fn differential_age(&self) -> F32Error {
+        self.base.parameter(10)
+    }
+
+    pub fn reference_station(&self) -> UsizeError {
+        self.base.parameter(11)
+    }
+
+    pub fn nav_status(&self) -> Option<char> {
+        self.base.field_str(12).chars().next()
+    }
+}
+
+impl<B: SentenceFields> Inspect for Gns<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Gns<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Gns", 12)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("timestamp", &self.timestamp().ok())?;
+        state.serialize_field(
+            "position",
+            &self.position().ok().as_ref().map(SerializedPosition::from),
+        )?;
+        state.serialize_field("mode_indicators", &self.mode_indicators())?;
+        state.serialize_field("number_of_satellites", &self.number_of_satellites().ok())?;
+        state.serialize_field("hdop", &self.hdop().ok())?;
+        state.serialize_field("altitude", &self.altitude().ok())?;
+        state.serialize_field("geoid_separation", &self.geoid_separation().ok())?;
+        state.serialize_field(
+            "differential_age",
+            &self.differential_age().ok().map(Seconds),
+        )?;
+        state.serialize_field("reference_station", &self.reference_station().ok())?;
+        state.serialize_field("nav_status", &self.nav_status())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct GnsBuilder {
+    talker: String,
+    time: Option<NaiveTime>,
+    position: Option<Position>,
+    mode_indicators: String,
+    number_of_satellites: Option<u8>,
+    hdop: Option<f32>,
+    altitude: Option<Distance>,
+    geoid_separation: Option<Distance>,
+    differential_age: Option<f32>,
+    reference_station: Option<usize>,
+    nav_status: Option<char>,
+}
+
+#[cfg(feature = "std")]
+impl GnsBuilder {
+    pub fn new() -> GnsBuilder {
+        GnsBuilder {
+            talker: String::from("GN"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn time(mut self, time: NaiveTime) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub fn mode_indicators(mut self, mode_indicators: &str) -> Self {
+        self.mode_indicators = String::from(mode_indicators);
+        self
+    }
+
+    pub fn number_of_satellites(mut self, number_of_satellites: u8) -> Self {
+        self.number_of_satellites = Some(number_of_satellites);
+        self
+    }
+
+    pub fn hdop(mut self, hdop: f32) -> Self {
+        self.hdop = Some(hdop);
+        self
+    }
+
+    pub fn altitude(mut self, altitude: Distance) -> Self {
+        self.altitude = Some(altitude);
+        self
+    }
+
+    pub fn geoid_separation(mut self, geoid_separation: Distance) -> Self {
+        self.geoid_separation = Some(geoid_separation);
+        self
+    }
+
+    pub fn differential_age(mut self, differential_age: f32) -> Self {
+        self.differential_age = Some(differential_age);
+        self
+    }
+
+    pub fn reference_station(mut self, reference_station: usize) -> Self {
+        self.reference_station = Some(reference_station);
+        self
+    }
+
+    pub fn nav_status(mut self, nav_status: char) -> Self {
+        self.nav_status = Some(nav_status);
+        self
+    }
+
+    pub fn build(self) -> Gns {
+        let mut parameters = vec![format_time(self.time)];
+        parameters.extend(format_position(self.position.as_ref()));
+        parameters.push(self.mode_indicators);
+        parameters.push(match self.number_of_satellites {
+            Some(n) => format!("{n:02}"),
+            None => String::new(),
+        });
+        parameters.push(format_float(self.hdop, 1));
+        parameters.push(format_float(self.altitude.map(|h| h.as_meters()), 1));
+        parameters.push(format_float(
+            self.geoid_separation.map(|h| h.as_meters()),
+            1,
+        ));
+        parameters.push(format_float(self.differential_age, 1));
+        parameters.push(match self.reference_station {
+            Some(n) => format!("{n:04}"),
+            None => String::new(),
+        });
+        if self.nav_status.is_some() {
+            parameters.push(format_value(self.nav_status));
+        }
+        Gns::new(Nmea0183Base::from_parts(&self.talker, "GNS", parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str) -> Gns {
+        Gns::new(Nmea0183Base::from_string(&line.to_string()).unwrap())
+    }
+
+    #[test]
+    fn parses_fix_data() {
+        let gns = parse("$GNGNS,014035.00,4332.69262,S,17235.48549,E,RR,13,0.9,25.63,11.24,,*70");
+        assert_eq!(
+            gns.timestamp().unwrap(),
+            NaiveTime::from_hms_opt(1, 40, 35).unwrap()
+        );
+        let (latitude, longitude) = gns.coordinates().unwrap();
+        assert!((latitude + 43.544877).abs() < 1e-6);
+        assert!((longitude - 172.591425).abs() < 1e-6);
+        assert_eq!(gns.mode_indicators(), ['R', 'R']);
+        assert_eq!(gns.system_mode(2), Some('R'));
+        assert_eq!(gns.system_mode(3), None);
+        assert_eq!(gns.number_of_satellites().unwrap(), 13);
+        assert_eq!(gns.hdop().unwrap(), 0.9);
+        assert_eq!(gns.altitude().unwrap().as_meters(), 25.63);
+        assert_eq!(gns.geoid_separation().unwrap().as_meters(), 11.24);
+        assert!(matches!(
+            gns.differential_age(),
+            Err(NmeaError::MissingField(10))
+        ));
+        assert_eq!(gns.nav_status(), None);
+    }
+
+    #[test]
+    fn parses_nav_status() {
+        let gns = parse("$GNGNS,112257.00,3844.24011,N,00908.43828,W,AN,03,10.5,,,,,V*33");
+        let position = gns.position().unwrap();
+        assert!((position.latitude() - 38.737335).abs() < 1e-6);
+        assert!((position.longitude() + 9.140638).abs() < 1e-6);
+        assert_eq!(gns.mode_count(), 2);
+        assert_eq!(gns.mode_indicator(1), Some('N'));
+        assert_eq!(gns.number_of_satellites().unwrap(), 3);
+        assert!(gns.altitude().is_err());
+        assert_eq!(gns.nav_status(), Some('V'));
+    }
+
+    #[test]
+    fn builder_formats_fields() {
+        let gns = Gns::builder()
+            .talker("GN")
+            .time(NaiveTime::from_hms_opt(1, 40, 35).unwrap())
+            .mode_indicators("AA")
+            .number_of_satellites(7)
+            .hdop(0.9)
+            .altitude(Distance::from_meters(25.6))
+            .reference_station(12)
+            .nav_status('S')
+            .build();
+        let fields = &gns.base().parameters;
+        assert_eq!(
+            fields[5..],
+            ["AA", "07", "0.9", "25.6", "", "", "0012", "S"]
+        );
+        assert_eq!(gns.reference_station().unwrap(), 12);
+        assert_eq!(gns.nav_status(), Some('S'));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_with_units() {
+        let gns = parse("$GNGNS,014035.00,4332.69262,S,17235.48549,E,RR,13,0.9,25.63,11.24,,*70");
+        let value = serde_json::to_value(&gns).unwrap();
+        assert_eq!(
+            value["altitude"],
+            serde_json::json!({ "value": 25.63f32, "unit": "m" })
+        );
+        assert_eq!(
+            value["geoid_separation"],
+            serde_json::json!({ "value": 11.24f32, "unit": "m" })
+        );
+        assert_eq!(value["differential_age"], serde_json::Value::Null);
+    }
+}
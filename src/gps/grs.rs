@@ -1,15 +1,170 @@
 use crate::base::*;
+use crate::inspect::Inspect;
+#[cfg(feature = "std")]
+use chrono::prelude::*;
 
 #[derive(Debug)]
-pub struct Grs {
-    base: Nmea0183Base,
+pub struct Grs<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Grs {
-    pub fn new(base: Nmea0183Base) -> Grs {
+    pub fn builder() -> GrsBuilder {
+        GrsBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Grs<B> {
+    pub fn new(base: B) -> Grs<B> {
         Grs { base }
     }
+
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
+    #[cfg(feature = "std")]
     pub fn timestamp(&self) -> NaiveTimeError {
         self.base.naive_time(0)
     }
+
+    pub fn mode(&self) -> U8Error {
+        self.base.parameter(1)
+    }
+
+    pub fn residual(&self, n: usize) -> F32Error {
+        if n < 12 {
+            self.base.parameter(n + 2)
+        } else {
+            Err(NmeaError::IndexOutOfRange(n))
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub fn residuals(&self) -> Vec<Option<f32>> {
+        (0..12).map(|n| self.residual(n).ok()).collect()
+    }
+
+    pub fn system_id(&self) -> U8Error {
+        self.base.parameter(14)
+    }
+
+    pub fn signal_id(&self) -> U8Error {
+        self.base.parameter(15)
+    }
+}
+
+impl<B: SentenceFields> Inspect for Grs<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Grs<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Grs", 6)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("timestamp", &self.timestamp().ok())?;
+        state.serialize_field("mode", &self.mode().ok())?;
+        state.serialize_field("residuals", &self.residuals())?;
+        state.serialize_field("system_id", &self.system_id().ok())?;
+        state.serialize_field("signal_id", &self.signal_id().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct GrsBuilder {
+    talker: String,
+    time: Option<NaiveTime>,
+    mode: Option<u8>,
+    residuals: Vec<Option<f32>>,
+    system_id: Option<u8>,
+    signal_id: Option<u8>,
+}
+
+#[cfg(feature = "std")]
+impl GrsBuilder {
+    pub fn new() -> GrsBuilder {
+        GrsBuilder {
+            talker: String::from("GP"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn time(mut self, time: NaiveTime) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    pub fn mode(mut self, mode: u8) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    pub fn residuals(mut self, residuals: Vec<Option<f32>>) -> Self {
+        self.residuals = residuals;
+        self
+    }
+
+    pub fn system_id(mut self, system_id: u8) -> Self {
+        self.system_id = Some(system_id);
+        self
+    }
+
+    pub fn signal_id(mut self, signal_id: u8) -> Self {
+        self.signal_id = Some(signal_id);
+        self
+    }
+
+    pub fn build(self) -> Grs {
+        let mut parameters = vec![format_time(self.time), format_value(self.mode)];
+        for n in 0..12 {
+            parameters.push(format_float(self.residuals.get(n).copied().flatten(), 1));
+        }
+        if self.system_id.is_some() || self.signal_id.is_some() {
+            parameters.push(format_value(self.system_id));
+            parameters.push(format_value(self.signal_id));
+        }
+        Grs::new(Nmea0183Base::from_parts(&self.talker, "GRS", parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_range_residuals() {
+        let line = "$GNGRS,104148.00,1,2.6,2.2,-1.6,-1.1,-1.7,-1.5,5.8,1.7,,,,,1,1*52";
+        let grs = Grs::new(Nmea0183Base::from_string(&line.to_string()).unwrap());
+        assert_eq!(grs.mode().unwrap(), 1);
+        assert_eq!(grs.residual(2).unwrap(), -1.6);
+        assert!(matches!(
+            grs.residual(12),
+            Err(NmeaError::IndexOutOfRange(12))
+        ));
+        let residuals = grs.residuals();
+        assert_eq!(residuals.len(), 12);
+        assert_eq!(residuals[7], Some(1.7));
+        assert_eq!(residuals[8], None);
+        assert_eq!(grs.system_id().unwrap(), 1);
+        assert_eq!(grs.signal_id().unwrap(), 1);
+    }
 }
@@ -1,36 +1,238 @@
 use crate::base::*;
+use crate::inspect::Inspect;
+
+pub const PRN_SLOTS: usize = 12;
 
 #[derive(Debug)]
-pub struct Gsa {
-    base: Nmea0183Base,
+pub struct Gsa<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Gsa {
-    pub fn new(base: Nmea0183Base) -> Gsa {
+    pub fn builder() -> GsaBuilder {
+        GsaBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Gsa<B> {
+    pub fn new(base: B) -> Gsa<B> {
         Gsa { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
     pub fn mode(&self) -> Result<char, NmeaError> {
-        Ok(self.base.parameters[0].chars().nth(0).unwrap())
+        self.base.field_char(0)
     }
 
     pub fn fix_type(&self) -> Result<char, NmeaError> {
-        Ok(self.base.parameters[1].chars().nth(0).unwrap())
+        self.base.field_char(1)
     }
 
+    #[deprecated(note = "use prns()")]
     pub fn prn_number(&self) -> U8Error {
         self.base.parameter(2)
     }
 
+    pub fn prn(&self, n: usize) -> Result<u16, NmeaError> {
+        if n < PRN_SLOTS {
+            self.base.parameter(n + 2)
+        } else {
+            Err(NmeaError::IndexOutOfRange(n))
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub fn prns(&self) -> Result<Vec<u16>, NmeaError> {
+        (0..PRN_SLOTS)
+            .filter(|n| !self.base.field_str(n + 2).is_empty())
+            .map(|n| self.prn(n))
+            .collect()
+    }
+
     pub fn pdop(&self) -> F32Error {
-        self.base.parameter(3)
+        self.base.parameter(14)
     }
 
     pub fn hdop(&self) -> F32Error {
-        self.base.parameter(4)
+        self.base.parameter(15)
     }
 
     pub fn vdop(&self) -> F32Error {
-        self.base.parameter(5)
+        self.base.parameter(16)
+    }
+
+    pub fn system_id(&self) -> U8Error {
+        match u8::from_str_radix(self.base.required_field(17)?, 16) {
+            Ok(id) => Ok(id),
+            Err(_) => Err(self.base.invalid_field(17)),
+        }
+    }
+}
+
+impl<B: SentenceFields> Inspect for Gsa<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Gsa<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Gsa", 8)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("mode", &self.mode().ok())?;
+        state.serialize_field("fix_type", &self.fix_type().ok())?;
+        state.serialize_field("prns", &self.prns().ok())?;
+        state.serialize_field("pdop", &self.pdop().ok())?;
+        state.serialize_field("hdop", &self.hdop().ok())?;
+        state.serialize_field("vdop", &self.vdop().ok())?;
+        state.serialize_field("system_id", &self.system_id().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct GsaBuilder {
+    talker: String,
+    mode: Option<char>,
+    fix_type: Option<char>,
+    prns: Vec<u16>,
+    pdop: Option<f32>,
+    hdop: Option<f32>,
+    vdop: Option<f32>,
+    system_id: Option<u8>,
+}
+
+#[cfg(feature = "std")]
+impl GsaBuilder {
+    pub fn new() -> GsaBuilder {
+        GsaBuilder {
+            talker: String::from("GP"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn mode(mut self, mode: char) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    pub fn fix_type(mut self, fix_type: char) -> Self {
+        self.fix_type = Some(fix_type);
+        self
+    }
+
+    pub fn prns(mut self, prns: Vec<u16>) -> Self {
+        self.prns = prns;
+        self
+    }
+
+    pub fn pdop(mut self, pdop: f32) -> Self {
+        self.pdop = Some(pdop);
+        self
+    }
+
+    pub fn hdop(mut self, hdop: f32) -> Self {
+        self.hdop = Some(hdop);
+        self
+    }
+
+    pub fn vdop(mut self, vdop: f32) -> Self {
+        self.vdop = Some(vdop);
+        self
+    }
+
+    pub fn system_id(mut self, system_id: u8) -> Self {
+        self.system_id = Some(system_id);
+        self
+    }
+
+    pub fn build(self) -> Gsa {
+        let mut parameters = vec![format_value(self.mode), format_value(self.fix_type)];
+        for n in 0..PRN_SLOTS {
+            parameters.push(match self.prns.get(n) {
+                Some(prn) => format!("{prn:02}"),
+                None => String::new(),
+            });
+        }
+        parameters.push(format_float(self.pdop, 1));
+        parameters.push(format_float(self.hdop, 1));
+        parameters.push(format_float(self.vdop, 1));
+        if self.system_id.is_some() {
+            parameters.push(format_value(self.system_id.map(|id| format!("{:X}", id))));
+        }
+        Gsa::new(Nmea0183Base::from_parts(&self.talker, "GSA", parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str) -> Gsa {
+        Gsa::new(Nmea0183Base::from_string(&line.to_string()).unwrap())
+    }
+
+    #[test]
+    fn parses_active_satellites() {
+        let gsa = parse("$GPGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39");
+        assert_eq!(gsa.mode().unwrap(), 'A');
+        assert_eq!(gsa.fix_type().unwrap(), '3');
+        assert_eq!(gsa.prns().unwrap(), [4, 5, 9, 12, 24]);
+        assert_eq!(gsa.prn(3).unwrap(), 9);
+        assert!(matches!(gsa.prn(2), Err(NmeaError::MissingField(4))));
+        assert!(matches!(
+            gsa.prn(PRN_SLOTS),
+            Err(NmeaError::IndexOutOfRange(12))
+        ));
+        assert_eq!(gsa.pdop().unwrap(), 2.5);
+        assert_eq!(gsa.hdop().unwrap(), 1.3);
+        assert_eq!(gsa.vdop().unwrap(), 2.1);
+        assert!(gsa.system_id().is_err());
+    }
+
+    #[test]
+    fn parses_system_id() {
+        let gsa = parse("$GNGSA,A,3,80,71,73,79,69,,,,,,,,1.83,1.09,1.47,2*09");
+        assert_eq!(gsa.prns().unwrap(), [80, 71, 73, 79, 69]);
+        assert_eq!(gsa.system_id().unwrap(), 2);
+    }
+
+    #[test]
+    fn builder_round_trip() {
+        let gsa = Gsa::builder()
+            .talker("GN")
+            .mode('A')
+            .fix_type('3')
+            .prns(vec![21, 5, 29, 25, 12, 10, 26, 2])
+            .pdop(1.2)
+            .hdop(0.7)
+            .vdop(1.0)
+            .system_id(1)
+            .build();
+        assert_eq!(
+            gsa.to_sentence(),
+            "$GNGSA,A,3,21,05,29,25,12,10,26,02,,,,,1.2,0.7,1.0,1*3A\r\n"
+        );
+        assert_eq!(gsa.prns().unwrap(), [21, 5, 29, 25, 12, 10, 26, 2]);
+        assert_eq!(gsa.system_id().unwrap(), 1);
     }
 }
@@ -1,15 +1,190 @@
 use crate::base::*;
+use crate::inspect::Inspect;
+#[cfg(feature = "std")]
+use chrono::prelude::*;
 
 #[derive(Debug)]
-pub struct Gst {
-    base: Nmea0183Base,
+pub struct Gst<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Gst {
-    pub fn new(base: Nmea0183Base) -> Gst {
+    pub fn builder() -> GstBuilder {
+        GstBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Gst<B> {
+    pub fn new(base: B) -> Gst<B> {
         Gst { base }
     }
+
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
+    #[cfg(feature = "std")]
     pub fn timestamp(&self) -> NaiveTimeError {
         self.base.naive_time(0)
     }
+
+    pub fn rms(&self) -> F32Error {
+        self.base.parameter(1)
+    }
+
+    pub fn semi_major_error(&self) -> Result<Distance, NmeaError> {
+        Ok(Distance::from_meters(self.base.parameter(2)?))
+    }
+
+    pub fn semi_minor_error(&self) -> Result<Distance, NmeaError> {
+        Ok(Distance::from_meters(self.base.parameter(3)?))
+    }
+
+    pub fn orientation(&self) -> F32Error {
+        self.base.parameter(4)
+    }
+
+    pub fn latitude_error(&self) -> Result<Distance, NmeaError> {
+        Ok(Distance::from_meters(self.base.parameter(5)?))
+    }
+
+    pub fn longitude_error(&self) -> Result<Distance, NmeaError> {
+        Ok(Distance::from_meters(self.base.parameter(6)?))
+    }
+
+    pub fn altitude_error(&self) -> Result<Distance, NmeaError> {
+        Ok(Distance::from_meters(self.base.parameter(7)?))
+    }
+}
+
+impl<B: SentenceFields> Inspect for Gst<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Gst<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Gst", 9)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("timestamp", &self.timestamp().ok())?;
+        state.serialize_field("rms", &self.rms().ok())?;
+        state.serialize_field("semi_major_error", &self.semi_major_error().ok())?;
+        state.serialize_field("semi_minor_error", &self.semi_minor_error().ok())?;
+        state.serialize_field("orientation", &self.orientation().ok().map(Degrees))?;
+        state.serialize_field("latitude_error", &self.latitude_error().ok())?;
+        state.serialize_field("longitude_error", &self.longitude_error().ok())?;
+        state.serialize_field("altitude_error", &self.altitude_error().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct GstBuilder {
+    talker: String,
+    time: Option<NaiveTime>,
+    rms: Option<f32>,
+    semi_major_error: Option<Distance>,
+    semi_minor_error: Option<Distance>,
+    orientation: Option<f32>,
+    latitude_error: Option<Distance>,
+    longitude_error: Option<Distance>,
+    altitude_error: Option<Distance>,
+}
+
+#[cfg(feature = "std")]
+impl GstBuilder {
+    pub fn new() -> GstBuilder {
+        GstBuilder {
+            talker: String::from("GP"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn time(mut self, time: NaiveTime) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    pub fn rms(mut self, rms: f32) -> Self {
+        self.rms = Some(rms);
+        self
+    }
+
+    pub fn semi_major_error(mut self, semi_major_error: Distance) -> Self {
+        self.semi_major_error = Some(semi_major_error);
+        self
+    }
+
+    pub fn semi_minor_error(mut self, semi_minor_error: Distance) -> Self {
+        self.semi_minor_error = Some(semi_minor_error);
+        self
+    }
+
+    pub fn orientation(mut self, orientation: f32) -> Self {
+        self.orientation = Some(orientation);
+        self
+    }
+
+    pub fn latitude_error(mut self, latitude_error: Distance) -> Self {
+        self.latitude_error = Some(latitude_error);
+        self
+    }
+
+    pub fn longitude_error(mut self, longitude_error: Distance) -> Self {
+        self.longitude_error = Some(longitude_error);
+        self
+    }
+
+    pub fn altitude_error(mut self, altitude_error: Distance) -> Self {
+        self.altitude_error = Some(altitude_error);
+        self
+    }
+
+    pub fn build(self) -> Gst {
+        let parameters = vec![
+            format_time(self.time),
+            format_float(self.rms, 1),
+            format_float(self.semi_major_error.map(|d| d.as_meters()), 1),
+            format_float(self.semi_minor_error.map(|d| d.as_meters()), 1),
+            format_float(self.orientation, 1),
+            format_float(self.latitude_error.map(|d| d.as_meters()), 1),
+            format_float(self.longitude_error.map(|d| d.as_meters()), 1),
+            format_float(self.altitude_error.map(|d| d.as_meters()), 1),
+        ];
+        Gst::new(Nmea0183Base::from_parts(&self.talker, "GST", parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_pseudorange_error_statistics() {
+        let line = "$GPGST,082356.00,1.8,,,,1.7,1.3,2.2*7E";
+        let gst = Gst::new(Nmea0183Base::from_string(&line.to_string()).unwrap());
+        assert_eq!(gst.rms().unwrap(), 1.8);
+        assert!(gst.semi_major_error().is_err());
+        assert!(gst.orientation().is_err());
+        assert_eq!(gst.latitude_error().unwrap().as_meters(), 1.7);
+        assert_eq!(gst.longitude_error().unwrap().as_meters(), 1.3);
+        assert_eq!(gst.altitude_error().unwrap().as_meters(), 2.2);
+    }
 }
@@ -1,23 +1,41 @@
 use crate::base::*;
+use crate::inspect::Inspect;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SatelliteData {
     pub id: usize,
-    pub elevation: usize,
-    pub azimuth: usize,
-    pub snr: usize,
+    pub elevation: Option<usize>,
+    pub azimuth: Option<usize>,
+    pub snr: Option<usize>,
 }
 
 #[derive(Debug)]
-pub struct Gsv {
-    base: Nmea0183Base,
+pub struct Gsv<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Gsv {
-    pub fn new(base: Nmea0183Base) -> Gsv {
+    pub fn builder() -> GsvBuilder {
+        GsvBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Gsv<B> {
+    pub fn new(base: B) -> Gsv<B> {
         Gsv { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
     pub fn sentence_total(&self) -> UsizeError {
         self.base.parameter(0)
     }
@@ -30,16 +48,197 @@ impl Gsv {
         self.base.parameter(2)
     }
 
+    pub fn satellite_count(&self) -> usize {
+        self.base.field_count().saturating_sub(3) / 4
+    }
+
     pub fn satellite(&self, n: usize) -> Result<SatelliteData, NmeaError> {
-        if n * 4 + 6 < self.base.parameters.len() {
+        if n < self.satellite_count() {
             Ok(SatelliteData {
                 id: self.base.parameter(n * 4 + 3)?,
-                elevation: self.base.parameter(n * 4 + 1 + 3)?,
-                azimuth: self.base.parameter(n * 4 + 2 + 3)?,
-                snr: self.base.parameter(n * 4 + 3 + 3)?,
+                elevation: self.optional_parameter(n * 4 + 1 + 3)?,
+                azimuth: self.optional_parameter(n * 4 + 2 + 3)?,
+                snr: self.optional_parameter(n * 4 + 3 + 3)?,
             })
         } else {
-            Err(NmeaError("Invalid index".to_string()))
+            Err(NmeaError::IndexOutOfRange(n))
+        }
+    }
+
+    pub fn signal_id(&self) -> Option<u8> {
+        let count = self.base.field_count();
+        if count > 3 && (count - 3) % 4 == 1 {
+            u8::from_str_radix(self.base.field_str(count - 1), 16).ok()
+        } else {
+            None
+        }
+    }
+
+    fn optional_parameter(&self, index: usize) -> Result<Option<usize>, NmeaError> {
+        match self.base.field_str(index) {
+            "" => Ok(None),
+            _ => Ok(Some(self.base.parameter(index)?)),
+        }
+    }
+}
+
+impl<B: SentenceFields> Inspect for Gsv<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Gsv<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Gsv", 6)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("sentence_total", &self.sentence_total().ok())?;
+        state.serialize_field("sentence_number", &self.sentence_number().ok())?;
+        state.serialize_field("number_of_satellites", &self.number_of_satellites().ok())?;
+        state.serialize_field(
+            "satellites",
+            &(0..self.satellite_count())
+                .filter_map(|n| self.satellite(n).ok())
+                .collect::<Vec<_>>(),
+        )?;
+        state.serialize_field("signal_id", &self.signal_id())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct GsvBuilder {
+    talker: String,
+    sentence_total: Option<usize>,
+    sentence_number: Option<usize>,
+    number_of_satellites: Option<usize>,
+    satellites: Vec<SatelliteData>,
+    signal_id: Option<u8>,
+}
+
+#[cfg(feature = "std")]
+impl GsvBuilder {
+    pub fn new() -> GsvBuilder {
+        GsvBuilder {
+            talker: String::from("GP"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn sentence_total(mut self, sentence_total: usize) -> Self {
+        self.sentence_total = Some(sentence_total);
+        self
+    }
+
+    pub fn sentence_number(mut self, sentence_number: usize) -> Self {
+        self.sentence_number = Some(sentence_number);
+        self
+    }
+
+    pub fn number_of_satellites(mut self, number_of_satellites: usize) -> Self {
+        self.number_of_satellites = Some(number_of_satellites);
+        self
+    }
+
+    pub fn satellites(mut self, satellites: Vec<SatelliteData>) -> Self {
+        self.satellites = satellites;
+        self
+    }
+
+    pub fn signal_id(mut self, signal_id: u8) -> Self {
+        self.signal_id = Some(signal_id);
+        self
+    }
+
+    pub fn build(self) -> Gsv {
+        let mut parameters = vec![
+            format_value(self.sentence_total),
+            format_value(self.sentence_number),
+            format_value(self.number_of_satellites),
+        ];
+        for satellite in self.satellites.iter().take(4) {
+            parameters.push(format!("{:02}", satellite.id));
+            parameters.push(format_value(
+                satellite.elevation.map(|e| format!("{:02}", e)),
+            ));
+            parameters.push(format_value(satellite.azimuth.map(|a| format!("{:03}", a))));
+            parameters.push(format_value(satellite.snr.map(|snr| format!("{:02}", snr))));
+        }
+        if self.signal_id.is_some() {
+            parameters.push(format_value(self.signal_id.map(|id| format!("{:X}", id))));
         }
+        Gsv::new(Nmea0183Base::from_parts(&self.talker, "GSV", parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str) -> Gsv {
+        Gsv::new(Nmea0183Base::from_string(&line.to_string()).unwrap())
+    }
+
+    #[test]
+    fn parses_satellites() {
+        let gsv = parse("$GPGSV,2,1,07,07,79,048,42,02,51,062,43,26,36,256,42,27,27,138,42*71");
+        assert_eq!(gsv.sentence_total().unwrap(), 2);
+        assert_eq!(gsv.sentence_number().unwrap(), 1);
+        assert_eq!(gsv.number_of_satellites().unwrap(), 7);
+        assert_eq!(gsv.satellite_count(), 4);
+        assert_eq!(
+            gsv.satellite(2).unwrap(),
+            SatelliteData {
+                id: 26,
+                elevation: Some(36),
+                azimuth: Some(256),
+                snr: Some(42),
+            }
+        );
+        assert!(matches!(
+            gsv.satellite(4),
+            Err(NmeaError::IndexOutOfRange(4))
+        ));
+        assert_eq!(gsv.signal_id(), None);
+    }
+
+    #[test]
+    fn keeps_satellite_without_position() {
+        let gsv = parse("$GPGSV,1,1,02,01,40,083,46,22,,,31*45");
+        let satellite = gsv.satellite(1).unwrap();
+        assert_eq!(satellite.id, 22);
+        assert_eq!(satellite.elevation, None);
+        assert_eq!(satellite.azimuth, None);
+        assert_eq!(satellite.snr, Some(31));
+        assert!(gsv.satellite(0).is_ok());
+    }
+
+    #[test]
+    fn builder_leaves_missing_values_empty() {
+        let gsv = Gsv::builder()
+            .sentence_total(1)
+            .sentence_number(1)
+            .number_of_satellites(1)
+            .satellites(vec![SatelliteData {
+                id: 5,
+                elevation: None,
+                azimuth: Some(7),
+                snr: None,
+            }])
+            .signal_id(1)
+            .build();
+        assert!(gsv.to_sentence().starts_with("$GPGSV,1,1,1,05,,007,,1*"));
+        assert_eq!(gsv.satellite(0).unwrap().azimuth, Some(7));
+        assert_eq!(gsv.signal_id(), Some(1));
     }
 }
@@ -1,15 +1,150 @@
 use crate::base::*;
+use crate::inspect::Inspect;
+#[cfg(feature = "std")]
+use chrono::prelude::*;
+#[cfg(feature = "std")]
+use liblatlon::Position;
 
 #[derive(Debug)]
-pub struct Gxa {
-    base: Nmea0183Base,
+pub struct Gxa<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Gxa {
-    pub fn new(base: Nmea0183Base) -> Gxa {
+    pub fn builder() -> GxaBuilder {
+        GxaBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Gxa<B> {
+    pub fn new(base: B) -> Gxa<B> {
         Gxa { base }
     }
+
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
+    #[cfg(feature = "std")]
     pub fn timestamp(&self) -> NaiveTimeError {
         self.base.naive_time(0)
     }
+
+    #[cfg(feature = "std")]
+    pub fn position(&self) -> PositionError {
+        self.base.position(1)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn waypoint_id(&self) -> Result<String, NmeaError> {
+        Ok(self.base.field(5)?.to_string())
+    }
+
+    pub fn satellite_number(&self) -> UsizeError {
+        self.base.parameter(6)
+    }
+}
+
+impl<B: SentenceFields> Inspect for Gxa<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Gxa<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Gxa", 5)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("timestamp", &self.timestamp().ok())?;
+        state.serialize_field(
+            "position",
+            &self.position().ok().as_ref().map(SerializedPosition::from),
+        )?;
+        state.serialize_field("waypoint_id", &self.waypoint_id().ok())?;
+        state.serialize_field("satellite_number", &self.satellite_number().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct GxaBuilder {
+    talker: String,
+    time: Option<NaiveTime>,
+    position: Option<Position>,
+    waypoint_id: String,
+    satellite_number: Option<usize>,
+}
+
+#[cfg(feature = "std")]
+impl GxaBuilder {
+    pub fn new() -> GxaBuilder {
+        GxaBuilder {
+            talker: String::from("GP"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn time(mut self, time: NaiveTime) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub fn waypoint_id(mut self, waypoint_id: String) -> Self {
+        self.waypoint_id = waypoint_id;
+        self
+    }
+
+    pub fn satellite_number(mut self, satellite_number: usize) -> Self {
+        self.satellite_number = Some(satellite_number);
+        self
+    }
+
+    pub fn build(self) -> Gxa {
+        let mut parameters = vec![format_time(self.time)];
+        parameters.extend(format_position(self.position.as_ref()));
+        parameters.push(self.waypoint_id);
+        parameters.push(format_value(self.satellite_number));
+        Gxa::new(Nmea0183Base::from_parts(&self.talker, "GXA", parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_transit_position() {
+        let line = "$GPGXA,001045.00,5655.8,N,00320.5,W,EGLM,31*6C";
+        let gxa = Gxa::new(Nmea0183Base::from_string(&line.to_string()).unwrap());
+        assert_eq!(
+            gxa.timestamp().unwrap(),
+            NaiveTime::from_hms_opt(0, 10, 45).unwrap()
+        );
+        let position = gxa.position().unwrap();
+        assert!((position.latitude() - 56.93).abs() < 1e-6);
+        assert!((position.longitude() + 3.341667).abs() < 1e-5);
+        assert_eq!(gxa.waypoint_id().unwrap(), "EGLM");
+        assert_eq!(gxa.satellite_number().unwrap(), 31);
+    }
 }
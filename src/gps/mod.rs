@@ -1,12 +1,18 @@
+#[cfg(feature = "std")]
+pub mod active_satellites;
 pub mod bwc;
 pub mod bwr;
+pub mod dtm;
 pub mod gbs;
 pub mod gga;
 pub mod gll;
+pub mod gns;
 pub mod grs;
 pub mod gsa;
 pub mod gst;
 pub mod gsv;
 pub mod gxa;
 pub mod rmc;
+#[cfg(feature = "std")]
+pub mod sky_view;
 pub mod trf;
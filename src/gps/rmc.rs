@@ -1,16 +1,37 @@
 use crate::base::*;
+use crate::inspect::Inspect;
+#[cfg(feature = "std")]
 use chrono::prelude::*;
+#[cfg(feature = "std")]
+use liblatlon::Position;
 
 #[derive(Debug)]
-pub struct Rmc {
-    base: Nmea0183Base,
+pub struct Rmc<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Rmc {
-    pub fn new(base: Nmea0183Base) -> Rmc {
+    pub fn builder() -> RmcBuilder {
+        RmcBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Rmc<B> {
+    pub fn new(base: B) -> Rmc<B> {
         Rmc { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
+    #[cfg(feature = "std")]
     pub fn timestamp(&self) -> Result<DateTime<Utc>, NmeaError> {
         let timeportion: NaiveTime = self.base.naive_time(0)?;
         let dateportion: NaiveDate = self.base.naive_date(8)?;
@@ -19,13 +40,18 @@ impl Rmc {
     }
 
     pub fn is_valid(&self) -> bool {
-        self.base.parameters[1] == "A"
+        self.base.field_str(1) == "A"
     }
 
+    #[cfg(feature = "std")]
     pub fn position(&self) -> PositionError {
         self.base.position(2)
     }
 
+    pub fn coordinates(&self) -> Result<(f64, f64), NmeaError> {
+        self.base.coordinates(2)
+    }
+
     pub fn sog(&self) -> Result<Speed, NmeaError> {
         Ok(Speed::from_knots(self.base.parameter(6)?))
     }
@@ -35,7 +61,7 @@ impl Rmc {
     }
 
     pub fn magnetic_variation(&self) -> F32Error {
-        if self.base.parameters[10] == "E" {
+        if self.base.field_str(10) == "E" {
             self.base.parameter(9)
         } else {
             Ok(-(self.base.parameter(9)?))
@@ -43,10 +69,169 @@ impl Rmc {
     }
 
     pub fn faa_mode(&self) -> Option<char> {
-        self.base.parameters[11].chars().nth(0)
+        self.base.field_str(11).chars().next()
     }
 
     pub fn nav_status(&self) -> Option<char> {
-        self.base.parameters[12].chars().nth(0)
+        self.base.field_str(12).chars().next()
+    }
+}
+
+impl<B: SentenceFields> Inspect for Rmc<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Rmc<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Rmc", 9)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("timestamp", &self.timestamp().ok())?;
+        state.serialize_field("is_valid", &self.is_valid())?;
+        state.serialize_field(
+            "position",
+            &self.position().ok().as_ref().map(SerializedPosition::from),
+        )?;
+        state.serialize_field("sog", &self.sog().ok())?;
+        state.serialize_field("track_made_good", &self.track_made_good().ok().map(Degrees))?;
+        state.serialize_field(
+            "magnetic_variation",
+            &self.magnetic_variation().ok().map(Degrees),
+        )?;
+        state.serialize_field("faa_mode", &self.faa_mode())?;
+        state.serialize_field("nav_status", &self.nav_status())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct RmcBuilder {
+    talker: String,
+    time: Option<NaiveTime>,
+    valid: bool,
+    position: Option<Position>,
+    sog: Option<Speed>,
+    track_made_good: Option<f32>,
+    date: Option<NaiveDate>,
+    magnetic_variation: Option<f32>,
+    faa_mode: Option<char>,
+    nav_status: Option<char>,
+}
+
+#[cfg(feature = "std")]
+impl RmcBuilder {
+    pub fn new() -> RmcBuilder {
+        RmcBuilder {
+            talker: String::from("GP"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn time(mut self, time: NaiveTime) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    pub fn valid(mut self, valid: bool) -> Self {
+        self.valid = valid;
+        self
+    }
+
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub fn sog(mut self, sog: Speed) -> Self {
+        self.sog = Some(sog);
+        self
+    }
+
+    pub fn track_made_good(mut self, track_made_good: f32) -> Self {
+        self.track_made_good = Some(track_made_good);
+        self
+    }
+
+    pub fn date(mut self, date: NaiveDate) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    pub fn magnetic_variation(mut self, magnetic_variation: f32) -> Self {
+        self.magnetic_variation = Some(magnetic_variation);
+        self
+    }
+
+    pub fn faa_mode(mut self, faa_mode: char) -> Self {
+        self.faa_mode = Some(faa_mode);
+        self
+    }
+
+    pub fn nav_status(mut self, nav_status: char) -> Self {
+        self.nav_status = Some(nav_status);
+        self
+    }
+
+    pub fn build(self) -> Rmc {
+        let mut parameters = vec![
+            format_time(self.time),
+            String::from(if self.valid { "A" } else { "V" }),
+        ];
+        parameters.extend(format_position(self.position.as_ref()));
+        parameters.push(format_float(self.sog.map(|s| s.as_knots()), 1));
+        parameters.push(format_float(self.track_made_good, 1));
+        parameters.push(format_date(self.date));
+        parameters.extend(format_signed(self.magnetic_variation, 1, 'E', 'W'));
+        if self.faa_mode.is_some() || self.nav_status.is_some() {
+            parameters.push(format_value(self.faa_mode));
+        }
+        if self.nav_status.is_some() {
+            parameters.push(format_value(self.nav_status));
+        }
+        Rmc::new(Nmea0183Base::from_parts(&self.talker, "RMC", parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_formats_fields() {
+        let rmc = Rmc::builder()
+            .time(NaiveTime::from_hms_opt(12, 35, 19).unwrap())
+            .valid(true)
+            .position(position_from_degrees(48.1173, 11.516_666_666))
+            .sog(Speed::from_knots(22.4))
+            .track_made_good(84.4)
+            .date(NaiveDate::from_ymd_opt(1994, 3, 23).unwrap())
+            .magnetic_variation(-3.1)
+            .build();
+        assert_eq!(
+            rmc.to_sentence(),
+            "$GPRMC,123519.000,A,4807.0380,N,01131.0000,E,22.4,84.4,230394,3.1,W*74\r\n"
+        );
+        assert!((rmc.sog().unwrap().as_knots() - 22.4).abs() < 1e-3);
+        assert_eq!(rmc.magnetic_variation().unwrap(), -3.1);
+    }
+
+    #[test]
+    fn builder_appends_mode_fields_only_when_set() {
+        let rmc = Rmc::builder().talker("GN").nav_status('V').build();
+        assert_eq!(rmc.base().parameters.len(), 13);
+        assert_eq!(rmc.faa_mode(), None);
+        assert_eq!(rmc.nav_status(), Some('V'));
+        assert_eq!(Rmc::builder().build().base().parameters.len(), 11);
     }
 }
@@ -0,0 +1,192 @@
+use crate::base::*;
+use crate::gps::gsv::{Gsv, SatelliteData};
+use crate::talker::TalkerId;
+use std::collections::HashMap;
+
+pub const MAX_GSV_SENTENCES: usize = 9;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SignalKey {
+    pub talker: TalkerId,
+    pub signal_id: Option<u8>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SkyView {
+    pub talker: TalkerId,
+    pub signal_id: Option<u8>,
+    pub satellites_in_view: usize,
+    pub satellites: Vec<SatelliteData>,
+}
+
+#[derive(Debug)]
+struct PartialView {
+    total: usize,
+    next: usize,
+    satellites_in_view: usize,
+    satellites: Vec<SatelliteData>,
+}
+
+#[derive(Debug, Default)]
+pub struct GsvAggregator {
+    partial: HashMap<SignalKey, PartialView>,
+    views: HashMap<SignalKey, SkyView>,
+    discarded: usize,
+}
+
+impl GsvAggregator {
+    pub fn new() -> GsvAggregator {
+        GsvAggregator::default()
+    }
+
+    pub fn push<B: SentenceFields>(&mut self, gsv: &Gsv<B>) -> Result<Option<&SkyView>, NmeaError> {
+        let total = gsv.sentence_total()?;
+        let number = gsv.sentence_number()?;
+        if total == 0 || total > MAX_GSV_SENTENCES {
+            return Err(gsv.base().invalid_field(0));
+        }
+        if number == 0 || number > total {
+            return Err(gsv.base().invalid_field(1));
+        }
+        let talker = gsv.base().talker_id().ok_or(NmeaError::NotPresent)?;
+        let key = SignalKey {
+            talker,
+            signal_id: gsv.signal_id(),
+        };
+        let satellites_in_view = gsv.number_of_satellites()?;
+        let satellites = (0..gsv.satellite_count())
+            .filter_map(|n| gsv.satellite(n).ok())
+            .collect::<Vec<_>>();
+
+        if number == 1 {
+            if self.partial.remove(&key).is_some() {
+                self.discarded += 1;
+            }
+            self.partial.insert(
+                key,
+                PartialView {
+                    total,
+                    next: 1,
+                    satellites_in_view,
+                    satellites: Vec::new(),
+                },
+            );
+        }
+        let partial = match self.partial.get_mut(&key) {
+            Some(partial) if partial.total == total && partial.next == number => partial,
+            Some(_) => {
+                self.partial.remove(&key);
+                self.discarded += 1;
+                return Ok(None);
+            }
+            None => return Ok(None),
+        };
+        partial.satellites.extend(satellites);
+        partial.next += 1;
+        if number < total {
+            return Ok(None);
+        }
+
+        match self.partial.remove(&key) {
+            Some(partial) => {
+                let view = SkyView {
+                    talker,
+                    signal_id: key.signal_id,
+                    satellites_in_view: partial.satellites_in_view,
+                    satellites: partial.satellites,
+                };
+                self.views.insert(key, view);
+                Ok(self.views.get(&key))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn view(&self, talker: TalkerId, signal_id: Option<u8>) -> Option<&SkyView> {
+        self.views.get(&SignalKey { talker, signal_id })
+    }
+
+    pub fn views(&self) -> impl Iterator<Item = &SkyView> {
+        self.views.values()
+    }
+
+    pub fn satellites(&self) -> impl Iterator<Item = (TalkerId, &SatelliteData)> {
+        self.views
+            .values()
+            .flat_map(|view| view.satellites.iter().map(move |s| (view.talker, s)))
+    }
+
+    pub fn discarded(&self) -> usize {
+        self.discarded
+    }
+
+    pub fn clear(&mut self) {
+        self.partial.clear();
+        self.views.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str) -> Gsv {
+        Gsv::new(Nmea0183Base::from_string(&line.to_string()).unwrap())
+    }
+
+    #[test]
+    fn aggregates_sentence_group() {
+        let mut aggregator = GsvAggregator::new();
+        let first = parse("$GPGSV,2,1,07,07,79,048,42,02,51,062,43,26,36,256,42,27,27,138,42*71");
+        assert!(aggregator.push(&first).unwrap().is_none());
+        let second = parse("$GPGSV,2,2,07,09,23,313,42,04,19,159,41,15,12,041,42*41");
+        let view = aggregator.push(&second).unwrap().unwrap();
+        assert_eq!(view.talker, TalkerId::GP);
+        assert_eq!(view.satellites_in_view, 7);
+        let ids = view.satellites.iter().map(|s| s.id).collect::<Vec<_>>();
+        assert_eq!(ids, [7, 2, 26, 27, 9, 4, 15]);
+        assert_eq!(aggregator.satellites().count(), 7);
+    }
+
+    #[test]
+    fn separates_signals_and_discards_out_of_order() {
+        let mut aggregator = GsvAggregator::new();
+        assert!(aggregator
+            .push(&parse("$GLGSV,1,1,01,65,10,020,30,1*4A"))
+            .unwrap()
+            .is_some());
+        assert!(aggregator.view(TalkerId::GL, Some(1)).is_some());
+        assert!(aggregator.view(TalkerId::GL, None).is_none());
+        let second = parse("$GPGSV,2,2,07,09,23,313,42,04,19,159,41,15,12,041,42*41");
+        assert!(aggregator.push(&second).unwrap().is_none());
+        let first = parse("$GPGSV,2,1,07,07,79,048,42,02,51,062,43,26,36,256,42,27,27,138,42*71");
+        aggregator.push(&first).unwrap();
+        aggregator.push(&first).unwrap();
+        assert_eq!(aggregator.discarded(), 1);
+    }
+
+    #[test]
+    fn survives_huge_satellite_count() {
+        let mut aggregator = GsvAggregator::new();
+        let gsv = parse("$GPGSV,1,1,99999999999999,01,40,083,46*45");
+        let view = aggregator.push(&gsv).unwrap().unwrap();
+        assert_eq!(view.satellites_in_view, 99999999999999);
+        assert_eq!(view.satellites.len(), 1);
+    }
+
+    #[test]
+    fn keeps_satellites_with_a_prn() {
+        let mut aggregator = GsvAggregator::new();
+        let view = aggregator
+            .push(&parse("$GPGSV,1,1,02,01,40,083,46,22,,,31*45"))
+            .unwrap()
+            .unwrap();
+        let ids = view.satellites.iter().map(|s| s.id).collect::<Vec<_>>();
+        assert_eq!(ids, [1, 22]);
+        let view = aggregator
+            .push(&parse("$GPGSV,1,1,02,01,40,083,46,,10,020,31*76"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(view.satellites.len(), 1);
+    }
+}
@@ -1,15 +1,217 @@
 use crate::base::*;
+use crate::inspect::Inspect;
+#[cfg(feature = "std")]
+use chrono::prelude::*;
+#[cfg(feature = "std")]
+use liblatlon::Position;
 
 #[derive(Debug)]
-pub struct Trf {
-    base: Nmea0183Base,
+pub struct Trf<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Trf {
-    pub fn new(base: Nmea0183Base) -> Trf {
+    pub fn builder() -> TrfBuilder {
+        TrfBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Trf<B> {
+    pub fn new(base: B) -> Trf<B> {
         Trf { base }
     }
+
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
+    #[cfg(feature = "std")]
     pub fn timestamp(&self) -> NaiveTimeError {
         self.base.naive_time(0)
     }
+
+    #[cfg(feature = "std")]
+    pub fn date(&self) -> NaiveDateError {
+        self.base.naive_date(1)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn position(&self) -> PositionError {
+        self.base.position(2)
+    }
+
+    pub fn elevation_angle(&self) -> F32Error {
+        self.base.parameter(6)
+    }
+
+    pub fn number_of_iterations(&self) -> UsizeError {
+        self.base.parameter(7)
+    }
+
+    pub fn number_of_doppler_intervals(&self) -> UsizeError {
+        self.base.parameter(8)
+    }
+
+    pub fn update_distance(&self) -> Result<Distance, NmeaError> {
+        Ok(Distance::from_nautical_miles(self.base.parameter(9)?))
+    }
+
+    pub fn satellite_id(&self) -> UsizeError {
+        self.base.parameter(10)
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.base.field_str(11) == "A"
+    }
+}
+
+impl<B: SentenceFields> Inspect for Trf<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Trf<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Trf", 10)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("timestamp", &self.timestamp().ok())?;
+        state.serialize_field("date", &self.date().ok())?;
+        state.serialize_field(
+            "position",
+            &self.position().ok().as_ref().map(SerializedPosition::from),
+        )?;
+        state.serialize_field("elevation_angle", &self.elevation_angle().ok().map(Degrees))?;
+        state.serialize_field("number_of_iterations", &self.number_of_iterations().ok())?;
+        state.serialize_field(
+            "number_of_doppler_intervals",
+            &self.number_of_doppler_intervals().ok(),
+        )?;
+        state.serialize_field("update_distance", &self.update_distance().ok())?;
+        state.serialize_field("satellite_id", &self.satellite_id().ok())?;
+        state.serialize_field("is_valid", &self.is_valid())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct TrfBuilder {
+    talker: String,
+    time: Option<NaiveTime>,
+    date: Option<NaiveDate>,
+    position: Option<Position>,
+    elevation_angle: Option<f32>,
+    number_of_iterations: Option<usize>,
+    number_of_doppler_intervals: Option<usize>,
+    update_distance: Option<Distance>,
+    satellite_id: Option<usize>,
+    valid: bool,
+}
+
+#[cfg(feature = "std")]
+impl TrfBuilder {
+    pub fn new() -> TrfBuilder {
+        TrfBuilder {
+            talker: String::from("GP"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn time(mut self, time: NaiveTime) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    pub fn date(mut self, date: NaiveDate) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub fn elevation_angle(mut self, elevation_angle: f32) -> Self {
+        self.elevation_angle = Some(elevation_angle);
+        self
+    }
+
+    pub fn number_of_iterations(mut self, number_of_iterations: usize) -> Self {
+        self.number_of_iterations = Some(number_of_iterations);
+        self
+    }
+
+    pub fn number_of_doppler_intervals(mut self, number_of_doppler_intervals: usize) -> Self {
+        self.number_of_doppler_intervals = Some(number_of_doppler_intervals);
+        self
+    }
+
+    pub fn update_distance(mut self, update_distance: Distance) -> Self {
+        self.update_distance = Some(update_distance);
+        self
+    }
+
+    pub fn satellite_id(mut self, satellite_id: usize) -> Self {
+        self.satellite_id = Some(satellite_id);
+        self
+    }
+
+    pub fn valid(mut self, valid: bool) -> Self {
+        self.valid = valid;
+        self
+    }
+
+    pub fn build(self) -> Trf {
+        let mut parameters = vec![format_time(self.time), format_date(self.date)];
+        parameters.extend(format_position(self.position.as_ref()));
+        parameters.push(format_float(self.elevation_angle, 1));
+        parameters.push(format_value(self.number_of_iterations));
+        parameters.push(format_value(self.number_of_doppler_intervals));
+        parameters.push(format_float(
+            self.update_distance.map(|d| d.as_nautical_mile()),
+            1,
+        ));
+        parameters.push(format_value(self.satellite_id));
+        parameters.push(String::from(if self.valid { "A" } else { "V" }));
+        Trf::new(Nmea0183Base::from_parts(&self.talker, "TRF", parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_transit_fix_data() {
+        let line = "$GPTRF,121314.00,230394,4807.038,N,01131.000,E,45.0,5,12,1.5,17,A*0F";
+        let trf = Trf::new(Nmea0183Base::from_string(&line.to_string()).unwrap());
+        assert_eq!(
+            trf.date().unwrap(),
+            NaiveDate::from_ymd_opt(1994, 3, 23).unwrap()
+        );
+        assert!((trf.position().unwrap().latitude() - 48.1173).abs() < 1e-6);
+        assert_eq!(trf.elevation_angle().unwrap(), 45.0);
+        assert_eq!(trf.number_of_iterations().unwrap(), 5);
+        assert_eq!(trf.number_of_doppler_intervals().unwrap(), 12);
+        assert!((trf.update_distance().unwrap().as_nautical_mile() - 1.5).abs() < 1e-4);
+        assert_eq!(trf.satellite_id().unwrap(), 17);
+        assert!(trf.is_valid());
+    }
 }
@@ -1,21 +1,38 @@
 use crate::base::*;
+use crate::inspect::Inspect;
 
 #[derive(Debug)]
-pub struct Hdg {
-    base: Nmea0183Base,
+pub struct Hdg<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Hdg {
-    pub fn new(base: Nmea0183Base) -> Hdg {
+    pub fn builder() -> HdgBuilder {
+        HdgBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Hdg<B> {
+    pub fn new(base: B) -> Hdg<B> {
         Hdg { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
     pub fn magnetic_heading(&self) -> F32Error {
         self.base.parameter(0)
     }
 
     pub fn magnetic_deviation(&self) -> F32Error {
-        if self.base.parameters[2] == "E" {
+        if self.base.field_str(2) == "E" {
             self.base.parameter(1)
         } else {
             Ok(-(self.base.parameter(1)?))
@@ -23,10 +40,86 @@ impl Hdg {
     }
 
     pub fn magnetic_variation(&self) -> F32Error {
-        if self.base.parameters[4] == "E" {
+        if self.base.field_str(4) == "E" {
             self.base.parameter(3)
         } else {
             Ok(-(self.base.parameter(3)?))
         }
     }
 }
+
+impl<B: SentenceFields> Inspect for Hdg<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Hdg<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Hdg", 4)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field(
+            "magnetic_heading",
+            &self.magnetic_heading().ok().map(Degrees),
+        )?;
+        state.serialize_field(
+            "magnetic_deviation",
+            &self.magnetic_deviation().ok().map(Degrees),
+        )?;
+        state.serialize_field(
+            "magnetic_variation",
+            &self.magnetic_variation().ok().map(Degrees),
+        )?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct HdgBuilder {
+    talker: String,
+    magnetic_heading: Option<f32>,
+    magnetic_deviation: Option<f32>,
+    magnetic_variation: Option<f32>,
+}
+
+#[cfg(feature = "std")]
+impl HdgBuilder {
+    pub fn new() -> HdgBuilder {
+        HdgBuilder {
+            talker: String::from("HC"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn magnetic_heading(mut self, magnetic_heading: f32) -> Self {
+        self.magnetic_heading = Some(magnetic_heading);
+        self
+    }
+
+    pub fn magnetic_deviation(mut self, magnetic_deviation: f32) -> Self {
+        self.magnetic_deviation = Some(magnetic_deviation);
+        self
+    }
+
+    pub fn magnetic_variation(mut self, magnetic_variation: f32) -> Self {
+        self.magnetic_variation = Some(magnetic_variation);
+        self
+    }
+
+    pub fn build(self) -> Hdg {
+        let mut parameters = vec![format_float(self.magnetic_heading, 1)];
+        parameters.extend(format_signed(self.magnetic_deviation, 1, 'E', 'W'));
+        parameters.extend(format_signed(self.magnetic_variation, 1, 'E', 'W'));
+        Hdg::new(Nmea0183Base::from_parts(&self.talker, "HDG", parameters))
+    }
+}
@@ -1,16 +1,84 @@
 use crate::base::*;
+use crate::inspect::Inspect;
 
 #[derive(Debug)]
-pub struct Hdm {
-    base: Nmea0183Base,
+pub struct Hdm<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Hdm {
-    pub fn new(base: Nmea0183Base) -> Hdm {
+    pub fn builder() -> HdmBuilder {
+        HdmBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Hdm<B> {
+    pub fn new(base: B) -> Hdm<B> {
         Hdm { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
     pub fn heading(&self) -> F32Error {
         self.base.parameter(0)
     }
 }
+
+impl<B: SentenceFields> Inspect for Hdm<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Hdm<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Hdm", 2)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("heading", &self.heading().ok().map(Degrees))?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct HdmBuilder {
+    talker: String,
+    heading: Option<f32>,
+}
+
+#[cfg(feature = "std")]
+impl HdmBuilder {
+    pub fn new() -> HdmBuilder {
+        HdmBuilder {
+            talker: String::from("HC"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn heading(mut self, heading: f32) -> Self {
+        self.heading = Some(heading);
+        self
+    }
+
+    pub fn build(self) -> Hdm {
+        let parameters = vec![format_float(self.heading, 1), String::from("M")];
+        Hdm::new(Nmea0183Base::from_parts(&self.talker, "HDM", parameters))
+    }
+}
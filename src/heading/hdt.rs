@@ -1,16 +1,84 @@
 use crate::base::*;
+use crate::inspect::Inspect;
 
 #[derive(Debug)]
-pub struct Hdt {
-    base: Nmea0183Base,
+pub struct Hdt<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Hdt {
-    pub fn new(base: Nmea0183Base) -> Hdt {
+    pub fn builder() -> HdtBuilder {
+        HdtBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Hdt<B> {
+    pub fn new(base: B) -> Hdt<B> {
         Hdt { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
     pub fn heading(&self) -> F32Error {
         self.base.parameter(0)
     }
 }
+
+impl<B: SentenceFields> Inspect for Hdt<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Hdt<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Hdt", 2)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("heading", &self.heading().ok().map(Degrees))?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct HdtBuilder {
+    talker: String,
+    heading: Option<f32>,
+}
+
+#[cfg(feature = "std")]
+impl HdtBuilder {
+    pub fn new() -> HdtBuilder {
+        HdtBuilder {
+            talker: String::from("HE"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn heading(mut self, heading: f32) -> Self {
+        self.heading = Some(heading);
+        self
+    }
+
+    pub fn build(self) -> Hdt {
+        let parameters = vec![format_float(self.heading, 1), String::from("T")];
+        Hdt::new(Nmea0183Base::from_parts(&self.talker, "HDT", parameters))
+    }
+}
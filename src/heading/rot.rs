@@ -1,20 +1,98 @@
 use crate::base::*;
+use crate::inspect::Inspect;
 
 #[derive(Debug)]
-pub struct Rot {
-    base: Nmea0183Base,
+pub struct Rot<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Rot {
-    pub fn new(base: Nmea0183Base) -> Rot {
+    pub fn builder() -> RotBuilder {
+        RotBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Rot<B> {
+    pub fn new(base: B) -> Rot<B> {
         Rot { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
     pub fn rate_of_turn(&self) -> F32Error {
         self.base.parameter(0)
     }
 
     pub fn is_valid(&self) -> bool {
-        self.base.parameters[1] == "A"
+        self.base.field_str(1) == "A"
+    }
+}
+
+impl<B: SentenceFields> Inspect for Rot<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Rot<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Rot", 3)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("rate_of_turn", &self.rate_of_turn().ok())?;
+        state.serialize_field("is_valid", &self.is_valid())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct RotBuilder {
+    talker: String,
+    rate_of_turn: Option<f32>,
+    valid: bool,
+}
+
+#[cfg(feature = "std")]
+impl RotBuilder {
+    pub fn new() -> RotBuilder {
+        RotBuilder {
+            talker: String::from("HE"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn rate_of_turn(mut self, rate_of_turn: f32) -> Self {
+        self.rate_of_turn = Some(rate_of_turn);
+        self
+    }
+
+    pub fn valid(mut self, valid: bool) -> Self {
+        self.valid = valid;
+        self
+    }
+
+    pub fn build(self) -> Rot {
+        let parameters = vec![
+            format_float(self.rate_of_turn, 1),
+            String::from(if self.valid { "A" } else { "V" }),
+        ];
+        Rot::new(Nmea0183Base::from_parts(&self.talker, "ROT", parameters))
     }
 }
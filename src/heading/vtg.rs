@@ -1,58 +1,155 @@
 use crate::base::*;
+use crate::inspect::Inspect;
 
 #[derive(Debug)]
-pub struct Vtg {
-    base: Nmea0183Base,
+pub struct Vtg<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Vtg {
-    pub fn new(base: Nmea0183Base) -> Vtg {
+    pub fn builder() -> VtgBuilder {
+        VtgBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Vtg<B> {
+    pub fn new(base: B) -> Vtg<B> {
         Vtg { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
     pub fn cog_true(&self) -> F32Error {
-        if self.base.parameters[1] == "T" {
+        if self.base.field_str(1) == "T" {
             self.base.parameter(0)
-        } else if self.base.parameters[3] == "T" {
+        } else if self.base.field_str(3) == "T" {
             self.base.parameter(2)
         } else {
-            Err(NmeaError("Not found.".to_string()))
+            Err(NmeaError::NotPresent)
         }
     }
 
     pub fn cog_mag(&self) -> F32Error {
-        if self.base.parameters[1] == "M" {
+        if self.base.field_str(1) == "M" {
             self.base.parameter(0)
-        } else if self.base.parameters[3] == "M" {
+        } else if self.base.field_str(3) == "M" {
             self.base.parameter(2)
         } else {
-            Err(NmeaError("Not found.".to_string()))
+            Err(NmeaError::NotPresent)
         }
     }
 
     pub fn sog(&self) -> Result<Speed, NmeaError> {
-        if self.base.parameters[5] == "N" {
+        if self.base.field_str(5) == "N" {
             Ok(Speed::from_knots(self.base.parameter(4)?))
-        } else if self.base.parameters[7] == "N" {
+        } else if self.base.field_str(7) == "N" {
             Ok(Speed::from_knots(self.base.parameter(6)?))
         } else {
-            match self.base.parameters[5].as_str() {
+            match self.base.field_str(5) {
                 "M" => Ok(Speed::from_mph(self.base.parameter(4)?)),
                 "K" => Ok(Speed::from_kph(self.base.parameter(4)?)),
-                _ => match self.base.parameters[7].as_str() {
+                _ => match self.base.field_str(7) {
                     "M" => Ok(Speed::from_mph(self.base.parameter(6)?)),
                     "K" => Ok(Speed::from_kph(self.base.parameter(6)?)),
-                    _ => Err(NmeaError("Not found".to_string())),
+                    _ => Err(self.base.unknown_units(5)),
                 },
             }
         }
     }
 
     pub fn faa_mode(&self) -> Option<char> {
-        if self.base.parameters.len() > 8 && self.base.parameters[8].len() > 0 {
-            Some(self.base.parameters[8].chars().nth(0).unwrap())
-        } else {
-            None
+        self.base.field_str(8).chars().next()
+    }
+}
+
+impl<B: SentenceFields> Inspect for Vtg<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Vtg<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Vtg", 5)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("cog_true", &self.cog_true().ok().map(Degrees))?;
+        state.serialize_field("cog_mag", &self.cog_mag().ok().map(Degrees))?;
+        state.serialize_field("sog", &self.sog().ok())?;
+        state.serialize_field("faa_mode", &self.faa_mode())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct VtgBuilder {
+    talker: String,
+    cog_true: Option<f32>,
+    cog_mag: Option<f32>,
+    sog: Option<Speed>,
+    faa_mode: Option<char>,
+}
+
+#[cfg(feature = "std")]
+impl VtgBuilder {
+    pub fn new() -> VtgBuilder {
+        VtgBuilder {
+            talker: String::from("GP"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn cog_true(mut self, cog_true: f32) -> Self {
+        self.cog_true = Some(cog_true);
+        self
+    }
+
+    pub fn cog_mag(mut self, cog_mag: f32) -> Self {
+        self.cog_mag = Some(cog_mag);
+        self
+    }
+
+    pub fn sog(mut self, sog: Speed) -> Self {
+        self.sog = Some(sog);
+        self
+    }
+
+    pub fn faa_mode(mut self, faa_mode: char) -> Self {
+        self.faa_mode = Some(faa_mode);
+        self
+    }
+
+    pub fn build(self) -> Vtg {
+        let mut parameters = vec![
+            format_float(self.cog_true, 1),
+            String::from("T"),
+            format_float(self.cog_mag, 1),
+            String::from("M"),
+            format_float(self.sog.map(|s| s.as_knots()), 1),
+            String::from("N"),
+            format_float(self.sog.map(|s| s.as_kph()), 1),
+            String::from("K"),
+        ];
+        if self.faa_mode.is_some() {
+            parameters.push(format_value(self.faa_mode));
         }
+        Vtg::new(Nmea0183Base::from_parts(&self.talker, "VTG", parameters))
     }
 }
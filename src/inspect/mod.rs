@@ -0,0 +1,208 @@
+#[cfg(feature = "std")]
+use chrono::{NaiveDate, NaiveTime};
+
+#[cfg(feature = "std")]
+use crate::base::Nmea0183Base;
+use crate::base::{degrees_from_nmea, NmeaError, SentenceFields, SentenceRef};
+use crate::sentence_type::{FieldKind, SentenceInfo};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FieldValue<'a> {
+    Empty,
+    Text(&'a str),
+    Flag(char),
+    Integer(i64),
+    Number(f64),
+    Degrees(f64),
+    #[cfg(feature = "std")]
+    Time(NaiveTime),
+    #[cfg(feature = "std")]
+    Date(NaiveDate),
+}
+
+#[derive(Debug)]
+pub struct InspectedField<'a> {
+    pub index: usize,
+    pub name: Option<&'static str>,
+    pub raw: &'a str,
+    pub value: Result<FieldValue<'a>, NmeaError>,
+    pub unit: Option<&'static str>,
+}
+
+pub trait Inspect {
+    type Fields: SentenceFields;
+
+    fn fields(&self) -> &Self::Fields;
+
+    fn inspect(&self) -> Inspector<'_, Self::Fields> {
+        Inspector::new(self.fields())
+    }
+}
+
+#[derive(Debug)]
+pub struct Inspector<'a, B> {
+    base: &'a B,
+    info: Option<&'static SentenceInfo>,
+    index: usize,
+}
+
+impl<'a, B: SentenceFields> Inspector<'a, B> {
+    pub fn new(base: &'a B) -> Inspector<'a, B> {
+        Inspector {
+            base,
+            info: base.sentence_type().map(|t| t.info()),
+            index: 0,
+        }
+    }
+
+    pub fn info(&self) -> Option<&'static SentenceInfo> {
+        self.info
+    }
+}
+
+impl<'a, B: SentenceFields> Iterator for Inspector<'a, B> {
+    type Item = InspectedField<'a>;
+
+    fn next(&mut self) -> Option<InspectedField<'a>> {
+        let n = self.index;
+        let count = self.base.field_count();
+        if n >= count {
+            return None;
+        }
+        self.index += 1;
+        let raw = self.base.field_str(n);
+        let field = self.info.and_then(|info| info.field_at(n, count));
+        let kind = field.map(|f| f.kind).unwrap_or(FieldKind::Text);
+        Some(InspectedField {
+            index: n,
+            name: field.map(|f| f.name),
+            raw,
+            value: decode(self.base, n, raw, kind),
+            unit: match kind {
+                FieldKind::Latitude | FieldKind::Longitude => Some("deg"),
+                _ => field.and_then(|f| f.unit),
+            },
+        })
+    }
+}
+
+fn decode<'a, B: SentenceFields>(
+    base: &B,
+    n: usize,
+    raw: &'a str,
+    kind: FieldKind,
+) -> Result<FieldValue<'a>, NmeaError> {
+    if raw.is_empty() {
+        return Ok(FieldValue::Empty);
+    }
+    match kind {
+        FieldKind::Text => Ok(FieldValue::Text(raw)),
+        FieldKind::Flag => {
+            let mut chars = raw.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(FieldValue::Flag(c)),
+                _ => Err(base.invalid_field(n)),
+            }
+        }
+        FieldKind::Integer => base.parameter(n).map(FieldValue::Integer),
+        FieldKind::Number => base.parameter(n).map(FieldValue::Number),
+        FieldKind::Latitude | FieldKind::Longitude => base
+            .parameter(n)
+            .map(|value| FieldValue::Degrees(degrees_from_nmea(value))),
+        #[cfg(feature = "std")]
+        FieldKind::Time => base.naive_time(n).map(FieldValue::Time),
+        #[cfg(feature = "std")]
+        FieldKind::Date => base.naive_date(n).map(FieldValue::Date),
+        #[cfg(not(feature = "std"))]
+        FieldKind::Time | FieldKind::Date => Ok(FieldValue::Text(raw)),
+    }
+}
+
+#[cfg(feature = "std")]
+impl Inspect for Nmea0183Base {
+    type Fields = Nmea0183Base;
+
+    fn fields(&self) -> &Nmea0183Base {
+        self
+    }
+}
+
+impl Inspect for SentenceRef<'_> {
+    type Fields = Self;
+
+    fn fields(&self) -> &Self {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GGA: &str = "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47";
+
+    #[test]
+    fn names_and_decodes_fields() {
+        let sentence = SentenceRef::parse(GGA).unwrap();
+        let fields = sentence.inspect().collect::<Vec<_>>();
+        assert_eq!(fields.len(), 14);
+        assert_eq!(fields[1].name, Some("latitude"));
+        assert_eq!(fields[1].unit, Some("deg"));
+        match fields[1].value {
+            Ok(FieldValue::Degrees(latitude)) => assert!((latitude - 48.1173).abs() < 1e-9),
+            ref other => panic!("unexpected {other:?}"),
+        }
+        assert_eq!(fields[2].value.as_ref().unwrap(), &FieldValue::Flag('N'));
+        assert_eq!(fields[6].value.as_ref().unwrap(), &FieldValue::Integer(8));
+        assert_eq!(
+            fields[8].value.as_ref().unwrap(),
+            &FieldValue::Number(545.4)
+        );
+        assert_eq!(fields[8].raw, "545.4");
+        assert_eq!(fields[13].value.as_ref().unwrap(), &FieldValue::Empty);
+        assert!(fields.iter().enumerate().all(|(i, f)| f.index == i));
+        assert!(sentence.inspect().info().is_some());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn decodes_times_and_dates() {
+        let line = "$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A";
+        let base = Nmea0183Base::from_string(&line.to_string()).unwrap();
+        let fields = base.inspect().collect::<Vec<_>>();
+        assert_eq!(
+            fields[0].value.as_ref().unwrap(),
+            &FieldValue::Time(NaiveTime::from_hms_opt(12, 35, 19).unwrap())
+        );
+        assert_eq!(
+            fields[8].value.as_ref().unwrap(),
+            &FieldValue::Date(NaiveDate::from_ymd_opt(1994, 3, 23).unwrap())
+        );
+    }
+
+    #[test]
+    fn reports_invalid_fields_without_stopping() {
+        let sentence = SentenceRef::parse("$HEHDT,27x,TT*28").unwrap();
+        let fields = sentence.inspect().collect::<Vec<_>>();
+        assert!(matches!(
+            fields[0].value,
+            Err(NmeaError::InvalidField { index: 0, .. })
+        ));
+        assert!(matches!(
+            fields[1].value,
+            Err(NmeaError::InvalidField { index: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn unknown_sentences_are_text() {
+        let sentence = SentenceRef::parse("$PGRME,15.0,M,45.0,M,25.0,M*1C").unwrap();
+        let mut inspector = sentence.inspect();
+        assert!(inspector.info().is_none());
+        let field = inspector.next().unwrap();
+        assert_eq!(field.name, None);
+        assert_eq!(field.unit, None);
+        assert_eq!(field.value.unwrap(), FieldValue::Text("15.0"));
+        assert_eq!(inspector.count(), 5);
+    }
+}
@@ -1,32 +1,66 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
 pub mod ais;
 pub mod base;
 pub mod gps;
 pub mod heading;
+pub mod inspect;
 pub mod miscellaneous;
+pub mod navigation;
+#[cfg(feature = "std")]
+pub mod proprietary;
+pub mod sentence_type;
+#[cfg(feature = "std")]
+pub mod stream;
+#[cfg(feature = "std")]
+pub mod tag_block;
+pub mod talker;
+#[cfg(feature = "std")]
+pub mod transport;
 pub mod wind;
 
-use ais::vdm::Vdm;
-use base::{Nmea0183Base, NmeaError};
+#[cfg(feature = "std")]
+use ais::{vdm::Vdm, vdo::Vdo};
+#[cfg(feature = "std")]
+use base::Nmea0183Base;
+use base::{ChecksumPolicy, NmeaError, SentenceFields, SentenceRef};
 use gps::{
-    bwc::Bwc, bwr::Bwr, gbs::Gbs, gga::Gga, gll::Gll, grs::Grs, gsa::Gsa, gst::Gst, gsv::Gsv,
-    gxa::Gxa, rmc::Rmc, trf::Trf,
+    bwc::Bwc, bwr::Bwr, dtm::Dtm, gbs::Gbs, gga::Gga, gll::Gll, gns::Gns, grs::Grs, gsa::Gsa,
+    gst::Gst, gsv::Gsv, gxa::Gxa, rmc::Rmc, trf::Trf,
 };
 use heading::{hdg::Hdg, hdm::Hdm, hdt::Hdt, rot::Rot, vtg::Vtg};
+use inspect::Inspect;
 use miscellaneous::{
     dbs::Dbs, dbt::Dbt, dpt::Dpt, mda::Mda, mtw::Mtw, rsa::Rsa, vlw::Vlw, xdr::Xdr, zda::Zda,
 };
+use navigation::{aam::Aam, apb::Apb, bod::Bod, bww::Bww, rmb::Rmb, rte::Rte, wpl::Wpl, xte::Xte};
+#[cfg(feature = "std")]
+use proprietary::{CustomSentence, DecoderRegistry};
+use sentence_type::SentenceType;
 use wind::{mwd::Mwd, mwv::Mwv, vhw::Vhw, vwr::Vwr, vwt::Vwt};
 
+// Sentences serialize their decoded values rather than the raw fields, so they
+// are serialize-only: rebuild a sentence with its builder or keep the original
+// line. The unit types such as Speed and Bearing deserialize as well.
+#[cfg(feature = "std")]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Nmea0183 {
+    AAM(Aam),
+    APB(Apb),
+    BOD(Bod),
     BWC(Bwc),
     BWR(Bwr),
+    BWW(Bww),
     DBS(Dbs),
     DBT(Dbt),
     DPT(Dpt),
+    DTM(Dtm),
     GBS(Gbs),
     GGA(Gga),
     GLL(Gll),
+    GNS(Gns),
     GRS(Grs),
     GSA(Gsa),
     GST(Gst),
@@ -39,66 +73,659 @@ pub enum Nmea0183 {
     MTW(Mtw),
     MWD(Mwd),
     MWV(Mwv),
+    RMB(Rmb),
     RMC(Rmc),
     ROT(Rot),
     RSA(Rsa),
+    RTE(Rte),
     TRF(Trf),
     VDM(Vdm),
+    VDO(Vdo),
     VHW(Vhw),
     VLW(Vlw),
     VTG(Vtg),
     VWR(Vwr),
     VWT(Vwt),
+    WPL(Wpl),
     XDR(Xdr),
+    XTE(Xte),
     ZDA(Zda),
 
+    Proprietary(Nmea0183Base),
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_custom"))]
+    Custom(Box<dyn CustomSentence>),
     Unknown(Nmea0183Base),
 }
 
+#[cfg(feature = "std")]
 impl Nmea0183 {
     pub fn from_string(message: &String) -> Result<Nmea0183, NmeaError> {
-        match Nmea0183Base::from_string(message) {
-            Ok(b) => Ok(classify(b)),
-            Err(e) => Err(NmeaError(format!("{e:?}"))),
+        Nmea0183Base::from_string(message).map(classify)
+    }
+
+    pub fn from_string_with(message: &str, policy: ChecksumPolicy) -> Result<Nmea0183, NmeaError> {
+        Nmea0183Base::from_string_with(message, policy).map(classify)
+    }
+
+    pub fn base(&self) -> &Nmea0183Base {
+        match self {
+            Nmea0183::AAM(s) => s.base(),
+            Nmea0183::APB(s) => s.base(),
+            Nmea0183::BOD(s) => s.base(),
+            Nmea0183::BWC(s) => s.base(),
+            Nmea0183::BWR(s) => s.base(),
+            Nmea0183::BWW(s) => s.base(),
+            Nmea0183::DBS(s) => s.base(),
+            Nmea0183::DBT(s) => s.base(),
+            Nmea0183::DPT(s) => s.base(),
+            Nmea0183::DTM(s) => s.base(),
+            Nmea0183::GBS(s) => s.base(),
+            Nmea0183::GGA(s) => s.base(),
+            Nmea0183::GLL(s) => s.base(),
+            Nmea0183::GNS(s) => s.base(),
+            Nmea0183::GRS(s) => s.base(),
+            Nmea0183::GSA(s) => s.base(),
+            Nmea0183::GST(s) => s.base(),
+            Nmea0183::GSV(s) => s.base(),
+            Nmea0183::GXA(s) => s.base(),
+            Nmea0183::HDG(s) => s.base(),
+            Nmea0183::HDM(s) => s.base(),
+            Nmea0183::HDT(s) => s.base(),
+            Nmea0183::MDA(s) => s.base(),
+            Nmea0183::MTW(s) => s.base(),
+            Nmea0183::MWD(s) => s.base(),
+            Nmea0183::MWV(s) => s.base(),
+            Nmea0183::RMB(s) => s.base(),
+            Nmea0183::RMC(s) => s.base(),
+            Nmea0183::ROT(s) => s.base(),
+            Nmea0183::RSA(s) => s.base(),
+            Nmea0183::RTE(s) => s.base(),
+            Nmea0183::TRF(s) => s.base(),
+            Nmea0183::VDM(s) => s.base(),
+            Nmea0183::VDO(s) => s.base(),
+            Nmea0183::VHW(s) => s.base(),
+            Nmea0183::VLW(s) => s.base(),
+            Nmea0183::VTG(s) => s.base(),
+            Nmea0183::VWR(s) => s.base(),
+            Nmea0183::VWT(s) => s.base(),
+            Nmea0183::WPL(s) => s.base(),
+            Nmea0183::XDR(s) => s.base(),
+            Nmea0183::XTE(s) => s.base(),
+            Nmea0183::ZDA(s) => s.base(),
+            Nmea0183::Proprietary(b) => b,
+            Nmea0183::Custom(s) => s.base(),
+            Nmea0183::Unknown(b) => b,
         }
     }
+
+    pub fn to_sentence(&self) -> String {
+        self.base().to_sentence()
+    }
+}
+
+#[derive(Debug)]
+pub enum Nmea0183Ref<'a> {
+    AAM(Aam<SentenceRef<'a>>),
+    APB(Apb<SentenceRef<'a>>),
+    BOD(Bod<SentenceRef<'a>>),
+    BWC(Bwc<SentenceRef<'a>>),
+    BWR(Bwr<SentenceRef<'a>>),
+    BWW(Bww<SentenceRef<'a>>),
+    DBS(Dbs<SentenceRef<'a>>),
+    DBT(Dbt<SentenceRef<'a>>),
+    DPT(Dpt<SentenceRef<'a>>),
+    DTM(Dtm<SentenceRef<'a>>),
+    GBS(Gbs<SentenceRef<'a>>),
+    GGA(Gga<SentenceRef<'a>>),
+    GLL(Gll<SentenceRef<'a>>),
+    GNS(Gns<SentenceRef<'a>>),
+    GRS(Grs<SentenceRef<'a>>),
+    GSA(Gsa<SentenceRef<'a>>),
+    GST(Gst<SentenceRef<'a>>),
+    GSV(Gsv<SentenceRef<'a>>),
+    GXA(Gxa<SentenceRef<'a>>),
+    HDG(Hdg<SentenceRef<'a>>),
+    HDM(Hdm<SentenceRef<'a>>),
+    HDT(Hdt<SentenceRef<'a>>),
+    MDA(Mda<SentenceRef<'a>>),
+    MTW(Mtw<SentenceRef<'a>>),
+    MWD(Mwd<SentenceRef<'a>>),
+    MWV(Mwv<SentenceRef<'a>>),
+    RMB(Rmb<SentenceRef<'a>>),
+    RMC(Rmc<SentenceRef<'a>>),
+    ROT(Rot<SentenceRef<'a>>),
+    RSA(Rsa<SentenceRef<'a>>),
+    RTE(Rte<SentenceRef<'a>>),
+    TRF(Trf<SentenceRef<'a>>),
+    #[cfg(feature = "std")]
+    VDM(Vdm<SentenceRef<'a>>),
+    #[cfg(feature = "std")]
+    VDO(Vdo<SentenceRef<'a>>),
+    VHW(Vhw<SentenceRef<'a>>),
+    VLW(Vlw<SentenceRef<'a>>),
+    VTG(Vtg<SentenceRef<'a>>),
+    VWR(Vwr<SentenceRef<'a>>),
+    VWT(Vwt<SentenceRef<'a>>),
+    WPL(Wpl<SentenceRef<'a>>),
+    XDR(Xdr<SentenceRef<'a>>),
+    XTE(Xte<SentenceRef<'a>>),
+    ZDA(Zda<SentenceRef<'a>>),
+
+    Proprietary(SentenceRef<'a>),
+    Unknown(SentenceRef<'a>),
 }
 
+impl<'a> Nmea0183Ref<'a> {
+    pub fn parse(line: &'a str) -> Result<Nmea0183Ref<'a>, NmeaError> {
+        Nmea0183Ref::parse_with(line, ChecksumPolicy::default())
+    }
+
+    pub fn parse_with(line: &'a str, policy: ChecksumPolicy) -> Result<Nmea0183Ref<'a>, NmeaError> {
+        SentenceRef::parse_with(line, policy).map(classify_ref)
+    }
+
+    pub fn base(&self) -> &SentenceRef<'a> {
+        match self {
+            Nmea0183Ref::AAM(s) => s.base(),
+            Nmea0183Ref::APB(s) => s.base(),
+            Nmea0183Ref::BOD(s) => s.base(),
+            Nmea0183Ref::BWC(s) => s.base(),
+            Nmea0183Ref::BWR(s) => s.base(),
+            Nmea0183Ref::BWW(s) => s.base(),
+            Nmea0183Ref::DBS(s) => s.base(),
+            Nmea0183Ref::DBT(s) => s.base(),
+            Nmea0183Ref::DPT(s) => s.base(),
+            Nmea0183Ref::DTM(s) => s.base(),
+            Nmea0183Ref::GBS(s) => s.base(),
+            Nmea0183Ref::GGA(s) => s.base(),
+            Nmea0183Ref::GLL(s) => s.base(),
+            Nmea0183Ref::GNS(s) => s.base(),
+            Nmea0183Ref::GRS(s) => s.base(),
+            Nmea0183Ref::GSA(s) => s.base(),
+            Nmea0183Ref::GST(s) => s.base(),
+            Nmea0183Ref::GSV(s) => s.base(),
+            Nmea0183Ref::GXA(s) => s.base(),
+            Nmea0183Ref::HDG(s) => s.base(),
+            Nmea0183Ref::HDM(s) => s.base(),
+            Nmea0183Ref::HDT(s) => s.base(),
+            Nmea0183Ref::MDA(s) => s.base(),
+            Nmea0183Ref::MTW(s) => s.base(),
+            Nmea0183Ref::MWD(s) => s.base(),
+            Nmea0183Ref::MWV(s) => s.base(),
+            Nmea0183Ref::RMB(s) => s.base(),
+            Nmea0183Ref::RMC(s) => s.base(),
+            Nmea0183Ref::ROT(s) => s.base(),
+            Nmea0183Ref::RSA(s) => s.base(),
+            Nmea0183Ref::RTE(s) => s.base(),
+            Nmea0183Ref::TRF(s) => s.base(),
+            #[cfg(feature = "std")]
+            Nmea0183Ref::VDM(s) => s.base(),
+            #[cfg(feature = "std")]
+            Nmea0183Ref::VDO(s) => s.base(),
+            Nmea0183Ref::VHW(s) => s.base(),
+            Nmea0183Ref::VLW(s) => s.base(),
+            Nmea0183Ref::VTG(s) => s.base(),
+            Nmea0183Ref::VWR(s) => s.base(),
+            Nmea0183Ref::VWT(s) => s.base(),
+            Nmea0183Ref::WPL(s) => s.base(),
+            Nmea0183Ref::XDR(s) => s.base(),
+            Nmea0183Ref::XTE(s) => s.base(),
+            Nmea0183Ref::ZDA(s) => s.base(),
+            Nmea0183Ref::Proprietary(b) => b,
+            Nmea0183Ref::Unknown(b) => b,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base().to_sentence()
+    }
+
+    #[cfg(feature = "std")]
+    pub fn into_owned(self) -> Nmea0183 {
+        classify(self.base().to_base())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Inspect for Nmea0183 {
+    type Fields = Nmea0183Base;
+
+    fn fields(&self) -> &Nmea0183Base {
+        self.base()
+    }
+}
+
+impl<'a> Inspect for Nmea0183Ref<'a> {
+    type Fields = SentenceRef<'a>;
+
+    fn fields(&self) -> &SentenceRef<'a> {
+        self.base()
+    }
+}
+
+#[cfg(feature = "std")]
+pub fn classify_with(registry: &DecoderRegistry, b: Nmea0183Base) -> Result<Nmea0183, NmeaError> {
+    registry.classify(b)
+}
+
+#[cfg(feature = "std")]
 pub fn classify(b: Nmea0183Base) -> Nmea0183 {
-    match b.message.to_uppercase().as_str() {
-        "DBS" => Nmea0183::DBS(Dbs::new(b)),
-        "DBT" => Nmea0183::DBT(Dbt::new(b)),
-        "DPT" => Nmea0183::DPT(Dpt::new(b)),
-        "GGA" => Nmea0183::GGA(Gga::new(b)),
-        "GLL" => Nmea0183::GLL(Gll::new(b)),
-        "GSA" => Nmea0183::GSA(Gsa::new(b)),
-        "GSV" => Nmea0183::GSV(Gsv::new(b)),
-        "HDG" => Nmea0183::HDG(Hdg::new(b)),
-        "HDM" => Nmea0183::HDM(Hdm::new(b)),
-        "HDT" => Nmea0183::HDT(Hdt::new(b)),
-        "MDA" => Nmea0183::MDA(Mda::new(b)),
-        "MTW" => Nmea0183::MTW(Mtw::new(b)),
-        "MWD" => Nmea0183::MWD(Mwd::new(b)),
-        "MWV" => Nmea0183::MWV(Mwv::new(b)),
-        "RMC" => Nmea0183::RMC(Rmc::new(b)),
-        "ROT" => Nmea0183::ROT(Rot::new(b)),
-        "RSA" => Nmea0183::RSA(Rsa::new(b)),
-        "VDM" => Nmea0183::VDM(Vdm::new(b)),
-        "VHW" => Nmea0183::VHW(Vhw::new(b)),
-        "VLW" => Nmea0183::VLW(Vlw::new(b)),
-        "VTG" => Nmea0183::VTG(Vtg::new(b)),
-        "VWR" => Nmea0183::VWR(Vwr::new(b)),
-        "VWT" => Nmea0183::VWT(Vwt::new(b)),
-        "XDR" => Nmea0183::XDR(Xdr::new(b)),
-        "ZDA" => Nmea0183::ZDA(Zda::new(b)),
+    if b.is_proprietary() {
+        return Nmea0183::Proprietary(b);
+    }
+    match SentenceType::from_message(&b.message) {
+        Some(SentenceType::AAM) => Nmea0183::AAM(Aam::new(b)),
+        Some(SentenceType::APB) => Nmea0183::APB(Apb::new(b)),
+        Some(SentenceType::BOD) => Nmea0183::BOD(Bod::new(b)),
+        Some(SentenceType::BWC) => Nmea0183::BWC(Bwc::new(b)),
+        Some(SentenceType::BWR) => Nmea0183::BWR(Bwr::new(b)),
+        Some(SentenceType::BWW) => Nmea0183::BWW(Bww::new(b)),
+        Some(SentenceType::DBS) => Nmea0183::DBS(Dbs::new(b)),
+        Some(SentenceType::DBT) => Nmea0183::DBT(Dbt::new(b)),
+        Some(SentenceType::DPT) => Nmea0183::DPT(Dpt::new(b)),
+        Some(SentenceType::DTM) => Nmea0183::DTM(Dtm::new(b)),
+        Some(SentenceType::GBS) => Nmea0183::GBS(Gbs::new(b)),
+        Some(SentenceType::GGA) => Nmea0183::GGA(Gga::new(b)),
+        Some(SentenceType::GLL) => Nmea0183::GLL(Gll::new(b)),
+        Some(SentenceType::GNS) => Nmea0183::GNS(Gns::new(b)),
+        Some(SentenceType::GRS) => Nmea0183::GRS(Grs::new(b)),
+        Some(SentenceType::GSA) => Nmea0183::GSA(Gsa::new(b)),
+        Some(SentenceType::GST) => Nmea0183::GST(Gst::new(b)),
+        Some(SentenceType::GSV) => Nmea0183::GSV(Gsv::new(b)),
+        Some(SentenceType::GXA) => Nmea0183::GXA(Gxa::new(b)),
+        Some(SentenceType::HDG) => Nmea0183::HDG(Hdg::new(b)),
+        Some(SentenceType::HDM) => Nmea0183::HDM(Hdm::new(b)),
+        Some(SentenceType::HDT) => Nmea0183::HDT(Hdt::new(b)),
+        Some(SentenceType::MDA) => Nmea0183::MDA(Mda::new(b)),
+        Some(SentenceType::MTW) => Nmea0183::MTW(Mtw::new(b)),
+        Some(SentenceType::MWD) => Nmea0183::MWD(Mwd::new(b)),
+        Some(SentenceType::MWV) => Nmea0183::MWV(Mwv::new(b)),
+        Some(SentenceType::RMB) => Nmea0183::RMB(Rmb::new(b)),
+        Some(SentenceType::RMC) => Nmea0183::RMC(Rmc::new(b)),
+        Some(SentenceType::ROT) => Nmea0183::ROT(Rot::new(b)),
+        Some(SentenceType::RSA) => Nmea0183::RSA(Rsa::new(b)),
+        Some(SentenceType::RTE) => Nmea0183::RTE(Rte::new(b)),
+        Some(SentenceType::TRF) => Nmea0183::TRF(Trf::new(b)),
+        Some(SentenceType::VDM) => Nmea0183::VDM(Vdm::new(b)),
+        Some(SentenceType::VDO) => Nmea0183::VDO(Vdo::new(b)),
+        Some(SentenceType::VHW) => Nmea0183::VHW(Vhw::new(b)),
+        Some(SentenceType::VLW) => Nmea0183::VLW(Vlw::new(b)),
+        Some(SentenceType::VTG) => Nmea0183::VTG(Vtg::new(b)),
+        Some(SentenceType::VWR) => Nmea0183::VWR(Vwr::new(b)),
+        Some(SentenceType::VWT) => Nmea0183::VWT(Vwt::new(b)),
+        Some(SentenceType::WPL) => Nmea0183::WPL(Wpl::new(b)),
+        Some(SentenceType::XDR) => Nmea0183::XDR(Xdr::new(b)),
+        Some(SentenceType::XTE) => Nmea0183::XTE(Xte::new(b)),
+        Some(SentenceType::ZDA) => Nmea0183::ZDA(Zda::new(b)),
         _ => Nmea0183::Unknown(b),
     }
 }
 
-pub fn sender(_nmea0183: &Nmea0183) -> Result<String, String> {
-    Err(String::from("Not yet implemented"))
+pub fn classify_ref(b: SentenceRef<'_>) -> Nmea0183Ref<'_> {
+    if b.is_proprietary() {
+        return Nmea0183Ref::Proprietary(b);
+    }
+    match SentenceType::from_message(b.message()) {
+        Some(SentenceType::AAM) => Nmea0183Ref::AAM(Aam::new(b)),
+        Some(SentenceType::APB) => Nmea0183Ref::APB(Apb::new(b)),
+        Some(SentenceType::BOD) => Nmea0183Ref::BOD(Bod::new(b)),
+        Some(SentenceType::BWC) => Nmea0183Ref::BWC(Bwc::new(b)),
+        Some(SentenceType::BWR) => Nmea0183Ref::BWR(Bwr::new(b)),
+        Some(SentenceType::BWW) => Nmea0183Ref::BWW(Bww::new(b)),
+        Some(SentenceType::DBS) => Nmea0183Ref::DBS(Dbs::new(b)),
+        Some(SentenceType::DBT) => Nmea0183Ref::DBT(Dbt::new(b)),
+        Some(SentenceType::DPT) => Nmea0183Ref::DPT(Dpt::new(b)),
+        Some(SentenceType::DTM) => Nmea0183Ref::DTM(Dtm::new(b)),
+        Some(SentenceType::GBS) => Nmea0183Ref::GBS(Gbs::new(b)),
+        Some(SentenceType::GGA) => Nmea0183Ref::GGA(Gga::new(b)),
+        Some(SentenceType::GLL) => Nmea0183Ref::GLL(Gll::new(b)),
+        Some(SentenceType::GNS) => Nmea0183Ref::GNS(Gns::new(b)),
+        Some(SentenceType::GRS) => Nmea0183Ref::GRS(Grs::new(b)),
+        Some(SentenceType::GSA) => Nmea0183Ref::GSA(Gsa::new(b)),
+        Some(SentenceType::GST) => Nmea0183Ref::GST(Gst::new(b)),
+        Some(SentenceType::GSV) => Nmea0183Ref::GSV(Gsv::new(b)),
+        Some(SentenceType::GXA) => Nmea0183Ref::GXA(Gxa::new(b)),
+        Some(SentenceType::HDG) => Nmea0183Ref::HDG(Hdg::new(b)),
+        Some(SentenceType::HDM) => Nmea0183Ref::HDM(Hdm::new(b)),
+        Some(SentenceType::HDT) => Nmea0183Ref::HDT(Hdt::new(b)),
+        Some(SentenceType::MDA) => Nmea0183Ref::MDA(Mda::new(b)),
+        Some(SentenceType::MTW) => Nmea0183Ref::MTW(Mtw::new(b)),
+        Some(SentenceType::MWD) => Nmea0183Ref::MWD(Mwd::new(b)),
+        Some(SentenceType::MWV) => Nmea0183Ref::MWV(Mwv::new(b)),
+        Some(SentenceType::RMB) => Nmea0183Ref::RMB(Rmb::new(b)),
+        Some(SentenceType::RMC) => Nmea0183Ref::RMC(Rmc::new(b)),
+        Some(SentenceType::ROT) => Nmea0183Ref::ROT(Rot::new(b)),
+        Some(SentenceType::RSA) => Nmea0183Ref::RSA(Rsa::new(b)),
+        Some(SentenceType::RTE) => Nmea0183Ref::RTE(Rte::new(b)),
+        Some(SentenceType::TRF) => Nmea0183Ref::TRF(Trf::new(b)),
+        #[cfg(feature = "std")]
+        Some(SentenceType::VDM) => Nmea0183Ref::VDM(Vdm::new(b)),
+        #[cfg(feature = "std")]
+        Some(SentenceType::VDO) => Nmea0183Ref::VDO(Vdo::new(b)),
+        Some(SentenceType::VHW) => Nmea0183Ref::VHW(Vhw::new(b)),
+        Some(SentenceType::VLW) => Nmea0183Ref::VLW(Vlw::new(b)),
+        Some(SentenceType::VTG) => Nmea0183Ref::VTG(Vtg::new(b)),
+        Some(SentenceType::VWR) => Nmea0183Ref::VWR(Vwr::new(b)),
+        Some(SentenceType::VWT) => Nmea0183Ref::VWT(Vwt::new(b)),
+        Some(SentenceType::WPL) => Nmea0183Ref::WPL(Wpl::new(b)),
+        Some(SentenceType::XDR) => Nmea0183Ref::XDR(Xdr::new(b)),
+        Some(SentenceType::XTE) => Nmea0183Ref::XTE(Xte::new(b)),
+        Some(SentenceType::ZDA) => Nmea0183Ref::ZDA(Zda::new(b)),
+        _ => Nmea0183Ref::Unknown(b),
+    }
+}
+
+#[cfg(feature = "serde")]
+#[allow(clippy::borrowed_box)]
+fn serialize_custom<S: serde::Serializer>(
+    sentence: &Box<dyn CustomSentence>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serde::Serialize::serialize(sentence.base(), serializer)
 }
 
-pub fn message(_nmea0183: &Nmea0183) -> Result<String, String> {
-    Err(String::from("Not yet implemented"))
+#[cfg(feature = "std")]
+pub fn sender(nmea0183: &Nmea0183) -> Result<String, String> {
+    Ok(nmea0183.base().sender.clone())
+}
+
+#[cfg(feature = "std")]
+pub fn message(nmea0183: &Nmea0183) -> Result<String, String> {
+    Ok(nmea0183.base().message.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SENTENCES: &[&str] = &[
+        "$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A",
+        "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47",
+        "$GPGLL,4916.45,N,12311.12,W,225444,A*31",
+        "$GPVTG,054.7,T,034.4,M,005.5,N,010.2,K*48",
+        "$HEHDT,274.07,T*19",
+        "$HCHDG,98.3,0.0,E,12.6,W*57",
+        "$IIMWV,214.8,R,0.1,K,A*36",
+        "$SDDBT,7.8,f,2.4,M,1.3,F*0D",
+        "$SDDPT,2.4,0.5*54",
+        "$YXMTW,17.9,C*1D",
+        "$GPZDA,201530.00,04,07,2002,00,00*60",
+        "$IIVHW,245.1,T,245.1,M,000.01,N,000.01,K*55",
+        "$GPGSV,3,1,11,03,03,111,00,04,15,270,00,06,01,010,00,13,06,292,00*74",
+        "$GPGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39",
+        "!AIVDM,1,1,,B,15M67FC000G?ufbE`FepT@3n00Sa,0*5C",
+    ];
+
+    #[test]
+    fn to_sentence_round_trips() {
+        for line in SENTENCES {
+            let sentence = Nmea0183::from_string(&line.to_string()).unwrap();
+            assert!(!matches!(sentence, Nmea0183::Unknown(_)), "{line}");
+            assert_eq!(sentence.to_sentence(), format!("{line}\r\n"));
+        }
+    }
+
+    #[test]
+    fn typed_to_sentence_matches_enum() {
+        let line = SENTENCES[0].to_string();
+        match Nmea0183::from_string(&line).unwrap() {
+            Nmea0183::RMC(rmc) => assert_eq!(rmc.to_sentence(), format!("{line}\r\n")),
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    #[test]
+    fn sender_and_message() {
+        let sentence = Nmea0183::from_string(&SENTENCES[4].to_string()).unwrap();
+        assert_eq!(sender(&sentence).unwrap(), "HE");
+        assert_eq!(message(&sentence).unwrap(), "HDT");
+    }
+
+    fn checksummed(body: &str) -> String {
+        let checksum = body.bytes().skip(1).fold(0, |c, b| c ^ b);
+        format!("{body}*{checksum:02X}")
+    }
+
+    #[test]
+    fn malformed_lines_return_errors() {
+        for line in [
+            "",
+            "$",
+            "$*00",
+            "$G*47",
+            "$GP*17",
+            "GPRMC,123519,A*6A",
+            "$GPRMC,123519,A*ZZ",
+            "$GPRMC,123519,A*",
+            "$GPRMC,123519,A",
+            "$\u{e9}\u{e9}RMC,A*00",
+            "!\u{1F6A2}*00",
+        ] {
+            assert!(
+                Nmea0183::from_string(&line.to_string()).is_err(),
+                "{line:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn truncated_sentences_do_not_panic() {
+        for line in SENTENCES {
+            let body = line.split('*').next().unwrap();
+            for end in 1..=body.len() {
+                if let Ok(sentence) = Nmea0183::from_string(&checksummed(&body[..end])) {
+                    for field in sentence.inspect() {
+                        let _ = field.value;
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn short_sentences_report_missing_fields() {
+        let rmc = Nmea0183::from_string(&checksummed("$GPRMC,123519,A")).unwrap();
+        match rmc {
+            Nmea0183::RMC(rmc) => assert!(matches!(
+                rmc.magnetic_variation(),
+                Err(NmeaError::MissingField(9))
+            )),
+            other => panic!("unexpected {other:?}"),
+        }
+        let mda = Nmea0183::from_string(&checksummed("$WIMDA,29.92")).unwrap();
+        match mda {
+            Nmea0183::MDA(mda) => assert!(matches!(
+                mda.pressure(),
+                Err(NmeaError::UnknownUnits { index: 1, .. })
+            )),
+            other => panic!("unexpected {other:?}"),
+        }
+        let xdr = Nmea0183::from_string(&checksummed("$YXXDR,C,19.3")).unwrap();
+        match xdr {
+            Nmea0183::XDR(xdr) => assert!(matches!(
+                xdr.measurements::<f32>(0),
+                Err(NmeaError::IndexOutOfRange(0))
+            )),
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    #[test]
+    fn classifies_gps_integrity_sentences() {
+        let classified = |line: &str| Nmea0183::from_string(&line.to_string()).unwrap();
+        assert!(matches!(
+            classified("$GPBWC,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM,A*4C"),
+            Nmea0183::BWC(_)
+        ));
+        assert!(matches!(
+            classified("$GPBWR,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,K,EGLM*35"),
+            Nmea0183::BWR(_)
+        ));
+        assert!(matches!(
+            classified("$GPGBS,235458.00,1.4,1.3,3.1,03,,-21.4,3.8,1,0*5A"),
+            Nmea0183::GBS(_)
+        ));
+        assert!(matches!(
+            classified("$GNGRS,104148.00,1,2.6,2.2,-1.6,-1.1,-1.7,-1.5,5.8,1.7,,,,,1,1*52"),
+            Nmea0183::GRS(_)
+        ));
+        assert!(matches!(
+            classified("$GPGST,082356.00,1.8,,,,1.7,1.3,2.2*7E"),
+            Nmea0183::GST(_)
+        ));
+        assert!(matches!(
+            classified("$GPGXA,001045.00,5655.8,N,00320.5,W,EGLM,31*6C"),
+            Nmea0183::GXA(_)
+        ));
+        assert!(matches!(
+            classified("$GPTRF,121314.00,230394,4807.038,N,01131.000,E,45.0,5,12,1.5,17,A*0F"),
+            Nmea0183::TRF(_)
+        ));
+    }
+
+    #[test]
+    fn borrowed_parse_matches_owned_parse() {
+        for line in SENTENCES {
+            let owned = Nmea0183Base::from_string(&line.to_string()).unwrap();
+            let borrowed = Nmea0183Ref::parse(line).unwrap();
+            let base = borrowed.base();
+            assert_eq!(base.delimiter(), owned.delimiter(), "{line}");
+            assert_eq!(base.sender(), owned.sender(), "{line}");
+            assert_eq!(base.message(), owned.message(), "{line}");
+            assert_eq!(base.checksum(), owned.checksum(), "{line}");
+            assert_eq!(
+                base.fields().collect::<Vec<_>>(),
+                owned.parameters,
+                "{line}"
+            );
+            assert_eq!(base.sentence_type(), owned.sentence_type(), "{line}");
+            assert_eq!(borrowed.to_sentence(), format!("{line}\r\n"));
+            let reparsed = borrowed.into_owned();
+            assert_eq!(
+                core::mem::discriminant(&reparsed),
+                core::mem::discriminant(&classify(owned)),
+                "{line}"
+            );
+        }
+    }
+
+    #[test]
+    fn borrowed_sentences_classify() {
+        match Nmea0183Ref::parse("$HEHDT,274.07,T*19").unwrap() {
+            Nmea0183Ref::HDT(hdt) => assert_eq!(hdt.heading().unwrap(), 274.07),
+            other => panic!("unexpected {other:?}"),
+        }
+        assert!(matches!(
+            Nmea0183Ref::parse("$PGRME,15.0,M,45.0,M,25.0,M*1C"),
+            Ok(Nmea0183Ref::Proprietary(_))
+        ));
+        assert!(matches!(
+            Nmea0183Ref::parse(&checksummed("$GPXYZ,1,2")),
+            Ok(Nmea0183Ref::Unknown(_))
+        ));
+        assert!(matches!(
+            Nmea0183Ref::parse("$HEHDT,274.07,T*18"),
+            Err(NmeaError::BadChecksum { .. })
+        ));
+        assert!(matches!(
+            Nmea0183Ref::parse_with("$HEHDT,274.07,T*18", ChecksumPolicy::Permissive),
+            Ok(Nmea0183Ref::HDT(_))
+        ));
+    }
+
+    #[test]
+    fn sentence_ref_fields() {
+        let sentence =
+            SentenceRef::parse("$GPGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39").unwrap();
+        assert_eq!(sentence.field_count(), 17);
+        assert_eq!(sentence.field(0).unwrap(), "A");
+        assert_eq!(sentence.field(4).unwrap(), "");
+        assert_eq!(sentence.field(16).unwrap(), "2.1");
+        assert!(matches!(
+            sentence.field(17),
+            Err(NmeaError::MissingField(17))
+        ));
+        assert_eq!(sentence.to_base().parameters.len(), 17);
+
+        let line = checksummed("$GPTXT");
+        let sentence = SentenceRef::parse(&line).unwrap();
+        assert_eq!(sentence.field_count(), 0);
+        assert_eq!(sentence.fields().count(), 0);
+        assert!(matches!(sentence.field(0), Err(NmeaError::MissingField(0))));
+
+        let line = checksummed("$GPTXT,");
+        let sentence = SentenceRef::parse(&line).unwrap();
+        assert_eq!(sentence.fields().collect::<Vec<_>>(), [""]);
+        let owned = Nmea0183Base::from_string(&line).unwrap();
+        assert_eq!(owned.parameters, [""]);
+    }
+
+    #[cfg(feature = "serde")]
+    fn json(line: &str) -> serde_json::Value {
+        serde_json::to_value(Nmea0183::from_string(&line.to_string()).unwrap()).unwrap()
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn angles_serialize_with_units() {
+        let degrees = |value: f64| serde_json::json!({ "value": value, "unit": "deg" });
+        let rmc = &json(SENTENCES[0])["RMC"];
+        assert_eq!(rmc["track_made_good"], degrees(84.4f32 as f64));
+        assert_eq!(rmc["magnetic_variation"], degrees(-3.1f32 as f64));
+        assert_eq!(rmc["sog"]["unit"], "m/s");
+        assert_eq!(
+            json(SENTENCES[4])["HDT"]["heading"],
+            degrees(274.07f32 as f64)
+        );
+        assert_eq!(
+            json(SENTENCES[6])["MWV"]["angle_relative"],
+            degrees(214.8f32 as f64)
+        );
+        assert_eq!(
+            json(SENTENCES[6])["MWV"]["angle_true"],
+            serde_json::Value::Null
+        );
+
+        let apb = &json("$GPAPB,A,A,0.10,R,N,V,V,011,M,DEST,011,M,011,M*3C")["APB"];
+        assert_eq!(
+            apb["heading_to_steer"],
+            serde_json::json!({ "Magnetic": degrees(11.0) })
+        );
+
+        let vdm = &json(SENTENCES[14])["VDM"]["decoded"]["PositionReport"];
+        assert_eq!(vdm["cog"], degrees(219.3f32 as f64));
+        assert_eq!(vdm["heading"], degrees(1.0));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn fix_data_serializes_with_units() {
+        let quantity = |value: f32, unit: &str| serde_json::json!({ "value": value, "unit": unit });
+        let gga = &json("$GPGGA,123519,4807.038,N,01131.000,E,2,08,0.9,545.4,M,46.9,M,3.2,0120*68")
+            ["GGA"];
+        assert_eq!(gga["height"], quantity(545.4, "m"));
+        assert_eq!(gga["geoid_separation"], quantity(46.9, "m"));
+        assert_eq!(gga["differential_age"], quantity(3.2, "s"));
+        assert_eq!(gga["reference_station"], 120);
+        assert_eq!(
+            json(SENTENCES[1])["GGA"]["differential_age"],
+            serde_json::Value::Null
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn bearings_round_trip_through_json() {
+        use navigation::Bearing;
+        let bearing = Bearing::True(11.5);
+        let value = serde_json::to_value(bearing).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({ "True": { "value": 11.5, "unit": "deg" } })
+        );
+        assert_eq!(serde_json::from_value::<Bearing>(value).unwrap(), bearing);
+        let wrong_unit = serde_json::json!({ "True": { "value": 11.5, "unit": "rad" } });
+        assert!(serde_json::from_value::<Bearing>(wrong_unit).is_err());
+    }
 }
@@ -1,36 +1,53 @@
 use crate::base::*;
+use crate::inspect::Inspect;
 
 #[derive(Debug)]
-pub struct Dbs {
-    base: Nmea0183Base,
+pub struct Dbs<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Dbs {
-    pub fn new(base: Nmea0183Base) -> Dbs {
+    pub fn builder() -> DbsBuilder {
+        DbsBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Dbs<B> {
+    pub fn new(base: B) -> Dbs<B> {
         Dbs { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
     pub fn depth(&self) -> Result<Distance, NmeaError> {
-        if self.base.parameters[1] == "M" {
+        if self.base.field_str(1) == "M" {
             Ok(Distance::from_meters(self.base.parameter(0)?))
-        } else if self.base.parameters[3] == "M" {
+        } else if self.base.field_str(3) == "M" {
             Ok(Distance::from_meters(self.base.parameter(2)?))
-        } else if self.base.parameters[5] == "M" {
+        } else if self.base.field_str(5) == "M" {
             Ok(Distance::from_meters(self.base.parameter(4)?))
-        } else if self.base.parameters[1] == "f" {
+        } else if self.base.field_str(1) == "f" {
             Ok(Distance::from_miles(
                 self.base.parameter::<f32>(0)? / 5280.0,
             ))
-        } else if self.base.parameters[3] == "f" {
+        } else if self.base.field_str(3) == "f" {
             Ok(Distance::from_miles(
                 self.base.parameter::<f32>(2)? / 5280.0,
             ))
-        } else if self.base.parameters[5] == "f" {
+        } else if self.base.field_str(5) == "f" {
             Ok(Distance::from_miles(
                 self.base.parameter::<f32>(4)? / 5280.0,
             ))
         } else {
-            Err(NmeaError("Invalid data.".to_string()))
+            Err(self.base.unknown_units(1))
         }
     }
 
@@ -42,3 +59,63 @@ impl Dbs {
         Ok(Distance::from_meters(self.base.parameter(2)?))
     }
 }
+
+impl<B: SentenceFields> Inspect for Dbs<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Dbs<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Dbs", 4)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("depth", &self.depth().ok())?;
+        state.serialize_field("offset", &self.offset().ok())?;
+        state.serialize_field("maximum_range", &self.maximum_range().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct DbsBuilder {
+    talker: String,
+    depth: Option<Distance>,
+}
+
+#[cfg(feature = "std")]
+impl DbsBuilder {
+    pub fn new() -> DbsBuilder {
+        DbsBuilder {
+            talker: String::from("SD"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn depth(mut self, depth: Distance) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    pub fn build(self) -> Dbs {
+        let parameters = vec![
+            format_float(self.depth.map(|d| d.as_feet()), 1),
+            String::from("f"),
+            format_float(self.depth.map(|d| d.as_meters()), 1),
+            String::from("M"),
+            format_float(self.depth.map(|d| d.as_fathoms()), 1),
+            String::from("F"),
+        ];
+        Dbs::new(Nmea0183Base::from_parts(&self.talker, "DBS", parameters))
+    }
+}
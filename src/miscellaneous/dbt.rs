@@ -1,36 +1,111 @@
 use crate::base::*;
+use crate::inspect::Inspect;
 
 #[derive(Debug)]
-pub struct Dbt {
-    base: Nmea0183Base,
+pub struct Dbt<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Dbt {
-    pub fn new(base: Nmea0183Base) -> Dbt {
+    pub fn builder() -> DbtBuilder {
+        DbtBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Dbt<B> {
+    pub fn new(base: B) -> Dbt<B> {
         Dbt { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
     pub fn depth(&self) -> Result<Distance, NmeaError> {
-        if self.base.parameters[1] == "M" {
+        if self.base.field_str(1) == "M" {
             Ok(Distance::from_meters(self.base.parameter(0)?))
-        } else if self.base.parameters[3] == "M" {
+        } else if self.base.field_str(3) == "M" {
             Ok(Distance::from_meters(self.base.parameter(2)?))
-        } else if self.base.parameters[5] == "M" {
+        } else if self.base.field_str(5) == "M" {
             Ok(Distance::from_meters(self.base.parameter(4)?))
-        } else if self.base.parameters[1] == "f" {
+        } else if self.base.field_str(1) == "f" {
             Ok(Distance::from_miles(
                 self.base.parameter::<f32>(0)? / 5280.0,
             ))
-        } else if self.base.parameters[3] == "f" {
+        } else if self.base.field_str(3) == "f" {
             Ok(Distance::from_miles(
                 self.base.parameter::<f32>(2)? / 5280.0,
             ))
-        } else if self.base.parameters[5] == "f" {
+        } else if self.base.field_str(5) == "f" {
             Ok(Distance::from_miles(
                 self.base.parameter::<f32>(4)? / 5280.0,
             ))
         } else {
-            Err(NmeaError("Invalid data.".to_string()))
+            Err(self.base.unknown_units(1))
+        }
+    }
+}
+
+impl<B: SentenceFields> Inspect for Dbt<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Dbt<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Dbt", 2)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("depth", &self.depth().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct DbtBuilder {
+    talker: String,
+    depth: Option<Distance>,
+}
+
+#[cfg(feature = "std")]
+impl DbtBuilder {
+    pub fn new() -> DbtBuilder {
+        DbtBuilder {
+            talker: String::from("SD"),
+            ..Default::default()
         }
     }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn depth(mut self, depth: Distance) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    pub fn build(self) -> Dbt {
+        let parameters = vec![
+            format_float(self.depth.map(|d| d.as_feet()), 1),
+            String::from("f"),
+            format_float(self.depth.map(|d| d.as_meters()), 1),
+            String::from("M"),
+            format_float(self.depth.map(|d| d.as_fathoms()), 1),
+            String::from("F"),
+        ];
+        Dbt::new(Nmea0183Base::from_parts(&self.talker, "DBT", parameters))
+    }
 }
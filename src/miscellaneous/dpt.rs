@@ -1,15 +1,32 @@
 use crate::base::*;
+use crate::inspect::Inspect;
 
 #[derive(Debug)]
-pub struct Dpt {
-    base: Nmea0183Base,
+pub struct Dpt<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Dpt {
-    pub fn new(base: Nmea0183Base) -> Dpt {
+    pub fn builder() -> DptBuilder {
+        DptBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Dpt<B> {
+    pub fn new(base: B) -> Dpt<B> {
         Dpt { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
     pub fn depth(&self) -> Result<Distance, NmeaError> {
         Ok(Distance::from_meters(self.base.parameter(0)?))
     }
@@ -22,3 +39,74 @@ impl Dpt {
         Ok(Distance::from_meters(self.base.parameter(2)?))
     }
 }
+
+impl<B: SentenceFields> Inspect for Dpt<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Dpt<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Dpt", 4)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("depth", &self.depth().ok())?;
+        state.serialize_field("offset", &self.offset().ok())?;
+        state.serialize_field("maximum_range", &self.maximum_range().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct DptBuilder {
+    talker: String,
+    depth: Option<Distance>,
+    offset: Option<Distance>,
+    maximum_range: Option<Distance>,
+}
+
+#[cfg(feature = "std")]
+impl DptBuilder {
+    pub fn new() -> DptBuilder {
+        DptBuilder {
+            talker: String::from("SD"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn depth(mut self, depth: Distance) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    pub fn offset(mut self, offset: Distance) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn maximum_range(mut self, maximum_range: Distance) -> Self {
+        self.maximum_range = Some(maximum_range);
+        self
+    }
+
+    pub fn build(self) -> Dpt {
+        let mut parameters = vec![
+            format_float(self.depth.map(|d| d.as_meters()), 1),
+            format_float(self.offset.map(|d| d.as_meters()), 1),
+        ];
+        if self.maximum_range.is_some() {
+            parameters.push(format_float(self.maximum_range.map(|d| d.as_meters()), 1));
+        }
+        Dpt::new(Nmea0183Base::from_parts(&self.talker, "DPT", parameters))
+    }
+}
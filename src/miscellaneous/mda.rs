@@ -1,46 +1,63 @@
 use crate::base::*;
+use crate::inspect::Inspect;
 
 #[derive(Debug)]
-pub struct Mda {
-    base: Nmea0183Base,
+pub struct Mda<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Mda {
-    pub fn new(base: Nmea0183Base) -> Mda {
+    pub fn builder() -> MdaBuilder {
+        MdaBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Mda<B> {
+    pub fn new(base: B) -> Mda<B> {
         Mda { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
     pub fn pressure(&self) -> Result<Pressure, NmeaError> {
-        if self.base.parameters[1] == "B" {
+        if self.base.field_str(1) == "B" {
             Ok(Pressure::from_bar(self.base.parameter(0)?))
-        } else if self.base.parameters[3] == "B" {
+        } else if self.base.field_str(3) == "B" {
             Ok(Pressure::from_bar(self.base.parameter(2)?))
-        } else if self.base.parameters[1] == "I" {
+        } else if self.base.field_str(1) == "I" {
             Ok(Pressure::from_inches_mercury(self.base.parameter(0)?))
-        } else if self.base.parameters[3] == "I" {
+        } else if self.base.field_str(3) == "I" {
             Ok(Pressure::from_inches_mercury(self.base.parameter(3)?))
         } else {
-            Err(NmeaError("Invalid data".to_string()))
+            Err(self.base.unknown_units(1))
         }
     }
 
     pub fn air_temperature(&self) -> Result<Temperature, NmeaError> {
-        if self.base.parameters[5] == "C" {
+        if self.base.field_str(5) == "C" {
             Ok(Temperature::from_celsius(self.base.parameter(4)?))
-        } else if self.base.parameters[5] == "F" {
+        } else if self.base.field_str(5) == "F" {
             Ok(Temperature::from_fahrenheit(self.base.parameter(4)?))
         } else {
-            Err(NmeaError("Invalid data".to_string()))
+            Err(self.base.unknown_units(5))
         }
     }
 
     pub fn water_temperature(&self) -> Result<Temperature, NmeaError> {
-        if self.base.parameters[7] == "C" {
+        if self.base.field_str(7) == "C" {
             Ok(Temperature::from_celsius(self.base.parameter(6)?))
-        } else if self.base.parameters[7] == "F" {
+        } else if self.base.field_str(7) == "F" {
             Ok(Temperature::from_fahrenheit(self.base.parameter(6)?))
         } else {
-            Err(NmeaError("Invalid data".to_string()))
+            Err(self.base.unknown_units(7))
         }
     }
 
@@ -53,50 +70,203 @@ impl Mda {
     }
 
     pub fn dew_point(&self) -> Result<Temperature, NmeaError> {
-        if self.base.parameters[11] == "C" {
+        if self.base.field_str(11) == "C" {
             Ok(Temperature::from_celsius(self.base.parameter(10)?))
-        } else if self.base.parameters[11] == "F" {
+        } else if self.base.field_str(11) == "F" {
             Ok(Temperature::from_fahrenheit(self.base.parameter(10)?))
         } else {
-            Err(NmeaError("Invalid data".to_string()))
+            Err(self.base.unknown_units(11))
         }
     }
 
     pub fn wind_direction_true(&self) -> F32Error {
-        if self.base.parameters[13] == "T" {
+        if self.base.field_str(13) == "T" {
             self.base.parameter(12)
-        } else if self.base.parameters[15] == "T" {
+        } else if self.base.field_str(15) == "T" {
             self.base.parameter(14)
         } else {
-            Err(NmeaError("Not found".to_string()))
+            Err(NmeaError::NotPresent)
         }
     }
 
     pub fn wind_direction_magnetic(&self) -> F32Error {
-        if self.base.parameters[13] == "M" {
+        if self.base.field_str(13) == "M" {
             self.base.parameter(12)
-        } else if self.base.parameters[15] == "M" {
+        } else if self.base.field_str(15) == "M" {
             self.base.parameter(14)
         } else {
-            Err(NmeaError("Not found".to_string()))
+            Err(NmeaError::NotPresent)
         }
     }
 
     pub fn wind_speed(&self) -> Result<Speed, NmeaError> {
-        if self.base.parameters[17] == "M" {
+        if self.base.field_str(17) == "M" {
             Ok(Speed::from_mps(self.base.parameter(16)?))
-        } else if self.base.parameters[19] == "M" {
+        } else if self.base.field_str(19) == "M" {
             Ok(Speed::from_mps(self.base.parameter(18)?))
         } else {
-            match self.base.parameters[17].as_str() {
+            match self.base.field_str(17) {
                 "N" => Ok(Speed::from_knots(self.base.parameter(16)?)),
                 "K" => Ok(Speed::from_kph(self.base.parameter(16)?)),
-                _ => match self.base.parameters[19].as_str() {
+                _ => match self.base.field_str(19) {
                     "N" => Ok(Speed::from_knots(self.base.parameter(18)?)),
                     "K" => Ok(Speed::from_kph(self.base.parameter(18)?)),
-                    _ => Err(NmeaError("Invalid data".to_string())),
+                    _ => Err(self.base.unknown_units(17)),
                 },
             }
         }
     }
 }
+
+impl<B: SentenceFields> Inspect for Mda<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Mda<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Mda", 10)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("pressure", &self.pressure().ok())?;
+        state.serialize_field("air_temperature", &self.air_temperature().ok())?;
+        state.serialize_field("water_temperature", &self.water_temperature().ok())?;
+        state.serialize_field("relative_humidity", &self.relative_humidity().ok())?;
+        state.serialize_field("absolute_humidity", &self.absolute_humidity().ok())?;
+        state.serialize_field("dew_point", &self.dew_point().ok())?;
+        state.serialize_field(
+            "wind_direction_true",
+            &self.wind_direction_true().ok().map(Degrees),
+        )?;
+        state.serialize_field(
+            "wind_direction_magnetic",
+            &self.wind_direction_magnetic().ok().map(Degrees),
+        )?;
+        state.serialize_field("wind_speed", &self.wind_speed().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct MdaBuilder {
+    talker: String,
+    pressure: Option<Pressure>,
+    air_temperature: Option<Temperature>,
+    water_temperature: Option<Temperature>,
+    relative_humidity: Option<f32>,
+    absolute_humidity: Option<f32>,
+    dew_point: Option<Temperature>,
+    wind_direction_true: Option<f32>,
+    wind_direction_magnetic: Option<f32>,
+    wind_speed: Option<Speed>,
+}
+
+#[cfg(feature = "std")]
+impl MdaBuilder {
+    pub fn new() -> MdaBuilder {
+        MdaBuilder {
+            talker: String::from("WI"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn pressure(mut self, pressure: Pressure) -> Self {
+        self.pressure = Some(pressure);
+        self
+    }
+
+    pub fn air_temperature(mut self, air_temperature: Temperature) -> Self {
+        self.air_temperature = Some(air_temperature);
+        self
+    }
+
+    pub fn water_temperature(mut self, water_temperature: Temperature) -> Self {
+        self.water_temperature = Some(water_temperature);
+        self
+    }
+
+    pub fn relative_humidity(mut self, relative_humidity: f32) -> Self {
+        self.relative_humidity = Some(relative_humidity);
+        self
+    }
+
+    pub fn absolute_humidity(mut self, absolute_humidity: f32) -> Self {
+        self.absolute_humidity = Some(absolute_humidity);
+        self
+    }
+
+    pub fn dew_point(mut self, dew_point: Temperature) -> Self {
+        self.dew_point = Some(dew_point);
+        self
+    }
+
+    pub fn wind_direction_true(mut self, wind_direction_true: f32) -> Self {
+        self.wind_direction_true = Some(wind_direction_true);
+        self
+    }
+
+    pub fn wind_direction_magnetic(mut self, wind_direction_magnetic: f32) -> Self {
+        self.wind_direction_magnetic = Some(wind_direction_magnetic);
+        self
+    }
+
+    pub fn wind_speed(mut self, wind_speed: Speed) -> Self {
+        self.wind_speed = Some(wind_speed);
+        self
+    }
+
+    pub fn build(self) -> Mda {
+        let parameters = vec![
+            format_float(self.pressure.map(|p| p.as_inches_mercury()), 2),
+            String::from("I"),
+            format_float(self.pressure.map(|p| p.as_bar()), 3),
+            String::from("B"),
+            format_float(self.air_temperature.map(|t| t.as_celsius()), 1),
+            String::from("C"),
+            format_float(self.water_temperature.map(|t| t.as_celsius()), 1),
+            String::from("C"),
+            format_float(self.relative_humidity, 1),
+            format_float(self.absolute_humidity, 1),
+            format_float(self.dew_point.map(|t| t.as_celsius()), 1),
+            String::from("C"),
+            format_float(self.wind_direction_true, 1),
+            String::from("T"),
+            format_float(self.wind_direction_magnetic, 1),
+            String::from("M"),
+            format_float(self.wind_speed.map(|s| s.as_knots()), 1),
+            String::from("N"),
+            format_float(self.wind_speed.map(|s| s.as_mps()), 1),
+            String::from("M"),
+        ];
+        Mda::new(Nmea0183Base::from_parts(&self.talker, "MDA", parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_converts_units() {
+        let mda = Mda::builder()
+            .pressure(Pressure::from_bar(1.013))
+            .air_temperature(Temperature::from_fahrenheit(68.0))
+            .wind_speed(Speed::from_mps(5.0))
+            .build();
+        let fields = &mda.base().parameters;
+        assert_eq!(fields[0..6], ["29.92", "I", "1.013", "B", "20.0", "C"]);
+        assert_eq!(fields[16..20], ["9.7", "N", "5.0", "M"]);
+        assert!((mda.pressure().unwrap().as_bar() - 1.013).abs() < 1e-6);
+        assert!((mda.air_temperature().unwrap().as_celsius() - 20.0).abs() < 1e-6);
+    }
+}
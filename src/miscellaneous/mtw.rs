@@ -1,20 +1,91 @@
 use crate::base::*;
+use crate::inspect::Inspect;
 
 #[derive(Debug)]
-pub struct Mtw {
-    base: Nmea0183Base,
+pub struct Mtw<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Mtw {
-    pub fn new(base: Nmea0183Base) -> Mtw {
+    pub fn builder() -> MtwBuilder {
+        MtwBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Mtw<B> {
+    pub fn new(base: B) -> Mtw<B> {
         Mtw { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
     pub fn temperature(&self) -> Result<Temperature, NmeaError> {
-        match self.base.parameters[1].as_str() {
+        match self.base.field_str(1) {
             "C" => Ok(Temperature::from_celsius(self.base.parameter::<f32>(0)?)),
             "F" => Ok(Temperature::from_fahrenheit(self.base.parameter::<f32>(0)?)),
-            _ => Err(NmeaError("Not found".to_string())),
+            _ => Err(self.base.unknown_units(1)),
+        }
+    }
+}
+
+impl<B: SentenceFields> Inspect for Mtw<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Mtw<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Mtw", 2)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("temperature", &self.temperature().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct MtwBuilder {
+    talker: String,
+    temperature: Option<Temperature>,
+}
+
+#[cfg(feature = "std")]
+impl MtwBuilder {
+    pub fn new() -> MtwBuilder {
+        MtwBuilder {
+            talker: String::from("YX"),
+            ..Default::default()
         }
     }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn temperature(mut self, temperature: Temperature) -> Self {
+        self.temperature = Some(temperature);
+        self
+    }
+
+    pub fn build(self) -> Mtw {
+        let parameters = vec![
+            format_float(self.temperature.map(|t| t.as_celsius()), 1),
+            String::from("C"),
+        ];
+        Mtw::new(Nmea0183Base::from_parts(&self.talker, "MTW", parameters))
+    }
 }
@@ -1,20 +1,37 @@
 use crate::base::*;
+use crate::inspect::Inspect;
 
 #[derive(Debug)]
-pub struct Rsa {
-    base: Nmea0183Base,
+pub struct Rsa<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Rsa {
-    pub fn new(base: Nmea0183Base) -> Rsa {
+    pub fn builder() -> RsaBuilder {
+        RsaBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Rsa<B> {
+    pub fn new(base: B) -> Rsa<B> {
         Rsa { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
     pub fn angle(&self) -> F32Error {
-        if self.base.parameters[1] == "A" {
+        if self.base.field_str(1) == "A" {
             self.base.parameter(0)
         } else {
-            Err(NmeaError("Not found".to_string()))
+            Err(NmeaError::DataInvalid)
         }
     }
 
@@ -23,10 +40,81 @@ impl Rsa {
     }
 
     pub fn port_rudder(&self) -> F32Error {
-        if self.base.parameters[3] == "A" {
+        if self.base.field_str(3) == "A" {
             self.base.parameter(2)
         } else {
-            Err(NmeaError("Not found".to_string()))
+            Err(NmeaError::DataInvalid)
+        }
+    }
+}
+
+impl<B: SentenceFields> Inspect for Rsa<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Rsa<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Rsa", 4)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("angle", &self.angle().ok().map(Degrees))?;
+        state.serialize_field(
+            "starboard_rudder",
+            &self.starboard_rudder().ok().map(Degrees),
+        )?;
+        state.serialize_field("port_rudder", &self.port_rudder().ok().map(Degrees))?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct RsaBuilder {
+    talker: String,
+    starboard_rudder: Option<f32>,
+    port_rudder: Option<f32>,
+}
+
+#[cfg(feature = "std")]
+impl RsaBuilder {
+    pub fn new() -> RsaBuilder {
+        RsaBuilder {
+            talker: String::from("AG"),
+            ..Default::default()
         }
     }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn starboard_rudder(mut self, starboard_rudder: f32) -> Self {
+        self.starboard_rudder = Some(starboard_rudder);
+        self
+    }
+
+    pub fn port_rudder(mut self, port_rudder: f32) -> Self {
+        self.port_rudder = Some(port_rudder);
+        self
+    }
+
+    pub fn build(self) -> Rsa {
+        let parameters = vec![
+            format_float(self.starboard_rudder, 1),
+            String::from(if self.starboard_rudder.is_some() {
+                "A"
+            } else {
+                "V"
+            }),
+            format_float(self.port_rudder, 1),
+            String::from(if self.port_rudder.is_some() { "A" } else { "V" }),
+        ];
+        Rsa::new(Nmea0183Base::from_parts(&self.talker, "RSA", parameters))
+    }
 }
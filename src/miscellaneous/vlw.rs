@@ -1,48 +1,154 @@
 use crate::base::*;
+use crate::inspect::Inspect;
 
 #[derive(Debug)]
-pub struct Vlw {
-    base: Nmea0183Base,
+pub struct Vlw<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Vlw {
-    pub fn new(base: Nmea0183Base) -> Vlw {
+    pub fn builder() -> VlwBuilder {
+        VlwBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Vlw<B> {
+    pub fn new(base: B) -> Vlw<B> {
         Vlw { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
     pub fn cumulative_dtw(&self) -> Result<Distance, NmeaError> {
-        match self.base.parameters[1].as_str() {
+        match self.base.field_str(1) {
             "M" => Ok(Distance::from_meters(self.base.parameter(0)?)),
             "K" => Ok(Distance::from_kilometers(self.base.parameter(0)?)),
             "N" => Ok(Distance::from_nautical_miles(self.base.parameter(0)?)),
-            _ => Err(NmeaError("Invalid data".to_string())),
+            _ => Err(self.base.unknown_units(1)),
         }
     }
 
     pub fn dtw_since_reset(&self) -> Result<Distance, NmeaError> {
-        match self.base.parameters[3].as_str() {
+        match self.base.field_str(3) {
             "M" => Ok(Distance::from_meters(self.base.parameter(2)?)),
             "K" => Ok(Distance::from_kilometers(self.base.parameter(2)?)),
             "N" => Ok(Distance::from_nautical_miles(self.base.parameter(2)?)),
-            _ => Err(NmeaError("Invalid data".to_string())),
+            _ => Err(self.base.unknown_units(3)),
         }
     }
 
     pub fn dog_cumulative(&self) -> Result<Distance, NmeaError> {
-        match self.base.parameters[5].as_str() {
+        match self.base.field_str(5) {
             "M" => Ok(Distance::from_meters(self.base.parameter(4)?)),
             "K" => Ok(Distance::from_kilometers(self.base.parameter(4)?)),
             "N" => Ok(Distance::from_nautical_miles(self.base.parameter(4)?)),
-            _ => Err(NmeaError("Invalid data".to_string())),
+            _ => Err(self.base.unknown_units(5)),
         }
     }
 
     pub fn dog_since_reset(&self) -> Result<Distance, NmeaError> {
-        match self.base.parameters[7].as_str() {
+        match self.base.field_str(7) {
             "M" => Ok(Distance::from_meters(self.base.parameter(6)?)),
             "K" => Ok(Distance::from_kilometers(self.base.parameter(6)?)),
             "N" => Ok(Distance::from_nautical_miles(self.base.parameter(6)?)),
-            _ => Err(NmeaError("Invalid data".to_string())),
+            _ => Err(self.base.unknown_units(7)),
+        }
+    }
+}
+
+impl<B: SentenceFields> Inspect for Vlw<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Vlw<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Vlw", 5)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("cumulative_dtw", &self.cumulative_dtw().ok())?;
+        state.serialize_field("dtw_since_reset", &self.dtw_since_reset().ok())?;
+        state.serialize_field("dog_cumulative", &self.dog_cumulative().ok())?;
+        state.serialize_field("dog_since_reset", &self.dog_since_reset().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct VlwBuilder {
+    talker: String,
+    cumulative_dtw: Option<Distance>,
+    dtw_since_reset: Option<Distance>,
+    dog_cumulative: Option<Distance>,
+    dog_since_reset: Option<Distance>,
+}
+
+#[cfg(feature = "std")]
+impl VlwBuilder {
+    pub fn new() -> VlwBuilder {
+        VlwBuilder {
+            talker: String::from("VW"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn cumulative_dtw(mut self, cumulative_dtw: Distance) -> Self {
+        self.cumulative_dtw = Some(cumulative_dtw);
+        self
+    }
+
+    pub fn dtw_since_reset(mut self, dtw_since_reset: Distance) -> Self {
+        self.dtw_since_reset = Some(dtw_since_reset);
+        self
+    }
+
+    pub fn dog_cumulative(mut self, dog_cumulative: Distance) -> Self {
+        self.dog_cumulative = Some(dog_cumulative);
+        self
+    }
+
+    pub fn dog_since_reset(mut self, dog_since_reset: Distance) -> Self {
+        self.dog_since_reset = Some(dog_since_reset);
+        self
+    }
+
+    pub fn build(self) -> Vlw {
+        let mut parameters = vec![
+            format_float(self.cumulative_dtw.map(|d| d.as_nautical_mile()), 1),
+            String::from("N"),
+            format_float(self.dtw_since_reset.map(|d| d.as_nautical_mile()), 1),
+            String::from("N"),
+        ];
+        if self.dog_cumulative.is_some() || self.dog_since_reset.is_some() {
+            parameters.push(format_float(
+                self.dog_cumulative.map(|d| d.as_nautical_mile()),
+                1,
+            ));
+            parameters.push(String::from("N"));
+            parameters.push(format_float(
+                self.dog_since_reset.map(|d| d.as_nautical_mile()),
+                1,
+            ));
+            parameters.push(String::from("N"));
         }
+        Vlw::new(Nmea0183Base::from_parts(&self.talker, "VLW", parameters))
     }
 }
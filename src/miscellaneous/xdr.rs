@@ -1,34 +1,116 @@
-use std::str::FromStr;
+#[cfg(feature = "std")]
+use core::fmt::Display;
+use core::str::FromStr;
 
 use crate::base::*;
+use crate::inspect::Inspect;
 
 #[derive(Debug)]
-pub struct Xdr {
-    base: Nmea0183Base,
+pub struct Xdr<B = DefaultFields> {
+    base: B,
 }
 
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Transducer<T> {
     pub xdr_type: char,
     pub xdr_data: T,
     pub xdr_units: char,
-    pub xdr_name: String,
+    pub xdr_name: RawText,
 }
 
+#[cfg(feature = "std")]
 impl Xdr {
-    pub fn new(base: Nmea0183Base) -> Xdr {
+    pub fn builder() -> XdrBuilder {
+        XdrBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Xdr<B> {
+    pub fn new(base: B) -> Xdr<B> {
         Xdr { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
     pub fn measurements<T: FromStr>(&self, index: usize) -> Result<Transducer<T>, NmeaError> {
-        if index >= self.base.parameters.len() / 4 {
-            Err(NmeaError("Index out of range".to_string()))
+        if index >= self.base.field_count() / 4 {
+            Err(NmeaError::IndexOutOfRange(index))
         } else {
             Ok(Transducer::<T> {
-                xdr_type: self.base.parameters[index * 4].chars().nth(0).unwrap(),
+                xdr_type: self.base.field_char(index * 4)?,
                 xdr_data: self.base.parameter::<T>(index * 4 + 1)?,
-                xdr_units: self.base.parameters[index * 4 + 2].chars().nth(0).unwrap(),
-                xdr_name: self.base.parameters[index * 4 + 3].clone(),
+                xdr_units: self.base.field_char(index * 4 + 2)?,
+                xdr_name: RawText::from(self.base.field(index * 4 + 3)?),
             })
         }
     }
 }
+
+impl<B: SentenceFields> Inspect for Xdr<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Xdr<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Xdr", 2)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field(
+            "measurements",
+            &(0..self.base.field_count() / 4)
+                .filter_map(|n| self.measurements::<f32>(n).ok())
+                .collect::<Vec<_>>(),
+        )?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct XdrBuilder {
+    talker: String,
+    measurements: Vec<[String; 4]>,
+}
+
+#[cfg(feature = "std")]
+impl XdrBuilder {
+    pub fn new() -> XdrBuilder {
+        XdrBuilder {
+            talker: String::from("YX"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn measurement<T: Display>(mut self, transducer: Transducer<T>) -> Self {
+        self.measurements.push([
+            transducer.xdr_type.to_string(),
+            transducer.xdr_data.to_string(),
+            transducer.xdr_units.to_string(),
+            transducer.xdr_name,
+        ]);
+        self
+    }
+
+    pub fn build(self) -> Xdr {
+        let parameters = self.measurements.into_iter().flatten().collect();
+        Xdr::new(Nmea0183Base::from_parts(&self.talker, "XDR", parameters))
+    }
+}
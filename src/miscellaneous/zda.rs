@@ -1,38 +1,136 @@
 use crate::base::*;
+use crate::inspect::Inspect;
 
+#[cfg(feature = "std")]
 use chrono::prelude::*;
+#[cfg(feature = "std")]
 use chrono::Duration;
 
 #[derive(Debug)]
-pub struct Zda {
-    base: Nmea0183Base,
+pub struct Zda<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Zda {
-    pub fn new(base: Nmea0183Base) -> Zda {
+    pub fn builder() -> ZdaBuilder {
+        ZdaBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Zda<B> {
+    pub fn new(base: B) -> Zda<B> {
         Zda { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
+    #[cfg(feature = "std")]
     pub fn timestamp(&self) -> Result<DateTime<Utc>, NmeaError> {
         let timeportion = self.base.naive_time(0)?;
-        let date_string = self.base.parameters[1].clone()
-            + self.base.parameters[2].clone().as_str()
-            + self.base.parameters[3].clone().as_str();
+        let date_string = format!(
+            "{}{}{}",
+            self.base.field(1)?,
+            self.base.field(2)?,
+            self.base.field(3)?
+        );
         match NaiveDate::parse_from_str(date_string.as_str(), "%d%m%Y") {
             Ok(dateportion) => {
                 let datestamp = NaiveDateTime::new(dateportion, timeportion);
                 Ok(DateTime::from_naive_utc_and_offset(datestamp, Utc))
             }
-            Err(e) => Err(NmeaError(format!("{}", e))),
+            Err(_) => Err(NmeaError::InvalidField {
+                index: 1,
+                raw: date_string,
+            }),
         }
     }
 
+    #[cfg(feature = "std")]
     pub fn local_time(&self) -> NaiveDateTimeError {
         let utc = self.timestamp()?;
         let hours = self.base.parameter::<i64>(4)?;
-        let minutes = self.base.parameter::<i64>(5)? + hours * 60;
-        let delta = Duration::minutes(minutes);
-        let naive = NaiveDateTime::new(utc.date_naive(), utc.time()) + delta;
-        Ok(naive)
+        let minutes = hours
+            .checked_mul(60)
+            .and_then(|m| m.checked_add(self.base.parameter::<i64>(5).ok()?))
+            .and_then(Duration::try_minutes);
+        match minutes.and_then(|delta| utc.naive_utc().checked_add_signed(delta)) {
+            Some(naive) => Ok(naive),
+            None => Err(self.base.invalid_field(4)),
+        }
+    }
+}
+
+impl<B: SentenceFields> Inspect for Zda<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Zda<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Zda", 3)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("timestamp", &self.timestamp().ok())?;
+        state.serialize_field("local_time", &self.local_time().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct ZdaBuilder {
+    talker: String,
+    timestamp: Option<DateTime<Utc>>,
+    local_zone_hours: i8,
+    local_zone_minutes: u8,
+}
+
+#[cfg(feature = "std")]
+impl ZdaBuilder {
+    pub fn new() -> ZdaBuilder {
+        ZdaBuilder {
+            talker: String::from("GP"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: DateTime<Utc>) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    pub fn local_zone(mut self, hours: i8, minutes: u8) -> Self {
+        self.local_zone_hours = hours;
+        self.local_zone_minutes = minutes;
+        self
+    }
+
+    pub fn build(self) -> Zda {
+        let parameters = vec![
+            format_time(self.timestamp.map(|t| t.time())),
+            format_value(self.timestamp.map(|t| t.format("%d"))),
+            format_value(self.timestamp.map(|t| t.format("%m"))),
+            format_value(self.timestamp.map(|t| t.format("%Y"))),
+            format!("{:03}", self.local_zone_hours),
+            format!("{:02}", self.local_zone_minutes),
+        ];
+        Zda::new(Nmea0183Base::from_parts(&self.talker, "ZDA", parameters))
     }
 }
@@ -0,0 +1,133 @@
+use crate::base::*;
+use crate::inspect::Inspect;
+use crate::navigation::*;
+
+#[derive(Debug)]
+pub struct Aam<B = DefaultFields> {
+    base: B,
+}
+
+#[cfg(feature = "std")]
+impl Aam {
+    pub fn builder() -> AamBuilder {
+        AamBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Aam<B> {
+    pub fn new(base: B) -> Aam<B> {
+        Aam { base }
+    }
+
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
+    pub fn arrival_status(&self) -> Result<ArrivalStatus, NmeaError> {
+        Ok(ArrivalStatus {
+            circle_entered: status(&self.base, 0)?,
+            perpendicular_passed: status(&self.base, 1)?,
+        })
+    }
+
+    pub fn arrival_radius(&self) -> Result<Distance, NmeaError> {
+        distance(&self.base, 2, 3)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn waypoint_id(&self) -> Result<String, NmeaError> {
+        Ok(self.base.field(4)?.to_string())
+    }
+}
+
+impl<B: SentenceFields> Inspect for Aam<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Aam<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Aam", 4)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("arrival_status", &self.arrival_status().ok())?;
+        state.serialize_field("arrival_radius", &self.arrival_radius().ok())?;
+        state.serialize_field("waypoint_id", &self.waypoint_id().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct AamBuilder {
+    talker: String,
+    arrival_status: ArrivalStatus,
+    arrival_radius: Option<Distance>,
+    waypoint_id: String,
+}
+
+#[cfg(feature = "std")]
+impl AamBuilder {
+    pub fn new() -> AamBuilder {
+        AamBuilder {
+            talker: String::from("GP"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn arrival_status(mut self, arrival_status: ArrivalStatus) -> Self {
+        self.arrival_status = arrival_status;
+        self
+    }
+
+    pub fn arrival_radius(mut self, arrival_radius: Distance) -> Self {
+        self.arrival_radius = Some(arrival_radius);
+        self
+    }
+
+    pub fn waypoint_id(mut self, waypoint_id: String) -> Self {
+        self.waypoint_id = waypoint_id;
+        self
+    }
+
+    pub fn build(self) -> Aam {
+        let parameters = vec![
+            format_status(self.arrival_status.circle_entered),
+            format_status(self.arrival_status.perpendicular_passed),
+            format_float(self.arrival_radius.map(|d| d.as_nautical_mile()), 2),
+            String::from("N"),
+            self.waypoint_id,
+        ];
+        Aam::new(Nmea0183Base::from_parts(&self.talker, "AAM", parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_arrival_alarm() {
+        let line = "$GPAAM,A,A,0.10,N,WPTNME*32";
+        let aam = Aam::new(Nmea0183Base::from_string(&line.to_string()).unwrap());
+        let status = aam.arrival_status().unwrap();
+        assert!(status.circle_entered && status.perpendicular_passed);
+        assert!(status.is_arrived());
+        assert!((aam.arrival_radius().unwrap().as_nautical_mile() - 0.1).abs() < 1e-4);
+        assert_eq!(aam.waypoint_id().unwrap(), "WPTNME");
+    }
+}
@@ -0,0 +1,211 @@
+use crate::base::*;
+use crate::inspect::Inspect;
+use crate::navigation::*;
+
+#[derive(Debug)]
+pub struct Apb<B = DefaultFields> {
+    base: B,
+}
+
+#[cfg(feature = "std")]
+impl Apb {
+    pub fn builder() -> ApbBuilder {
+        ApbBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Apb<B> {
+    pub fn new(base: B) -> Apb<B> {
+        Apb { base }
+    }
+
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.base.field_str(0) == "A" && self.base.field_str(1) == "A"
+    }
+
+    pub fn cross_track_error(&self) -> Result<CrossTrackError, NmeaError> {
+        Ok(CrossTrackError {
+            distance: distance(&self.base, 2, 4)?,
+            steer: steer_direction(&self.base, 3)?,
+        })
+    }
+
+    pub fn arrival_status(&self) -> Result<ArrivalStatus, NmeaError> {
+        Ok(ArrivalStatus {
+            circle_entered: status(&self.base, 5)?,
+            perpendicular_passed: status(&self.base, 6)?,
+        })
+    }
+
+    pub fn bearing_origin_to_destination(&self) -> Result<Bearing, NmeaError> {
+        bearing(&self.base, 7)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn destination_id(&self) -> Result<String, NmeaError> {
+        Ok(self.base.field(9)?.to_string())
+    }
+
+    pub fn bearing_to_destination(&self) -> Result<Bearing, NmeaError> {
+        bearing(&self.base, 10)
+    }
+
+    pub fn heading_to_steer(&self) -> Result<Bearing, NmeaError> {
+        bearing(&self.base, 12)
+    }
+
+    pub fn faa_mode(&self) -> Option<char> {
+        self.base.field_str(14).chars().next()
+    }
+}
+
+impl<B: SentenceFields> Inspect for Apb<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Apb<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Apb", 9)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("is_valid", &self.is_valid())?;
+        state.serialize_field("cross_track_error", &self.cross_track_error().ok())?;
+        state.serialize_field("arrival_status", &self.arrival_status().ok())?;
+        state.serialize_field(
+            "bearing_origin_to_destination",
+            &self.bearing_origin_to_destination().ok(),
+        )?;
+        state.serialize_field("destination_id", &self.destination_id().ok())?;
+        state.serialize_field(
+            "bearing_to_destination",
+            &self.bearing_to_destination().ok(),
+        )?;
+        state.serialize_field("heading_to_steer", &self.heading_to_steer().ok())?;
+        state.serialize_field("faa_mode", &self.faa_mode())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct ApbBuilder {
+    talker: String,
+    valid: bool,
+    cross_track_error: Option<CrossTrackError>,
+    arrival_status: ArrivalStatus,
+    bearing_origin_to_destination: Option<Bearing>,
+    destination_id: String,
+    bearing_to_destination: Option<Bearing>,
+    heading_to_steer: Option<Bearing>,
+    faa_mode: Option<char>,
+}
+
+#[cfg(feature = "std")]
+impl ApbBuilder {
+    pub fn new() -> ApbBuilder {
+        ApbBuilder {
+            talker: String::from("GP"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn valid(mut self, valid: bool) -> Self {
+        self.valid = valid;
+        self
+    }
+
+    pub fn cross_track_error(mut self, cross_track_error: CrossTrackError) -> Self {
+        self.cross_track_error = Some(cross_track_error);
+        self
+    }
+
+    pub fn arrival_status(mut self, arrival_status: ArrivalStatus) -> Self {
+        self.arrival_status = arrival_status;
+        self
+    }
+
+    pub fn bearing_origin_to_destination(mut self, bearing: Bearing) -> Self {
+        self.bearing_origin_to_destination = Some(bearing);
+        self
+    }
+
+    pub fn destination_id(mut self, destination_id: String) -> Self {
+        self.destination_id = destination_id;
+        self
+    }
+
+    pub fn bearing_to_destination(mut self, bearing: Bearing) -> Self {
+        self.bearing_to_destination = Some(bearing);
+        self
+    }
+
+    pub fn heading_to_steer(mut self, heading: Bearing) -> Self {
+        self.heading_to_steer = Some(heading);
+        self
+    }
+
+    pub fn faa_mode(mut self, faa_mode: char) -> Self {
+        self.faa_mode = Some(faa_mode);
+        self
+    }
+
+    pub fn build(self) -> Apb {
+        let mut parameters = vec![format_status(self.valid), format_status(self.valid)];
+        parameters.extend(format_cross_track(self.cross_track_error));
+        parameters.push(String::from("N"));
+        parameters.push(format_status(self.arrival_status.circle_entered));
+        parameters.push(format_status(self.arrival_status.perpendicular_passed));
+        parameters.extend(format_bearing(self.bearing_origin_to_destination));
+        parameters.push(self.destination_id);
+        parameters.extend(format_bearing(self.bearing_to_destination));
+        parameters.extend(format_bearing(self.heading_to_steer));
+        if self.faa_mode.is_some() {
+            parameters.push(format_value(self.faa_mode));
+        }
+        Apb::new(Nmea0183Base::from_parts(&self.talker, "APB", parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_autopilot_sentence() {
+        let line = "$GPAPB,A,A,0.10,R,N,V,V,011,M,DEST,011,M,011,M*3C";
+        let apb = Apb::new(Nmea0183Base::from_string(&line.to_string()).unwrap());
+        assert!(apb.is_valid());
+        assert_eq!(
+            apb.cross_track_error().unwrap().steer,
+            SteerDirection::Right
+        );
+        assert!(!apb.arrival_status().unwrap().is_arrived());
+        assert_eq!(
+            apb.bearing_origin_to_destination().unwrap(),
+            Bearing::Magnetic(11.0)
+        );
+        assert_eq!(apb.destination_id().unwrap(), "DEST");
+        assert_eq!(apb.bearing_to_destination().unwrap().reference(), 'M');
+        assert_eq!(apb.heading_to_steer().unwrap().degrees(), 11.0);
+        assert_eq!(apb.faa_mode(), None);
+    }
+}
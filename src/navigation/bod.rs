@@ -0,0 +1,152 @@
+use crate::base::*;
+use crate::inspect::Inspect;
+
+#[derive(Debug)]
+pub struct Bod<B = DefaultFields> {
+    base: B,
+}
+
+#[cfg(feature = "std")]
+impl Bod {
+    pub fn builder() -> BodBuilder {
+        BodBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Bod<B> {
+    pub fn new(base: B) -> Bod<B> {
+        Bod { base }
+    }
+
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
+    pub fn bearing_true(&self) -> F32Error {
+        if self.base.field_str(1) == "T" {
+            self.base.parameter(0)
+        } else {
+            Err(NmeaError::NotPresent)
+        }
+    }
+
+    pub fn bearing_magnetic(&self) -> F32Error {
+        if self.base.field_str(3) == "M" {
+            self.base.parameter(2)
+        } else {
+            Err(NmeaError::NotPresent)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub fn destination_id(&self) -> Result<String, NmeaError> {
+        Ok(self.base.field(4)?.to_string())
+    }
+
+    #[cfg(feature = "std")]
+    pub fn origin_id(&self) -> Result<String, NmeaError> {
+        Ok(self.base.field(5)?.to_string())
+    }
+}
+
+impl<B: SentenceFields> Inspect for Bod<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Bod<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Bod", 5)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("bearing_true", &self.bearing_true().ok().map(Degrees))?;
+        state.serialize_field(
+            "bearing_magnetic",
+            &self.bearing_magnetic().ok().map(Degrees),
+        )?;
+        state.serialize_field("destination_id", &self.destination_id().ok())?;
+        state.serialize_field("origin_id", &self.origin_id().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct BodBuilder {
+    talker: String,
+    bearing_true: Option<f32>,
+    bearing_magnetic: Option<f32>,
+    destination_id: String,
+    origin_id: String,
+}
+
+#[cfg(feature = "std")]
+impl BodBuilder {
+    pub fn new() -> BodBuilder {
+        BodBuilder {
+            talker: String::from("GP"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn bearing_true(mut self, bearing_true: f32) -> Self {
+        self.bearing_true = Some(bearing_true);
+        self
+    }
+
+    pub fn bearing_magnetic(mut self, bearing_magnetic: f32) -> Self {
+        self.bearing_magnetic = Some(bearing_magnetic);
+        self
+    }
+
+    pub fn destination_id(mut self, destination_id: String) -> Self {
+        self.destination_id = destination_id;
+        self
+    }
+
+    pub fn origin_id(mut self, origin_id: String) -> Self {
+        self.origin_id = origin_id;
+        self
+    }
+
+    pub fn build(self) -> Bod {
+        let parameters = vec![
+            format_float(self.bearing_true, 1),
+            String::from("T"),
+            format_float(self.bearing_magnetic, 1),
+            String::from("M"),
+            self.destination_id,
+            self.origin_id,
+        ];
+        Bod::new(Nmea0183Base::from_parts(&self.talker, "BOD", parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_bearing_origin_to_destination() {
+        let line = "$GPBOD,099.3,T,105.6,M,POINTB,*48";
+        let bod = Bod::new(Nmea0183Base::from_string(&line.to_string()).unwrap());
+        assert_eq!(bod.bearing_true().unwrap(), 99.3);
+        assert_eq!(bod.bearing_magnetic().unwrap(), 105.6);
+        assert_eq!(bod.destination_id().unwrap(), "POINTB");
+        assert_eq!(bod.origin_id().unwrap(), "");
+    }
+}
@@ -0,0 +1,152 @@
+use crate::base::*;
+use crate::inspect::Inspect;
+
+#[derive(Debug)]
+pub struct Bww<B = DefaultFields> {
+    base: B,
+}
+
+#[cfg(feature = "std")]
+impl Bww {
+    pub fn builder() -> BwwBuilder {
+        BwwBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Bww<B> {
+    pub fn new(base: B) -> Bww<B> {
+        Bww { base }
+    }
+
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
+    pub fn bearing_true(&self) -> F32Error {
+        if self.base.field_str(1) == "T" {
+            self.base.parameter(0)
+        } else {
+            Err(NmeaError::NotPresent)
+        }
+    }
+
+    pub fn bearing_magnetic(&self) -> F32Error {
+        if self.base.field_str(3) == "M" {
+            self.base.parameter(2)
+        } else {
+            Err(NmeaError::NotPresent)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_waypoint_id(&self) -> Result<String, NmeaError> {
+        Ok(self.base.field(4)?.to_string())
+    }
+
+    #[cfg(feature = "std")]
+    pub fn from_waypoint_id(&self) -> Result<String, NmeaError> {
+        Ok(self.base.field(5)?.to_string())
+    }
+}
+
+impl<B: SentenceFields> Inspect for Bww<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Bww<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Bww", 5)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("bearing_true", &self.bearing_true().ok().map(Degrees))?;
+        state.serialize_field(
+            "bearing_magnetic",
+            &self.bearing_magnetic().ok().map(Degrees),
+        )?;
+        state.serialize_field("to_waypoint_id", &self.to_waypoint_id().ok())?;
+        state.serialize_field("from_waypoint_id", &self.from_waypoint_id().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct BwwBuilder {
+    talker: String,
+    bearing_true: Option<f32>,
+    bearing_magnetic: Option<f32>,
+    to_waypoint_id: String,
+    from_waypoint_id: String,
+}
+
+#[cfg(feature = "std")]
+impl BwwBuilder {
+    pub fn new() -> BwwBuilder {
+        BwwBuilder {
+            talker: String::from("GP"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn bearing_true(mut self, bearing_true: f32) -> Self {
+        self.bearing_true = Some(bearing_true);
+        self
+    }
+
+    pub fn bearing_magnetic(mut self, bearing_magnetic: f32) -> Self {
+        self.bearing_magnetic = Some(bearing_magnetic);
+        self
+    }
+
+    pub fn to_waypoint_id(mut self, to_waypoint_id: String) -> Self {
+        self.to_waypoint_id = to_waypoint_id;
+        self
+    }
+
+    pub fn from_waypoint_id(mut self, from_waypoint_id: String) -> Self {
+        self.from_waypoint_id = from_waypoint_id;
+        self
+    }
+
+    pub fn build(self) -> Bww {
+        let parameters = vec![
+            format_float(self.bearing_true, 1),
+            String::from("T"),
+            format_float(self.bearing_magnetic, 1),
+            String::from("M"),
+            self.to_waypoint_id,
+            self.from_waypoint_id,
+        ];
+        Bww::new(Nmea0183Base::from_parts(&self.talker, "BWW", parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_bearing_waypoint_to_waypoint() {
+        let line = "$GPBWW,099.3,T,105.6,M,POINTB,POINTA*4E";
+        let bww = Bww::new(Nmea0183Base::from_string(&line.to_string()).unwrap());
+        assert_eq!(bww.bearing_true().unwrap(), 99.3);
+        assert_eq!(bww.bearing_magnetic().unwrap(), 105.6);
+        assert_eq!(bww.to_waypoint_id().unwrap(), "POINTB");
+        assert_eq!(bww.from_waypoint_id().unwrap(), "POINTA");
+    }
+}
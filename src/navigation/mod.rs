@@ -0,0 +1,178 @@
+pub mod aam;
+pub mod apb;
+pub mod bod;
+pub mod bww;
+pub mod rmb;
+#[cfg(feature = "std")]
+pub mod route;
+pub mod rte;
+pub mod wpl;
+pub mod xte;
+
+use crate::base::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SteerDirection {
+    Left,
+    Right,
+}
+
+impl SteerDirection {
+    pub fn from_char(c: char) -> Option<SteerDirection> {
+        match c {
+            'L' => Some(SteerDirection::Left),
+            'R' => Some(SteerDirection::Right),
+            _ => None,
+        }
+    }
+
+    pub fn as_char(&self) -> char {
+        match self {
+            SteerDirection::Left => 'L',
+            SteerDirection::Right => 'R',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CrossTrackError {
+    pub distance: Distance,
+    pub steer: SteerDirection,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArrivalStatus {
+    pub circle_entered: bool,
+    pub perpendicular_passed: bool,
+}
+
+impl ArrivalStatus {
+    pub fn is_arrived(&self) -> bool {
+        self.circle_entered || self.perpendicular_passed
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Bearing {
+    True(
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                serialize_with = "serialize_degrees",
+                deserialize_with = "deserialize_degrees"
+            )
+        )]
+        f32,
+    ),
+    Magnetic(
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                serialize_with = "serialize_degrees",
+                deserialize_with = "deserialize_degrees"
+            )
+        )]
+        f32,
+    ),
+}
+
+impl Bearing {
+    pub fn degrees(&self) -> f32 {
+        match self {
+            Bearing::True(degrees) | Bearing::Magnetic(degrees) => *degrees,
+        }
+    }
+
+    pub fn reference(&self) -> char {
+        match self {
+            Bearing::True(_) => 'T',
+            Bearing::Magnetic(_) => 'M',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RouteType {
+    #[default]
+    Complete,
+    Working,
+}
+
+impl RouteType {
+    pub fn from_char(c: char) -> Option<RouteType> {
+        match c {
+            'c' | 'C' => Some(RouteType::Complete),
+            'w' | 'W' => Some(RouteType::Working),
+            _ => None,
+        }
+    }
+
+    pub fn as_char(&self) -> char {
+        match self {
+            RouteType::Complete => 'c',
+            RouteType::Working => 'w',
+        }
+    }
+}
+
+pub(crate) fn status<B: SentenceFields>(base: &B, n: usize) -> Result<bool, NmeaError> {
+    match base.field_char(n)? {
+        'A' => Ok(true),
+        'V' => Ok(false),
+        _ => Err(base.invalid_field(n)),
+    }
+}
+
+pub(crate) fn steer_direction<B: SentenceFields>(
+    base: &B,
+    n: usize,
+) -> Result<SteerDirection, NmeaError> {
+    SteerDirection::from_char(base.field_char(n)?).ok_or_else(|| base.invalid_field(n))
+}
+
+pub(crate) fn distance<B: SentenceFields>(
+    base: &B,
+    n: usize,
+    units: usize,
+) -> Result<Distance, NmeaError> {
+    match base.field_str(units) {
+        "N" => Ok(Distance::from_nautical_miles(base.parameter(n)?)),
+        "K" => Ok(Distance::from_kilometers(base.parameter(n)?)),
+        _ => Err(base.unknown_units(units)),
+    }
+}
+
+pub(crate) fn bearing<B: SentenceFields>(base: &B, n: usize) -> Result<Bearing, NmeaError> {
+    let degrees = base.parameter(n)?;
+    match base.field_str(n + 1) {
+        "T" => Ok(Bearing::True(degrees)),
+        "M" => Ok(Bearing::Magnetic(degrees)),
+        _ => Err(base.unknown_units(n + 1)),
+    }
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn format_status(status: bool) -> String {
+    String::from(if status { "A" } else { "V" })
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn format_bearing(bearing: Option<Bearing>) -> [String; 2] {
+    [
+        format_float(bearing.map(|b| b.degrees()), 1),
+        format_value(bearing.map(|b| b.reference())),
+    ]
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn format_cross_track(cross_track: Option<CrossTrackError>) -> [String; 2] {
+    [
+        format_float(cross_track.map(|x| x.distance.as_nautical_mile()), 2),
+        format_value(cross_track.map(|x| x.steer.as_char())),
+    ]
+}
@@ -0,0 +1,245 @@
+use crate::base::*;
+use crate::inspect::Inspect;
+use crate::navigation::*;
+#[cfg(feature = "std")]
+use liblatlon::Position;
+
+#[derive(Debug)]
+pub struct Rmb<B = DefaultFields> {
+    base: B,
+}
+
+#[cfg(feature = "std")]
+impl Rmb {
+    pub fn builder() -> RmbBuilder {
+        RmbBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Rmb<B> {
+    pub fn new(base: B) -> Rmb<B> {
+        Rmb { base }
+    }
+
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.base.field_str(0) == "A"
+    }
+
+    pub fn cross_track_error(&self) -> Result<CrossTrackError, NmeaError> {
+        Ok(CrossTrackError {
+            distance: Distance::from_nautical_miles(self.base.parameter(1)?),
+            steer: steer_direction(&self.base, 2)?,
+        })
+    }
+
+    #[cfg(feature = "std")]
+    pub fn origin_id(&self) -> Result<String, NmeaError> {
+        Ok(self.base.field(3)?.to_string())
+    }
+
+    #[cfg(feature = "std")]
+    pub fn destination_id(&self) -> Result<String, NmeaError> {
+        Ok(self.base.field(4)?.to_string())
+    }
+
+    #[cfg(feature = "std")]
+    pub fn destination_position(&self) -> PositionError {
+        self.base.position(5)
+    }
+
+    pub fn destination_coordinates(&self) -> Result<(f64, f64), NmeaError> {
+        self.base.coordinates(5)
+    }
+
+    pub fn range_to_destination(&self) -> Result<Distance, NmeaError> {
+        Ok(Distance::from_nautical_miles(self.base.parameter(9)?))
+    }
+
+    pub fn bearing_to_destination(&self) -> F32Error {
+        self.base.parameter(10)
+    }
+
+    pub fn closing_velocity(&self) -> Result<Speed, NmeaError> {
+        Ok(Speed::from_knots(self.base.parameter(11)?))
+    }
+
+    pub fn arrived(&self) -> Result<bool, NmeaError> {
+        status(&self.base, 12)
+    }
+
+    pub fn faa_mode(&self) -> Option<char> {
+        self.base.field_str(13).chars().next()
+    }
+}
+
+impl<B: SentenceFields> Inspect for Rmb<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Rmb<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Rmb", 11)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("is_valid", &self.is_valid())?;
+        state.serialize_field("cross_track_error", &self.cross_track_error().ok())?;
+        state.serialize_field("origin_id", &self.origin_id().ok())?;
+        state.serialize_field("destination_id", &self.destination_id().ok())?;
+        state.serialize_field(
+            "destination_position",
+            &self
+                .destination_position()
+                .ok()
+                .as_ref()
+                .map(SerializedPosition::from),
+        )?;
+        state.serialize_field("range_to_destination", &self.range_to_destination().ok())?;
+        state.serialize_field(
+            "bearing_to_destination",
+            &self.bearing_to_destination().ok().map(Degrees),
+        )?;
+        state.serialize_field("closing_velocity", &self.closing_velocity().ok())?;
+        state.serialize_field("arrived", &self.arrived().ok())?;
+        state.serialize_field("faa_mode", &self.faa_mode())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct RmbBuilder {
+    talker: String,
+    valid: bool,
+    cross_track_error: Option<CrossTrackError>,
+    origin_id: String,
+    destination_id: String,
+    destination_position: Option<Position>,
+    range_to_destination: Option<Distance>,
+    bearing_to_destination: Option<f32>,
+    closing_velocity: Option<Speed>,
+    arrived: bool,
+    faa_mode: Option<char>,
+}
+
+#[cfg(feature = "std")]
+impl RmbBuilder {
+    pub fn new() -> RmbBuilder {
+        RmbBuilder {
+            talker: String::from("GP"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn valid(mut self, valid: bool) -> Self {
+        self.valid = valid;
+        self
+    }
+
+    pub fn cross_track_error(mut self, cross_track_error: CrossTrackError) -> Self {
+        self.cross_track_error = Some(cross_track_error);
+        self
+    }
+
+    pub fn origin_id(mut self, origin_id: String) -> Self {
+        self.origin_id = origin_id;
+        self
+    }
+
+    pub fn destination_id(mut self, destination_id: String) -> Self {
+        self.destination_id = destination_id;
+        self
+    }
+
+    pub fn destination_position(mut self, destination_position: Position) -> Self {
+        self.destination_position = Some(destination_position);
+        self
+    }
+
+    pub fn range_to_destination(mut self, range: Distance) -> Self {
+        self.range_to_destination = Some(range);
+        self
+    }
+
+    pub fn bearing_to_destination(mut self, bearing: f32) -> Self {
+        self.bearing_to_destination = Some(bearing);
+        self
+    }
+
+    pub fn closing_velocity(mut self, closing_velocity: Speed) -> Self {
+        self.closing_velocity = Some(closing_velocity);
+        self
+    }
+
+    pub fn arrived(mut self, arrived: bool) -> Self {
+        self.arrived = arrived;
+        self
+    }
+
+    pub fn faa_mode(mut self, faa_mode: char) -> Self {
+        self.faa_mode = Some(faa_mode);
+        self
+    }
+
+    pub fn build(self) -> Rmb {
+        let mut parameters = vec![format_status(self.valid)];
+        parameters.extend(format_cross_track(self.cross_track_error));
+        parameters.push(self.origin_id);
+        parameters.push(self.destination_id);
+        parameters.extend(format_position(self.destination_position.as_ref()));
+        parameters.push(format_float(
+            self.range_to_destination.map(|d| d.as_nautical_mile()),
+            1,
+        ));
+        parameters.push(format_float(self.bearing_to_destination, 1));
+        parameters.push(format_float(self.closing_velocity.map(|s| s.as_knots()), 1));
+        parameters.push(format_status(self.arrived));
+        if self.faa_mode.is_some() {
+            parameters.push(format_value(self.faa_mode));
+        }
+        Rmb::new(Nmea0183Base::from_parts(&self.talker, "RMB", parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_recommended_minimum_navigation() {
+        let line = "$GPRMB,A,0.66,L,003,004,4917.24,N,12309.57,W,001.3,052.5,000.5,V*20";
+        let rmb = Rmb::new(Nmea0183Base::from_string(&line.to_string()).unwrap());
+        assert!(rmb.is_valid());
+        let xte = rmb.cross_track_error().unwrap();
+        assert!((xte.distance.as_nautical_mile() - 0.66).abs() < 1e-4);
+        assert_eq!(xte.steer, SteerDirection::Left);
+        assert_eq!(rmb.origin_id().unwrap(), "003");
+        assert_eq!(rmb.destination_id().unwrap(), "004");
+        let position = rmb.destination_position().unwrap();
+        assert!((position.latitude() - 49.287333).abs() < 1e-5);
+        assert!((position.longitude() + 123.1595).abs() < 1e-5);
+        assert!((rmb.range_to_destination().unwrap().as_nautical_mile() - 1.3).abs() < 1e-4);
+        assert_eq!(rmb.bearing_to_destination().unwrap(), 52.5);
+        assert!((rmb.closing_velocity().unwrap().as_knots() - 0.5).abs() < 1e-4);
+        assert!(!rmb.arrived().unwrap());
+        assert_eq!(rmb.faa_mode(), None);
+    }
+}
@@ -0,0 +1,237 @@
+use crate::base::*;
+use crate::navigation::{rte::Rte, wpl::Wpl, RouteType};
+use crate::Nmea0183;
+use liblatlon::Position;
+use std::collections::HashMap;
+
+pub const MAX_ROUTE_SENTENCES: usize = 99;
+
+#[derive(Debug)]
+pub struct Route {
+    pub name: String,
+    pub route_type: RouteType,
+    pub waypoints: Vec<(String, Position)>,
+}
+
+#[derive(Debug)]
+struct PartialRoute {
+    route_type: RouteType,
+    parts: Vec<Option<Vec<String>>>,
+}
+
+impl PartialRoute {
+    fn is_complete(&self) -> bool {
+        self.parts.iter().all(|p| p.is_some())
+    }
+}
+
+#[derive(Debug)]
+struct PendingRoute {
+    route_type: RouteType,
+    waypoints: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct RouteAssembler {
+    waypoints: HashMap<String, (f64, f64)>,
+    partial: HashMap<String, PartialRoute>,
+    unresolved: HashMap<String, PendingRoute>,
+}
+
+impl RouteAssembler {
+    pub fn new() -> RouteAssembler {
+        RouteAssembler::default()
+    }
+
+    pub fn push(&mut self, sentence: &Nmea0183) -> Result<Vec<Route>, NmeaError> {
+        match sentence {
+            Nmea0183::RTE(rte) => self.push_rte(rte),
+            Nmea0183::WPL(wpl) => self.push_wpl(wpl),
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    pub fn push_rte<B: SentenceFields>(&mut self, rte: &Rte<B>) -> Result<Vec<Route>, NmeaError> {
+        let total = rte.sentence_total()?;
+        let number = rte.sentence_number()?;
+        if total == 0 || total > MAX_ROUTE_SENTENCES {
+            return Err(rte.base().invalid_field(0));
+        }
+        if number == 0 || number > total {
+            return Err(rte.base().invalid_field(1));
+        }
+        let route_type = rte.route_type()?;
+        let name = rte.route_id()?;
+        let waypoints = rte.waypoints()?;
+
+        let restart = match self.partial.get(&name) {
+            Some(partial) => {
+                partial.parts.len() != total
+                    || partial.route_type != route_type
+                    || partial.parts[number - 1].is_some()
+            }
+            None => false,
+        };
+        if restart {
+            self.partial.remove(&name);
+        }
+        let partial = self
+            .partial
+            .entry(name.clone())
+            .or_insert_with(|| PartialRoute {
+                route_type,
+                parts: vec![None; total],
+            });
+        partial.parts[number - 1] = Some(waypoints);
+        if !partial.is_complete() {
+            return Ok(Vec::new());
+        }
+
+        match self.partial.remove(&name) {
+            Some(partial) => {
+                let pending = PendingRoute {
+                    route_type: partial.route_type,
+                    waypoints: partial.parts.into_iter().flatten().flatten().collect(),
+                };
+                self.unresolved.insert(name.clone(), pending);
+                Ok(self.resolve(&name).into_iter().collect())
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+
+    pub fn push_wpl<B: SentenceFields>(&mut self, wpl: &Wpl<B>) -> Result<Vec<Route>, NmeaError> {
+        let id = wpl.waypoint_id()?;
+        let coordinates = wpl.coordinates()?;
+        self.waypoints.insert(id.clone(), coordinates);
+        let mut waiting = self
+            .unresolved
+            .iter()
+            .filter(|(_, route)| {
+                route.waypoints.contains(&id)
+                    && route
+                        .waypoints
+                        .iter()
+                        .all(|w| self.waypoints.contains_key(w))
+            })
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        waiting.sort_unstable();
+        Ok(waiting
+            .into_iter()
+            .filter_map(|name| self.resolve(&name))
+            .collect())
+    }
+
+    pub fn waypoint(&self, id: &str) -> Option<Position> {
+        self.waypoints
+            .get(id)
+            .map(|(latitude, longitude)| position_from_degrees(*latitude, *longitude))
+    }
+
+    pub fn missing_waypoints(&self) -> Vec<&str> {
+        let mut missing = self
+            .unresolved
+            .values()
+            .flat_map(|route| route.waypoints.iter())
+            .filter(|id| !self.waypoints.contains_key(*id))
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        missing.sort_unstable();
+        missing.dedup();
+        missing
+    }
+
+    pub fn pending(&self) -> usize {
+        self.partial.len() + self.unresolved.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.waypoints.clear();
+        self.partial.clear();
+        self.unresolved.clear();
+    }
+
+    fn resolve(&mut self, name: &str) -> Option<Route> {
+        let pending = self.unresolved.get(name)?;
+        let mut waypoints = Vec::with_capacity(pending.waypoints.len());
+        for id in &pending.waypoints {
+            waypoints.push((id.clone(), self.waypoint(id)?));
+        }
+        let route_type = pending.route_type;
+        self.unresolved.remove(name);
+        Some(Route {
+            name: String::from(name),
+            route_type,
+            waypoints,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn push(assembler: &mut RouteAssembler, line: &str) -> Vec<Route> {
+        let sentence = Nmea0183::from_string(&line.to_string()).unwrap();
+        assembler.push(&sentence).unwrap()
+    }
+
+    #[test]
+    fn resolves_route_after_waypoints() {
+        let mut assembler = RouteAssembler::new();
+        assert!(push(&mut assembler, "$GPWPL,4917.16,N,12310.64,W,WP1*60").is_empty());
+        assert!(push(&mut assembler, "$GPWPL,4916.45,N,12311.12,W,WP2*64").is_empty());
+        let routes = push(&mut assembler, "$GPRTE,1,1,c,A,WP1,WP2*75");
+        assert_eq!(routes.len(), 1);
+        assert_eq!(routes[0].name, "A");
+        assert_eq!(routes[0].route_type, RouteType::Complete);
+        assert_eq!(routes[0].waypoints[0].0, "WP1");
+        assert!((routes[0].waypoints[0].1.latitude() - 49.286).abs() < 1e-6);
+        assert_eq!(assembler.pending(), 0);
+    }
+
+    #[test]
+    fn resolves_multi_sentence_route() {
+        let mut assembler = RouteAssembler::new();
+        assert!(push(&mut assembler, "$GPRTE,2,2,c,C,WP3*6C").is_empty());
+        assert!(push(&mut assembler, "$GPRTE,2,1,c,C,WP1*6D").is_empty());
+        assert_eq!(assembler.missing_waypoints(), ["WP1", "WP3"]);
+        assert!(push(&mut assembler, "$GPWPL,4917.16,N,12310.64,W,WP1*60").is_empty());
+        let routes = push(&mut assembler, "$GPWPL,4915.00,N,12312.00,W,WP3*67");
+        assert_eq!(routes.len(), 1);
+        let ids = routes[0]
+            .waypoints
+            .iter()
+            .map(|(id, _)| id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, ["WP1", "WP3"]);
+    }
+
+    #[test]
+    fn waypoint_resolves_every_waiting_route() {
+        let mut assembler = RouteAssembler::new();
+        assert!(push(&mut assembler, "$GPWPL,4917.16,N,12310.64,W,WP1*60").is_empty());
+        assert!(push(&mut assembler, "$GPWPL,4915.00,N,12312.00,W,WP3*67").is_empty());
+        assert!(push(&mut assembler, "$GPRTE,1,1,c,A,WP1,WP2*75").is_empty());
+        assert!(push(&mut assembler, "$GPRTE,1,1,c,B,WP2,WP3*74").is_empty());
+        assert_eq!(push(&mut assembler, "$GPRTE,1,1,w,D,WP1,WP3*65").len(), 1);
+        assert_eq!(assembler.pending(), 2);
+        assert_eq!(assembler.missing_waypoints(), ["WP2"]);
+        let routes = push(&mut assembler, "$GPWPL,4916.45,N,12311.12,W,WP2*64");
+        let names = routes.iter().map(|r| r.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["A", "B"]);
+        assert_eq!(assembler.pending(), 0);
+    }
+
+    #[test]
+    fn rejects_invalid_sentence_numbers() {
+        let mut assembler = RouteAssembler::new();
+        let line = "$GPRTE,1,2,c,A,WP1";
+        let rte = Rte::new(Nmea0183Base::from_string_with(line, ChecksumPolicy::Lenient).unwrap());
+        assert!(matches!(
+            assembler.push_rte(&rte),
+            Err(NmeaError::InvalidField { index: 1, .. })
+        ));
+    }
+}
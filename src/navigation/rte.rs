@@ -0,0 +1,208 @@
+use crate::base::*;
+use crate::inspect::Inspect;
+use crate::navigation::*;
+
+#[derive(Debug)]
+pub struct Rte<B = DefaultFields> {
+    base: B,
+}
+
+#[cfg(feature = "std")]
+impl Rte {
+    pub fn builder() -> RteBuilder {
+        RteBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Rte<B> {
+    pub fn new(base: B) -> Rte<B> {
+        Rte { base }
+    }
+
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
+    pub fn sentence_total(&self) -> UsizeError {
+        self.base.parameter(0)
+    }
+
+    pub fn sentence_number(&self) -> UsizeError {
+        self.base.parameter(1)
+    }
+
+    pub fn route_type(&self) -> Result<RouteType, NmeaError> {
+        RouteType::from_char(self.base.field_char(2)?).ok_or_else(|| self.base.invalid_field(2))
+    }
+
+    #[cfg(feature = "std")]
+    pub fn route_id(&self) -> Result<String, NmeaError> {
+        Ok(self.base.field(3)?.to_string())
+    }
+
+    pub fn waypoint_count(&self) -> usize {
+        self.base.field_count().saturating_sub(4)
+    }
+
+    pub fn waypoint(&self, n: usize) -> Result<&str, NmeaError> {
+        if n >= self.waypoint_count() {
+            return Err(NmeaError::IndexOutOfRange(n));
+        }
+        self.base.required_field(n + 4)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn waypoints(&self) -> Result<Vec<String>, NmeaError> {
+        (0..self.waypoint_count())
+            .map(|n| self.waypoint(n).map(String::from))
+            .collect()
+    }
+}
+
+impl<B: SentenceFields> Inspect for Rte<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Rte<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Rte", 6)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("sentence_total", &self.sentence_total().ok())?;
+        state.serialize_field("sentence_number", &self.sentence_number().ok())?;
+        state.serialize_field("route_type", &self.route_type().ok())?;
+        state.serialize_field("route_id", &self.route_id().ok())?;
+        state.serialize_field("waypoints", &self.waypoints().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct RteBuilder {
+    talker: String,
+    sentence_total: Option<usize>,
+    sentence_number: Option<usize>,
+    route_type: RouteType,
+    route_id: String,
+    waypoints: Vec<String>,
+}
+
+#[cfg(feature = "std")]
+impl RteBuilder {
+    pub fn new() -> RteBuilder {
+        RteBuilder {
+            talker: String::from("GP"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn sentence_total(mut self, sentence_total: usize) -> Self {
+        self.sentence_total = Some(sentence_total);
+        self
+    }
+
+    pub fn sentence_number(mut self, sentence_number: usize) -> Self {
+        self.sentence_number = Some(sentence_number);
+        self
+    }
+
+    pub fn route_type(mut self, route_type: RouteType) -> Self {
+        self.route_type = route_type;
+        self
+    }
+
+    pub fn route_id(mut self, route_id: String) -> Self {
+        self.route_id = route_id;
+        self
+    }
+
+    pub fn waypoints(mut self, waypoints: Vec<String>) -> Self {
+        self.waypoints = waypoints;
+        self
+    }
+
+    pub fn build(self) -> Rte {
+        let mut parameters = vec![
+            format_value(self.sentence_total.or(Some(1))),
+            format_value(self.sentence_number.or(Some(1))),
+            String::from(self.route_type.as_char()),
+            self.route_id,
+        ];
+        parameters.extend(self.waypoints);
+        Rte::new(Nmea0183Base::from_parts(&self.talker, "RTE", parameters))
+    }
+
+    pub fn build_all(self) -> Vec<Rte> {
+        let overhead = "$RTE,99,99,c,*hh\r\n".len() + self.talker.len() + self.route_id.len();
+        let available = MAX_SENTENCE_LENGTH.saturating_sub(overhead);
+        let mut parts: Vec<Vec<String>> = Vec::new();
+        let mut length = available;
+        for waypoint in self.waypoints {
+            if length + waypoint.len() + 1 > available {
+                parts.push(Vec::new());
+                length = 0;
+            }
+            length += waypoint.len() + 1;
+            if let Some(part) = parts.last_mut() {
+                part.push(waypoint);
+            }
+        }
+        if parts.is_empty() {
+            parts.push(Vec::new());
+        }
+        let total = parts.len();
+        parts
+            .into_iter()
+            .enumerate()
+            .map(|(n, waypoints)| {
+                RteBuilder::new()
+                    .talker(&self.talker)
+                    .sentence_total(total)
+                    .sentence_number(n + 1)
+                    .route_type(self.route_type)
+                    .route_id(self.route_id.clone())
+                    .waypoints(waypoints)
+                    .build()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_route_waypoints() {
+        let line =
+            "$GPRTE,2,1,c,0,PBRCPK,PBRTO,PTELGR,PPLAND,PYAMBU,PPFAIR,PWARRN,PMORTL,PLISMR*73";
+        let rte = Rte::new(Nmea0183Base::from_string(&line.to_string()).unwrap());
+        assert_eq!(rte.sentence_total().unwrap(), 2);
+        assert_eq!(rte.sentence_number().unwrap(), 1);
+        assert_eq!(rte.route_type().unwrap(), RouteType::Complete);
+        assert_eq!(rte.route_id().unwrap(), "0");
+        assert_eq!(rte.waypoint_count(), 9);
+        assert_eq!(rte.waypoint(8).unwrap(), "PLISMR");
+        assert!(matches!(
+            rte.waypoint(9),
+            Err(NmeaError::IndexOutOfRange(9))
+        ));
+        assert_eq!(rte.waypoints().unwrap()[..2], ["PBRCPK", "PBRTO"]);
+    }
+}
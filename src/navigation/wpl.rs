@@ -0,0 +1,124 @@
+use crate::base::*;
+use crate::inspect::Inspect;
+#[cfg(feature = "std")]
+use liblatlon::Position;
+
+#[derive(Debug)]
+pub struct Wpl<B = DefaultFields> {
+    base: B,
+}
+
+#[cfg(feature = "std")]
+impl Wpl {
+    pub fn builder() -> WplBuilder {
+        WplBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Wpl<B> {
+    pub fn new(base: B) -> Wpl<B> {
+        Wpl { base }
+    }
+
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
+    #[cfg(feature = "std")]
+    pub fn position(&self) -> PositionError {
+        self.base.position(0)
+    }
+
+    pub fn coordinates(&self) -> Result<(f64, f64), NmeaError> {
+        self.base.coordinates(0)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn waypoint_id(&self) -> Result<String, NmeaError> {
+        Ok(self.base.required_field(4)?.to_string())
+    }
+}
+
+impl<B: SentenceFields> Inspect for Wpl<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Wpl<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Wpl", 3)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field(
+            "position",
+            &self.position().ok().as_ref().map(SerializedPosition::from),
+        )?;
+        state.serialize_field("waypoint_id", &self.waypoint_id().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct WplBuilder {
+    talker: String,
+    position: Option<Position>,
+    waypoint_id: String,
+}
+
+#[cfg(feature = "std")]
+impl WplBuilder {
+    pub fn new() -> WplBuilder {
+        WplBuilder {
+            talker: String::from("GP"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub fn waypoint_id(mut self, waypoint_id: String) -> Self {
+        self.waypoint_id = waypoint_id;
+        self
+    }
+
+    pub fn build(self) -> Wpl {
+        let mut parameters = Vec::from(format_position(self.position.as_ref()));
+        parameters.push(self.waypoint_id);
+        Wpl::new(Nmea0183Base::from_parts(&self.talker, "WPL", parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_waypoint_location() {
+        let line = "$GPWPL,4917.16,N,12310.64,W,003*65";
+        let wpl = Wpl::new(Nmea0183Base::from_string(&line.to_string()).unwrap());
+        let (latitude, longitude) = wpl.coordinates().unwrap();
+        assert!((latitude - 49.286).abs() < 1e-9);
+        assert!((longitude + 123.177333).abs() < 1e-6);
+        let position = wpl.position().unwrap();
+        assert!((position.longitude() - longitude).abs() < 1e-9);
+        assert_eq!(wpl.waypoint_id().unwrap(), "003");
+    }
+}
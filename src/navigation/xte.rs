@@ -0,0 +1,131 @@
+use crate::base::*;
+use crate::inspect::Inspect;
+use crate::navigation::*;
+
+#[derive(Debug)]
+pub struct Xte<B = DefaultFields> {
+    base: B,
+}
+
+#[cfg(feature = "std")]
+impl Xte {
+    pub fn builder() -> XteBuilder {
+        XteBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Xte<B> {
+    pub fn new(base: B) -> Xte<B> {
+        Xte { base }
+    }
+
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.base.field_str(0) == "A" && self.base.field_str(1) == "A"
+    }
+
+    pub fn cross_track_error(&self) -> Result<CrossTrackError, NmeaError> {
+        Ok(CrossTrackError {
+            distance: distance(&self.base, 2, 4)?,
+            steer: steer_direction(&self.base, 3)?,
+        })
+    }
+
+    pub fn faa_mode(&self) -> Option<char> {
+        self.base.field_str(5).chars().next()
+    }
+}
+
+impl<B: SentenceFields> Inspect for Xte<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Xte<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Xte", 4)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("is_valid", &self.is_valid())?;
+        state.serialize_field("cross_track_error", &self.cross_track_error().ok())?;
+        state.serialize_field("faa_mode", &self.faa_mode())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct XteBuilder {
+    talker: String,
+    valid: bool,
+    cross_track_error: Option<CrossTrackError>,
+    faa_mode: Option<char>,
+}
+
+#[cfg(feature = "std")]
+impl XteBuilder {
+    pub fn new() -> XteBuilder {
+        XteBuilder {
+            talker: String::from("GP"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn valid(mut self, valid: bool) -> Self {
+        self.valid = valid;
+        self
+    }
+
+    pub fn cross_track_error(mut self, cross_track_error: CrossTrackError) -> Self {
+        self.cross_track_error = Some(cross_track_error);
+        self
+    }
+
+    pub fn faa_mode(mut self, faa_mode: char) -> Self {
+        self.faa_mode = Some(faa_mode);
+        self
+    }
+
+    pub fn build(self) -> Xte {
+        let mut parameters = vec![format_status(self.valid), format_status(self.valid)];
+        parameters.extend(format_cross_track(self.cross_track_error));
+        parameters.push(String::from("N"));
+        if self.faa_mode.is_some() {
+            parameters.push(format_value(self.faa_mode));
+        }
+        Xte::new(Nmea0183Base::from_parts(&self.talker, "XTE", parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_cross_track_error() {
+        let line = "$GPXTE,A,A,0.67,L,N*6F";
+        let xte = Xte::new(Nmea0183Base::from_string(&line.to_string()).unwrap());
+        assert!(xte.is_valid());
+        let error = xte.cross_track_error().unwrap();
+        assert!((error.distance.as_nautical_mile() - 0.67).abs() < 1e-4);
+        assert_eq!(error.steer, SteerDirection::Left);
+        assert_eq!(xte.faa_mode(), None);
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{base::*, classify, Nmea0183};
+use std::{any::Any, collections::HashMap, fmt::Debug};
+
+pub trait CustomSentence: Debug + Send + Sync {
+    fn base(&self) -> &Nmea0183Base;
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl dyn CustomSentence {
+    pub fn downcast_ref<T: CustomSentence + 'static>(&self) -> Option<&T> {
+        self.as_any().downcast_ref::<T>()
+    }
+}
+
+pub trait SentenceDecoder: Send + Sync {
+    fn decode(&self, base: Nmea0183Base) -> Result<Box<dyn CustomSentence>, NmeaError>;
+}
+
+impl<F> SentenceDecoder for F
+where
+    F: Fn(Nmea0183Base) -> Result<Box<dyn CustomSentence>, NmeaError> + Send + Sync,
+{
+    fn decode(&self, base: Nmea0183Base) -> Result<Box<dyn CustomSentence>, NmeaError> {
+        self(base)
+    }
+}
+
+#[derive(Default)]
+pub struct DecoderRegistry {
+    decoders: HashMap<String, Box<dyn SentenceDecoder>>,
+}
+
+impl DecoderRegistry {
+    pub fn new() -> DecoderRegistry {
+        DecoderRegistry::default()
+    }
+
+    pub fn register<D: SentenceDecoder + 'static>(&mut self, address: &str, decoder: D) {
+        self.decoders
+            .insert(address.to_uppercase(), Box::new(decoder));
+    }
+
+    pub fn unregister(&mut self, address: &str) -> bool {
+        self.decoders.remove(&address.to_uppercase()).is_some()
+    }
+
+    pub fn decoder(&self, base: &Nmea0183Base) -> Option<&dyn SentenceDecoder> {
+        let message = base.message.to_uppercase();
+        let found = if base.is_proprietary() {
+            self.decoders.get(&format!("P{message}")).or_else(|| {
+                base.manufacturer()
+                    .and_then(|m| self.decoders.get(&format!("P{}", m.to_uppercase())))
+            })
+        } else {
+            self.decoders.get(&message)
+        };
+        found.map(|d| d.as_ref())
+    }
+
+    pub fn classify(&self, b: Nmea0183Base) -> Result<Nmea0183, NmeaError> {
+        match self.decoder(&b) {
+            Some(decoder) => Ok(Nmea0183::Custom(decoder.decode(b)?)),
+            None => Ok(classify(b)),
+        }
+    }
+
+    pub fn from_string(&self, line: &String) -> Result<Nmea0183, NmeaError> {
+        self.classify(Nmea0183Base::from_string(line)?)
+    }
+
+    pub fn from_string_with(
+        &self,
+        line: &str,
+        policy: ChecksumPolicy,
+    ) -> Result<Nmea0183, NmeaError> {
+        self.classify(Nmea0183Base::from_string_with(line, policy)?)
+    }
+}
+
+impl Debug for DecoderRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DecoderRegistry")
+            .field("addresses", &self.decoders.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{classify_with, stream::StreamDecoder};
+    use std::sync::Arc;
+
+    const PGRME: &str = "$PGRME,15.0,M,45.0,M,25.0,M*1C";
+    const PGRMZ: &str = "$PGRMZ,93,f,3*21";
+
+    #[derive(Debug)]
+    struct Pgrme {
+        base: Nmea0183Base,
+    }
+
+    impl Pgrme {
+        fn horizontal_error(&self) -> F32Error {
+            self.base.parameter(0)
+        }
+    }
+
+    impl CustomSentence for Pgrme {
+        fn base(&self) -> &Nmea0183Base {
+            &self.base
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    fn decode_pgrme(base: Nmea0183Base) -> Result<Box<dyn CustomSentence>, NmeaError> {
+        Ok(Box::new(Pgrme { base }))
+    }
+
+    fn registry(address: &str) -> DecoderRegistry {
+        let mut registry = DecoderRegistry::new();
+        registry.register(address, decode_pgrme);
+        registry
+    }
+
+    #[test]
+    fn registered_sentences_decode_to_custom() {
+        let registry = registry("pgrme");
+        match registry.from_string_with(PGRME, ChecksumPolicy::Strict) {
+            Ok(Nmea0183::Custom(custom)) => {
+                let pgrme = custom.downcast_ref::<Pgrme>().unwrap();
+                assert_eq!(pgrme.horizontal_error().unwrap(), 15.0);
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+        assert!(matches!(
+            registry.from_string(&String::from(PGRMZ)),
+            Ok(Nmea0183::Proprietary(_))
+        ));
+    }
+
+    #[test]
+    fn manufacturer_address_matches_every_message() {
+        let registry = registry("PGRM");
+        for line in [PGRME, PGRMZ] {
+            assert!(matches!(
+                registry.from_string(&String::from(line)),
+                Ok(Nmea0183::Custom(_))
+            ));
+        }
+        let mut registry = registry;
+        assert!(registry.unregister("pgrm"));
+        assert!(!registry.unregister("PGRM"));
+        assert!(matches!(
+            registry.from_string(&String::from(PGRME)),
+            Ok(Nmea0183::Proprietary(_))
+        ));
+    }
+
+    #[test]
+    fn standard_sentences_can_be_overridden() {
+        let registry = registry("HDT");
+        let base = Nmea0183Base::from_string(&String::from("$HEHDT,274.07,T*19")).unwrap();
+        assert!(matches!(
+            classify_with(&registry, base),
+            Ok(Nmea0183::Custom(_))
+        ));
+        let base = Nmea0183Base::from_string(&String::from(PGRME)).unwrap();
+        assert!(matches!(classify(base), Nmea0183::Proprietary(_)));
+    }
+
+    #[test]
+    fn decoder_errors_are_returned() {
+        let mut registry = DecoderRegistry::new();
+        registry.register("PGRME", |_| -> Result<Box<dyn CustomSentence>, NmeaError> {
+            Err(NmeaError::DataInvalid)
+        });
+        assert!(matches!(
+            registry.from_string(&String::from(PGRME)),
+            Err(NmeaError::DataInvalid)
+        ));
+    }
+
+    #[test]
+    fn stream_decoder_consults_registry() {
+        let mut decoder = StreamDecoder::with_registry(Arc::new(registry("PGRME")));
+        let input = format!("{PGRME}\r\n{PGRMZ}\r\n");
+        let results = decoder.feed(input.as_bytes()).collect::<Vec<_>>();
+        assert!(matches!(
+            results[..],
+            [Ok(Nmea0183::Custom(_)), Ok(Nmea0183::Proprietary(_))]
+        ));
+
+        let mut decoder = StreamDecoder::new();
+        decoder.set_registry(Arc::new(registry("PGRMZ")));
+        let results = decoder.feed(input.as_bytes()).collect::<Vec<_>>();
+        assert!(matches!(
+            results[..],
+            [Ok(Nmea0183::Proprietary(_)), Ok(Nmea0183::Custom(_))]
+        ));
+    }
+}
@@ -0,0 +1,938 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SentenceType {
+    AAM,
+    APB,
+    BOD,
+    BWC,
+    BWR,
+    BWW,
+    DBS,
+    DBT,
+    DPT,
+    DTM,
+    GBS,
+    GGA,
+    GLL,
+    GNS,
+    GRS,
+    GSA,
+    GST,
+    GSV,
+    GXA,
+    HDG,
+    HDM,
+    HDT,
+    MDA,
+    MTW,
+    MWD,
+    MWV,
+    RMB,
+    RMC,
+    ROT,
+    RSA,
+    RTE,
+    TRF,
+    VDM,
+    VDO,
+    VHW,
+    VLW,
+    VTG,
+    VWR,
+    VWT,
+    WPL,
+    XDR,
+    XTE,
+    ZDA,
+}
+
+#[derive(Debug)]
+pub struct FieldInfo {
+    pub name: &'static str,
+    pub kind: FieldKind,
+    pub unit: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    Text,
+    Flag,
+    Integer,
+    Number,
+    Time,
+    Date,
+    Latitude,
+    Longitude,
+}
+
+#[derive(Debug)]
+pub struct SentenceInfo {
+    pub sentence_type: SentenceType,
+    pub message: &'static str,
+    pub name: &'static str,
+    pub version: &'static str,
+    pub repeat: Option<(usize, usize)>,
+    pub fields: &'static [FieldInfo],
+}
+
+impl SentenceType {
+    pub fn from_message(message: &str) -> Option<SentenceType> {
+        SENTENCES
+            .iter()
+            .find(|info| info.message.eq_ignore_ascii_case(message))
+            .map(|info| info.sentence_type)
+    }
+
+    pub fn info(&self) -> &'static SentenceInfo {
+        &SENTENCES[*self as usize]
+    }
+
+    pub fn message(&self) -> &'static str {
+        self.info().message
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.info().name
+    }
+}
+
+impl core::fmt::Display for SentenceType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl SentenceInfo {
+    pub fn field_count(&self) -> usize {
+        self.fields.len()
+    }
+
+    pub fn field(&self, n: usize) -> Option<&'static FieldInfo> {
+        self.fields.get(n)
+    }
+
+    pub fn is_variable_length(&self) -> bool {
+        self.repeat.is_some()
+    }
+
+    pub fn field_at(&self, n: usize, count: usize) -> Option<&'static FieldInfo> {
+        let (start, length) = match self.repeat {
+            Some(repeat) if n >= repeat.0 => repeat,
+            _ => return self.field(n),
+        };
+        let trailing = self.fields.len().saturating_sub(start + length);
+        let repeated = count.saturating_sub(start);
+        if trailing > 0 && repeated % length == trailing && n + trailing >= count {
+            return self.field(start + length + n - (count - trailing));
+        }
+        self.field(start + (n - start) % length)
+    }
+}
+
+const fn field(name: &'static str, kind: FieldKind) -> FieldInfo {
+    FieldInfo {
+        name,
+        kind,
+        unit: None,
+    }
+}
+
+const fn measured(name: &'static str, unit: &'static str) -> FieldInfo {
+    FieldInfo {
+        name,
+        kind: FieldKind::Number,
+        unit: Some(unit),
+    }
+}
+
+pub static SENTENCES: [SentenceInfo; 43] = [
+    SentenceInfo {
+        sentence_type: SentenceType::AAM,
+        message: "AAM",
+        name: "Waypoint arrival alarm",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            field("arrival circle status", FieldKind::Flag),
+            field("perpendicular status", FieldKind::Flag),
+            measured("arrival circle radius", "nmi"),
+            field("radius units", FieldKind::Flag),
+            field("waypoint id", FieldKind::Text),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::APB,
+        message: "APB",
+        name: "Heading/track controller (autopilot) sentence B",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            field("status", FieldKind::Flag),
+            field("cycle lock status", FieldKind::Flag),
+            measured("cross track error", "nmi"),
+            field("steer direction", FieldKind::Flag),
+            field("cross track units", FieldKind::Flag),
+            field("arrival circle status", FieldKind::Flag),
+            field("perpendicular status", FieldKind::Flag),
+            measured("bearing origin to destination", "deg"),
+            field("bearing origin to destination reference", FieldKind::Flag),
+            field("destination waypoint id", FieldKind::Text),
+            measured("bearing present position to destination", "deg"),
+            field(
+                "bearing present position to destination reference",
+                FieldKind::Flag,
+            ),
+            measured("heading to steer", "deg"),
+            field("heading to steer reference", FieldKind::Flag),
+            field("mode", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::BOD,
+        message: "BOD",
+        name: "Bearing origin to destination",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            measured("bearing true", "deg"),
+            field("true", FieldKind::Flag),
+            measured("bearing magnetic", "deg"),
+            field("magnetic", FieldKind::Flag),
+            field("destination waypoint id", FieldKind::Text),
+            field("origin waypoint id", FieldKind::Text),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::BWC,
+        message: "BWC",
+        name: "Bearing and distance to waypoint, great circle",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            field("time", FieldKind::Time),
+            field("waypoint latitude", FieldKind::Latitude),
+            field("north/south", FieldKind::Flag),
+            field("waypoint longitude", FieldKind::Longitude),
+            field("east/west", FieldKind::Flag),
+            measured("bearing true", "deg"),
+            field("true", FieldKind::Flag),
+            measured("bearing magnetic", "deg"),
+            field("magnetic", FieldKind::Flag),
+            measured("distance", "nmi"),
+            field("distance units", FieldKind::Flag),
+            field("waypoint id", FieldKind::Text),
+            field("mode", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::BWR,
+        message: "BWR",
+        name: "Bearing and distance to waypoint, rhumb line",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            field("time", FieldKind::Time),
+            field("waypoint latitude", FieldKind::Latitude),
+            field("north/south", FieldKind::Flag),
+            field("waypoint longitude", FieldKind::Longitude),
+            field("east/west", FieldKind::Flag),
+            measured("bearing true", "deg"),
+            field("true", FieldKind::Flag),
+            measured("bearing magnetic", "deg"),
+            field("magnetic", FieldKind::Flag),
+            measured("distance", "nmi"),
+            field("distance units", FieldKind::Flag),
+            field("waypoint id", FieldKind::Text),
+            field("mode", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::BWW,
+        message: "BWW",
+        name: "Bearing waypoint to waypoint",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            measured("bearing true", "deg"),
+            field("true", FieldKind::Flag),
+            measured("bearing magnetic", "deg"),
+            field("magnetic", FieldKind::Flag),
+            field("to waypoint id", FieldKind::Text),
+            field("from waypoint id", FieldKind::Text),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::DBS,
+        message: "DBS",
+        name: "Depth below surface",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            measured("depth", "ft"),
+            field("feet", FieldKind::Flag),
+            measured("depth", "m"),
+            field("meters", FieldKind::Flag),
+            measured("depth", "fathom"),
+            field("fathoms", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::DBT,
+        message: "DBT",
+        name: "Depth below transducer",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            measured("depth", "ft"),
+            field("feet", FieldKind::Flag),
+            measured("depth", "m"),
+            field("meters", FieldKind::Flag),
+            measured("depth", "fathom"),
+            field("fathoms", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::DPT,
+        message: "DPT",
+        name: "Depth",
+        version: "2.0",
+        repeat: None,
+        fields: &[
+            measured("depth", "m"),
+            measured("transducer offset", "m"),
+            measured("maximum range", "m"),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::DTM,
+        message: "DTM",
+        name: "Datum reference",
+        version: "2.1",
+        repeat: None,
+        fields: &[
+            field("local datum", FieldKind::Text),
+            field("local datum subdivision", FieldKind::Text),
+            measured("latitude offset", "min"),
+            field("north/south", FieldKind::Flag),
+            measured("longitude offset", "min"),
+            field("east/west", FieldKind::Flag),
+            measured("altitude offset", "m"),
+            field("reference datum", FieldKind::Text),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::GBS,
+        message: "GBS",
+        name: "GNSS satellite fault detection",
+        version: "2.3",
+        repeat: None,
+        fields: &[
+            field("time", FieldKind::Time),
+            measured("latitude error", "m"),
+            measured("longitude error", "m"),
+            measured("altitude error", "m"),
+            field("failed satellite", FieldKind::Integer),
+            field("probability of missed detection", FieldKind::Number),
+            measured("bias estimate", "m"),
+            measured("bias standard deviation", "m"),
+            field("system id", FieldKind::Integer),
+            field("signal id", FieldKind::Integer),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::GGA,
+        message: "GGA",
+        name: "Global positioning system fix data",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            field("time", FieldKind::Time),
+            field("latitude", FieldKind::Latitude),
+            field("north/south", FieldKind::Flag),
+            field("longitude", FieldKind::Longitude),
+            field("east/west", FieldKind::Flag),
+            field("quality", FieldKind::Integer),
+            field("number of satellites", FieldKind::Integer),
+            field("horizontal dilution of precision", FieldKind::Number),
+            measured("altitude", "m"),
+            field("altitude units", FieldKind::Flag),
+            measured("geoid separation", "m"),
+            field("geoid separation units", FieldKind::Flag),
+            measured("age of differential data", "s"),
+            field("reference station id", FieldKind::Text),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::GLL,
+        message: "GLL",
+        name: "Geographic position, latitude/longitude",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            field("latitude", FieldKind::Latitude),
+            field("north/south", FieldKind::Flag),
+            field("longitude", FieldKind::Longitude),
+            field("east/west", FieldKind::Flag),
+            field("time", FieldKind::Time),
+            field("status", FieldKind::Flag),
+            field("mode", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::GNS,
+        message: "GNS",
+        name: "GNSS fix data",
+        version: "3.0",
+        repeat: None,
+        fields: &[
+            field("time", FieldKind::Time),
+            field("latitude", FieldKind::Latitude),
+            field("north/south", FieldKind::Flag),
+            field("longitude", FieldKind::Longitude),
+            field("east/west", FieldKind::Flag),
+            field("mode", FieldKind::Text),
+            field("number of satellites", FieldKind::Integer),
+            field("horizontal dilution of precision", FieldKind::Number),
+            measured("altitude", "m"),
+            measured("geoid separation", "m"),
+            measured("age of differential data", "s"),
+            field("reference station id", FieldKind::Text),
+            field("navigational status", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::GRS,
+        message: "GRS",
+        name: "GNSS range residuals",
+        version: "2.3",
+        repeat: None,
+        fields: &[
+            field("time", FieldKind::Time),
+            field("mode", FieldKind::Flag),
+            measured("residual 1", "m"),
+            measured("residual 2", "m"),
+            measured("residual 3", "m"),
+            measured("residual 4", "m"),
+            measured("residual 5", "m"),
+            measured("residual 6", "m"),
+            measured("residual 7", "m"),
+            measured("residual 8", "m"),
+            measured("residual 9", "m"),
+            measured("residual 10", "m"),
+            measured("residual 11", "m"),
+            measured("residual 12", "m"),
+            field("system id", FieldKind::Integer),
+            field("signal id", FieldKind::Integer),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::GSA,
+        message: "GSA",
+        name: "GNSS DOP and active satellites",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            field("selection mode", FieldKind::Flag),
+            field("fix mode", FieldKind::Integer),
+            field("satellite 1", FieldKind::Integer),
+            field("satellite 2", FieldKind::Integer),
+            field("satellite 3", FieldKind::Integer),
+            field("satellite 4", FieldKind::Integer),
+            field("satellite 5", FieldKind::Integer),
+            field("satellite 6", FieldKind::Integer),
+            field("satellite 7", FieldKind::Integer),
+            field("satellite 8", FieldKind::Integer),
+            field("satellite 9", FieldKind::Integer),
+            field("satellite 10", FieldKind::Integer),
+            field("satellite 11", FieldKind::Integer),
+            field("satellite 12", FieldKind::Integer),
+            field("position dilution of precision", FieldKind::Number),
+            field("horizontal dilution of precision", FieldKind::Number),
+            field("vertical dilution of precision", FieldKind::Number),
+            field("system id", FieldKind::Integer),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::GST,
+        message: "GST",
+        name: "GNSS pseudorange noise statistics",
+        version: "2.3",
+        repeat: None,
+        fields: &[
+            field("time", FieldKind::Time),
+            field("rms", FieldKind::Number),
+            measured("semi-major axis error", "m"),
+            measured("semi-minor axis error", "m"),
+            measured("semi-major axis orientation", "deg"),
+            measured("latitude error", "m"),
+            measured("longitude error", "m"),
+            measured("altitude error", "m"),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::GSV,
+        message: "GSV",
+        name: "GNSS satellites in view",
+        version: "1.5",
+        repeat: Some((3, 4)),
+        fields: &[
+            field("total sentences", FieldKind::Integer),
+            field("sentence number", FieldKind::Integer),
+            field("satellites in view", FieldKind::Integer),
+            field("satellite id", FieldKind::Integer),
+            measured("elevation", "deg"),
+            measured("azimuth", "deg"),
+            measured("snr", "dB-Hz"),
+            field("signal id", FieldKind::Integer),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::GXA,
+        message: "GXA",
+        name: "TRANSIT position",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            field("time", FieldKind::Time),
+            field("latitude", FieldKind::Latitude),
+            field("north/south", FieldKind::Flag),
+            field("longitude", FieldKind::Longitude),
+            field("east/west", FieldKind::Flag),
+            field("waypoint id", FieldKind::Text),
+            field("satellite number", FieldKind::Integer),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::HDG,
+        message: "HDG",
+        name: "Heading, deviation and variation",
+        version: "2.0",
+        repeat: None,
+        fields: &[
+            measured("heading", "deg"),
+            measured("deviation", "deg"),
+            field("deviation direction", FieldKind::Flag),
+            measured("variation", "deg"),
+            field("variation direction", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::HDM,
+        message: "HDM",
+        name: "Heading, magnetic",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            measured("heading", "deg"),
+            field("magnetic", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::HDT,
+        message: "HDT",
+        name: "Heading, true",
+        version: "1.5",
+        repeat: None,
+        fields: &[measured("heading", "deg"), field("true", FieldKind::Flag)],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::MDA,
+        message: "MDA",
+        name: "Meteorological composite",
+        version: "2.0",
+        repeat: None,
+        fields: &[
+            measured("pressure", "inHg"),
+            field("inches", FieldKind::Flag),
+            measured("pressure", "bar"),
+            field("bars", FieldKind::Flag),
+            measured("air temperature", "°C"),
+            field("celsius", FieldKind::Flag),
+            measured("water temperature", "°C"),
+            field("celsius", FieldKind::Flag),
+            measured("relative humidity", "%"),
+            measured("absolute humidity", "%"),
+            measured("dew point", "°C"),
+            field("celsius", FieldKind::Flag),
+            measured("wind direction true", "deg"),
+            field("true", FieldKind::Flag),
+            measured("wind direction magnetic", "deg"),
+            field("magnetic", FieldKind::Flag),
+            measured("wind speed", "kn"),
+            field("knots", FieldKind::Flag),
+            measured("wind speed", "m/s"),
+            field("meters per second", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::MTW,
+        message: "MTW",
+        name: "Water temperature",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            measured("temperature", "°C"),
+            field("celsius", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::MWD,
+        message: "MWD",
+        name: "Wind direction and speed",
+        version: "3.0",
+        repeat: None,
+        fields: &[
+            measured("wind direction true", "deg"),
+            field("true", FieldKind::Flag),
+            measured("wind direction magnetic", "deg"),
+            field("magnetic", FieldKind::Flag),
+            measured("wind speed", "kn"),
+            field("knots", FieldKind::Flag),
+            measured("wind speed", "m/s"),
+            field("meters per second", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::MWV,
+        message: "MWV",
+        name: "Wind speed and angle",
+        version: "2.0",
+        repeat: None,
+        fields: &[
+            measured("wind angle", "deg"),
+            field("reference", FieldKind::Flag),
+            field("wind speed", FieldKind::Number),
+            field("speed units", FieldKind::Flag),
+            field("status", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::RMB,
+        message: "RMB",
+        name: "Recommended minimum navigation information",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            field("status", FieldKind::Flag),
+            measured("cross track error", "nmi"),
+            field("steer direction", FieldKind::Flag),
+            field("origin waypoint id", FieldKind::Text),
+            field("destination waypoint id", FieldKind::Text),
+            field("destination latitude", FieldKind::Latitude),
+            field("north/south", FieldKind::Flag),
+            field("destination longitude", FieldKind::Longitude),
+            field("east/west", FieldKind::Flag),
+            measured("range to destination", "nmi"),
+            measured("bearing to destination", "deg"),
+            measured("destination closing velocity", "kn"),
+            field("arrival status", FieldKind::Flag),
+            field("mode", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::RMC,
+        message: "RMC",
+        name: "Recommended minimum specific GNSS data",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            field("time", FieldKind::Time),
+            field("status", FieldKind::Flag),
+            field("latitude", FieldKind::Latitude),
+            field("north/south", FieldKind::Flag),
+            field("longitude", FieldKind::Longitude),
+            field("east/west", FieldKind::Flag),
+            measured("speed over ground", "kn"),
+            measured("track made good", "deg"),
+            field("date", FieldKind::Date),
+            measured("magnetic variation", "deg"),
+            field("variation direction", FieldKind::Flag),
+            field("mode", FieldKind::Flag),
+            field("navigational status", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::ROT,
+        message: "ROT",
+        name: "Rate of turn",
+        version: "2.0",
+        repeat: None,
+        fields: &[
+            measured("rate of turn", "deg/min"),
+            field("status", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::RSA,
+        message: "RSA",
+        name: "Rudder sensor angle",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            measured("starboard rudder angle", "deg"),
+            field("starboard status", FieldKind::Flag),
+            measured("port rudder angle", "deg"),
+            field("port status", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::RTE,
+        message: "RTE",
+        name: "Routes",
+        version: "1.5",
+        repeat: Some((4, 1)),
+        fields: &[
+            field("total sentences", FieldKind::Integer),
+            field("sentence number", FieldKind::Integer),
+            field("mode", FieldKind::Flag),
+            field("route id", FieldKind::Text),
+            field("waypoint id", FieldKind::Text),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::TRF,
+        message: "TRF",
+        name: "TRANSIT fix data",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            field("time", FieldKind::Time),
+            field("date", FieldKind::Date),
+            field("latitude", FieldKind::Latitude),
+            field("north/south", FieldKind::Flag),
+            field("longitude", FieldKind::Longitude),
+            field("east/west", FieldKind::Flag),
+            measured("elevation angle", "deg"),
+            field("iterations", FieldKind::Integer),
+            field("doppler intervals", FieldKind::Integer),
+            measured("update distance", "nmi"),
+            field("satellite id", FieldKind::Integer),
+            field("status", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::VDM,
+        message: "VDM",
+        name: "AIS VHF data-link message",
+        version: "3.0",
+        repeat: None,
+        fields: &[
+            field("total sentences", FieldKind::Integer),
+            field("sentence number", FieldKind::Integer),
+            field("sequential message id", FieldKind::Integer),
+            field("channel", FieldKind::Flag),
+            field("encapsulated message", FieldKind::Text),
+            field("fill bits", FieldKind::Integer),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::VDO,
+        message: "VDO",
+        name: "AIS VHF data-link own-vessel report",
+        version: "3.0",
+        repeat: None,
+        fields: &[
+            field("total sentences", FieldKind::Integer),
+            field("sentence number", FieldKind::Integer),
+            field("sequential message id", FieldKind::Integer),
+            field("channel", FieldKind::Flag),
+            field("encapsulated message", FieldKind::Text),
+            field("fill bits", FieldKind::Integer),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::VHW,
+        message: "VHW",
+        name: "Water speed and heading",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            measured("heading true", "deg"),
+            field("true", FieldKind::Flag),
+            measured("heading magnetic", "deg"),
+            field("magnetic", FieldKind::Flag),
+            measured("speed", "kn"),
+            field("knots", FieldKind::Flag),
+            measured("speed", "km/h"),
+            field("kilometers per hour", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::VLW,
+        message: "VLW",
+        name: "Distance traveled through water",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            measured("total distance", "nmi"),
+            field("nautical miles", FieldKind::Flag),
+            measured("distance since reset", "nmi"),
+            field("nautical miles", FieldKind::Flag),
+            measured("total ground distance", "nmi"),
+            field("nautical miles", FieldKind::Flag),
+            measured("ground distance since reset", "nmi"),
+            field("nautical miles", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::VTG,
+        message: "VTG",
+        name: "Course over ground and ground speed",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            measured("course true", "deg"),
+            field("true", FieldKind::Flag),
+            measured("course magnetic", "deg"),
+            field("magnetic", FieldKind::Flag),
+            measured("speed", "kn"),
+            field("knots", FieldKind::Flag),
+            measured("speed", "km/h"),
+            field("kilometers per hour", FieldKind::Flag),
+            field("mode", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::VWR,
+        message: "VWR",
+        name: "Relative wind speed and angle",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            measured("wind angle", "deg"),
+            field("left/right", FieldKind::Flag),
+            measured("wind speed", "kn"),
+            field("knots", FieldKind::Flag),
+            measured("wind speed", "m/s"),
+            field("meters per second", FieldKind::Flag),
+            measured("wind speed", "km/h"),
+            field("kilometers per hour", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::VWT,
+        message: "VWT",
+        name: "True wind speed and angle",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            measured("wind angle", "deg"),
+            field("left/right", FieldKind::Flag),
+            measured("wind speed", "kn"),
+            field("knots", FieldKind::Flag),
+            measured("wind speed", "m/s"),
+            field("meters per second", FieldKind::Flag),
+            measured("wind speed", "km/h"),
+            field("kilometers per hour", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::WPL,
+        message: "WPL",
+        name: "Waypoint location",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            field("latitude", FieldKind::Latitude),
+            field("north/south", FieldKind::Flag),
+            field("longitude", FieldKind::Longitude),
+            field("east/west", FieldKind::Flag),
+            field("waypoint id", FieldKind::Text),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::XDR,
+        message: "XDR",
+        name: "Transducer measurements",
+        version: "2.0",
+        repeat: Some((0, 4)),
+        fields: &[
+            field("transducer type", FieldKind::Flag),
+            field("measurement", FieldKind::Number),
+            field("units", FieldKind::Flag),
+            field("transducer id", FieldKind::Text),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::XTE,
+        message: "XTE",
+        name: "Cross-track error, measured",
+        version: "1.5",
+        repeat: None,
+        fields: &[
+            field("status", FieldKind::Flag),
+            field("cycle lock status", FieldKind::Flag),
+            measured("cross track error", "nmi"),
+            field("steer direction", FieldKind::Flag),
+            field("cross track units", FieldKind::Flag),
+            field("mode", FieldKind::Flag),
+        ],
+    },
+    SentenceInfo {
+        sentence_type: SentenceType::ZDA,
+        message: "ZDA",
+        name: "Time and date",
+        version: "2.0",
+        repeat: None,
+        fields: &[
+            field("time", FieldKind::Time),
+            field("day", FieldKind::Integer),
+            field("month", FieldKind::Integer),
+            field("year", FieldKind::Integer),
+            field("local zone hours", FieldKind::Integer),
+            field("local zone minutes", FieldKind::Integer),
+        ],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_is_indexed_by_sentence_type() {
+        for (index, info) in SENTENCES.iter().enumerate() {
+            assert_eq!(info.sentence_type as usize, index, "{}", info.message);
+            assert_eq!(info.sentence_type.info().message, info.message);
+            assert_eq!(
+                SentenceType::from_message(info.message),
+                Some(info.sentence_type)
+            );
+            assert!(!info.fields.is_empty(), "{}", info.message);
+            if let Some((start, length)) = info.repeat {
+                assert!(start + length <= info.fields.len(), "{}", info.message);
+            }
+        }
+    }
+
+    #[test]
+    fn looks_up_messages() {
+        assert_eq!(SentenceType::from_message("hdt"), Some(SentenceType::HDT));
+        assert_eq!(SentenceType::from_message("XYZ"), None);
+        assert_eq!(SentenceType::HDT.name(), "Heading, true");
+        assert_eq!(SentenceType::GSV.to_string(), "GSV");
+        let heading = SentenceType::HDT.info().field(0).unwrap();
+        assert_eq!(
+            (heading.name, heading.kind, heading.unit),
+            ("heading", FieldKind::Number, Some("deg"))
+        );
+        assert!(SentenceType::HDT.info().field(2).is_none());
+    }
+
+    #[test]
+    fn repeated_fields_wrap() {
+        let gsv = SentenceType::GSV.info();
+        assert!(gsv.is_variable_length());
+        let name = |n, count| gsv.field_at(n, count).map(|f| f.name);
+        assert_eq!(name(2, 11), Some("satellites in view"));
+        assert_eq!(name(7, 11), Some("satellite id"));
+        assert_eq!(name(10, 11), Some("snr"));
+        assert_eq!(name(10, 12), Some("snr"));
+        assert_eq!(name(11, 12), Some("signal id"));
+
+        let rte = SentenceType::RTE.info();
+        assert_eq!(rte.field_at(9, 10).map(|f| f.name), Some("waypoint id"));
+        assert!(!SentenceType::HDT.info().is_variable_length());
+        assert!(SentenceType::HDT.info().field_at(5, 6).is_none());
+    }
+}
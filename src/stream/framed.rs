@@ -0,0 +1,268 @@
+use crate::{
+    base::{ChecksumPolicy, NmeaError},
+    proprietary::DecoderRegistry,
+    stream::StreamDecoder,
+    Nmea0183,
+};
+
+use futures_core::Stream;
+use futures_sink::Sink;
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{ready, Context, Poll};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+const READ_CHUNK: usize = 1024;
+
+#[derive(Debug)]
+pub struct NmeaReader<R> {
+    inner: R,
+    decoder: StreamDecoder,
+    eof: bool,
+}
+
+impl<R: AsyncRead + Unpin> NmeaReader<R> {
+    pub fn new(inner: R) -> NmeaReader<R> {
+        NmeaReader::with_policy(inner, ChecksumPolicy::default())
+    }
+
+    pub fn with_policy(inner: R, policy: ChecksumPolicy) -> NmeaReader<R> {
+        NmeaReader::with_decoder(inner, StreamDecoder::with_policy(policy))
+    }
+
+    pub fn with_registry(inner: R, registry: Arc<DecoderRegistry>) -> NmeaReader<R> {
+        NmeaReader::with_decoder(inner, StreamDecoder::with_registry(registry))
+    }
+
+    pub fn with_decoder(inner: R, decoder: StreamDecoder) -> NmeaReader<R> {
+        NmeaReader {
+            inner,
+            decoder,
+            eof: false,
+        }
+    }
+
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: AsyncRead + Unpin> Stream for NmeaReader<R> {
+    type Item = Result<Nmea0183, NmeaError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            if let Some(sentence) = this.decoder.next() {
+                return Poll::Ready(Some(sentence));
+            }
+            if this.eof {
+                return Poll::Ready(this.decoder.flush());
+            }
+            let mut chunk = [0u8; READ_CHUNK];
+            let mut buf = ReadBuf::new(&mut chunk);
+            if let Err(e) = ready!(Pin::new(&mut this.inner).poll_read(cx, &mut buf)) {
+                return Poll::Ready(Some(Err(NmeaError::Io(e))));
+            }
+            if buf.filled().is_empty() {
+                this.eof = true;
+            } else {
+                this.decoder.push(buf.filled());
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct NmeaWriter<W> {
+    inner: W,
+    buffer: Vec<u8>,
+}
+
+impl<W: AsyncWrite + Unpin> NmeaWriter<W> {
+    pub fn new(inner: W) -> NmeaWriter<W> {
+        NmeaWriter {
+            inner,
+            buffer: Vec::new(),
+        }
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    fn poll_drain(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), NmeaError>> {
+        while !self.buffer.is_empty() {
+            let written = ready!(Pin::new(&mut self.inner).poll_write(cx, &self.buffer))?;
+            if written == 0 {
+                return Poll::Ready(Err(NmeaError::Io(io::ErrorKind::WriteZero.into())));
+            }
+            self.buffer.drain(..written);
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<W: AsyncWrite + Unpin> Sink<Nmea0183> for NmeaWriter<W> {
+    type Error = NmeaError;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), NmeaError>> {
+        if self.buffer.len() >= READ_CHUNK {
+            self.poll_drain(cx)
+        } else {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: Nmea0183) -> Result<(), NmeaError> {
+        self.buffer.extend_from_slice(item.to_sentence().as_bytes());
+        Ok(())
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), NmeaError>> {
+        ready!(self.poll_drain(cx))?;
+        Poll::Ready(ready!(Pin::new(&mut self.inner).poll_flush(cx)).map_err(NmeaError::Io))
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), NmeaError>> {
+        ready!(self.poll_drain(cx))?;
+        Poll::Ready(ready!(Pin::new(&mut self.inner).poll_shutdown(cx)).map_err(NmeaError::Io))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proprietary::CustomSentence;
+    use std::task::Waker;
+
+    fn collect<R: AsyncRead + Unpin>(
+        mut reader: NmeaReader<R>,
+    ) -> Vec<Result<Nmea0183, NmeaError>> {
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut results = Vec::new();
+        while let Poll::Ready(Some(result)) = Pin::new(&mut reader).poll_next(&mut cx) {
+            results.push(result);
+        }
+        results
+    }
+
+    #[test]
+    fn reader_consults_registry() {
+        #[derive(Debug)]
+        struct Garmin(crate::base::Nmea0183Base);
+
+        impl CustomSentence for Garmin {
+            fn base(&self) -> &crate::base::Nmea0183Base {
+                &self.0
+            }
+
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+        }
+
+        let mut registry = DecoderRegistry::new();
+        registry.register(
+            "PGRM",
+            |base| -> Result<Box<dyn CustomSentence>, NmeaError> { Ok(Box::new(Garmin(base))) },
+        );
+        let input: &[u8] = b"$PGRME,15.0,M,45.0,M,25.0,M*1C\r\n$HEHDT,274.07,T*19\r\n";
+        let results = collect(NmeaReader::with_registry(input, Arc::new(registry)));
+        match &results[..] {
+            [Ok(Nmea0183::Custom(custom)), Ok(Nmea0183::HDT(_))] => {
+                assert!(custom.downcast_ref::<Garmin>().is_some())
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+
+        let results = collect(NmeaReader::new(input));
+        assert!(matches!(
+            results[..],
+            [Ok(Nmea0183::Proprietary(_)), Ok(Nmea0183::HDT(_))]
+        ));
+    }
+
+    #[test]
+    fn reader_spans_read_chunks_and_flushes_at_eof() {
+        let mut input = b"$HEHDT,274.07,T*19\r\n".repeat(100);
+        input.extend_from_slice(b"$SDDPT,2.4,0.5*54");
+        assert!(input.len() > READ_CHUNK);
+        let results = collect(NmeaReader::new(&input[..]));
+        assert_eq!(results.len(), 101);
+        assert!(results[..100]
+            .iter()
+            .all(|r| matches!(r, Ok(Nmea0183::HDT(_)))));
+        assert!(matches!(results[100], Ok(Nmea0183::DPT(_))));
+    }
+
+    #[test]
+    fn reader_applies_checksum_policy() {
+        let input: &[u8] = b"$HEHDT,274.07,T*18\r\n";
+        assert!(matches!(
+            collect(NmeaReader::new(input))[..],
+            [Err(NmeaError::BadChecksum { .. })]
+        ));
+        let reader = NmeaReader::with_policy(input, ChecksumPolicy::Permissive);
+        assert!(matches!(collect(reader)[..], [Ok(Nmea0183::HDT(_))]));
+    }
+
+    #[test]
+    fn reader_reports_io_errors() {
+        struct Failing;
+
+        impl AsyncRead for Failing {
+            fn poll_read(
+                self: Pin<&mut Self>,
+                _: &mut Context<'_>,
+                _: &mut ReadBuf<'_>,
+            ) -> Poll<io::Result<()>> {
+                Poll::Ready(Err(io::ErrorKind::ConnectionReset.into()))
+            }
+        }
+
+        let mut reader = NmeaReader::new(Failing);
+        let mut cx = Context::from_waker(Waker::noop());
+        match Pin::new(&mut reader).poll_next(&mut cx) {
+            Poll::Ready(Some(Err(NmeaError::Io(e)))) => {
+                assert_eq!(e.kind(), io::ErrorKind::ConnectionReset)
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    #[test]
+    fn writer_round_trips_through_reader() {
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut writer = NmeaWriter::new(Vec::new());
+        for line in ["$HEHDT,274.07,T*19", "$SDDPT,2.4,0.5*54"] {
+            let sentence = Nmea0183::from_string(&line.to_string()).unwrap();
+            assert!(matches!(
+                Pin::new(&mut writer).poll_ready(&mut cx),
+                Poll::Ready(Ok(()))
+            ));
+            Pin::new(&mut writer).start_send(sentence).unwrap();
+        }
+        assert!(writer.get_ref().is_empty());
+        assert!(matches!(
+            Pin::new(&mut writer).poll_flush(&mut cx),
+            Poll::Ready(Ok(()))
+        ));
+        let output = writer.into_inner();
+        assert_eq!(output, b"$HEHDT,274.07,T*19\r\n$SDDPT,2.4,0.5*54\r\n");
+        let results = collect(NmeaReader::new(&output[..]));
+        assert!(matches!(
+            results[..],
+            [Ok(Nmea0183::HDT(_)), Ok(Nmea0183::DPT(_))]
+        ));
+    }
+}
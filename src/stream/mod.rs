@@ -0,0 +1,256 @@
+#[cfg(feature = "tokio")]
+pub mod framed;
+
+use crate::{base::*, proprietary::DecoderRegistry, Nmea0183};
+use std::collections::{vec_deque, VecDeque};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct StreamDecoder {
+    buffer: Vec<u8>,
+    length: usize,
+    max_length: usize,
+    in_sentence: bool,
+    policy: ChecksumPolicy,
+    registry: Option<Arc<DecoderRegistry>>,
+    ready: VecDeque<Result<Nmea0183, NmeaError>>,
+}
+
+impl Default for StreamDecoder {
+    fn default() -> StreamDecoder {
+        StreamDecoder {
+            buffer: Vec::new(),
+            length: 0,
+            max_length: MAX_SENTENCE_LENGTH,
+            in_sentence: false,
+            policy: ChecksumPolicy::default(),
+            registry: None,
+            ready: VecDeque::new(),
+        }
+    }
+}
+
+impl StreamDecoder {
+    pub fn new() -> StreamDecoder {
+        StreamDecoder::default()
+    }
+
+    pub fn with_policy(policy: ChecksumPolicy) -> StreamDecoder {
+        StreamDecoder {
+            policy,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_registry(registry: Arc<DecoderRegistry>) -> StreamDecoder {
+        StreamDecoder {
+            registry: Some(registry),
+            ..Default::default()
+        }
+    }
+
+    pub fn policy(&self) -> ChecksumPolicy {
+        self.policy
+    }
+
+    pub fn set_registry(&mut self, registry: Arc<DecoderRegistry>) {
+        self.registry = Some(registry);
+    }
+
+    pub fn max_length(&self) -> usize {
+        self.max_length
+    }
+
+    pub fn set_max_length(&mut self, max_length: usize) {
+        self.max_length = max_length;
+    }
+
+    pub fn feed(&mut self, bytes: &[u8]) -> vec_deque::Drain<'_, Result<Nmea0183, NmeaError>> {
+        self.push(bytes);
+        self.ready.drain(..)
+    }
+
+    pub fn push(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            match b {
+                b'$' | b'!' => {
+                    if self.in_sentence {
+                        self.ready.push_back(Err(NmeaError::Truncated));
+                    }
+                    self.start(b);
+                }
+                b'\r' | b'\n' => {
+                    if self.in_sentence {
+                        self.complete();
+                    }
+                }
+                _ => {
+                    if self.in_sentence {
+                        self.length += 1;
+                        if self.length <= self.max_length.saturating_sub(2) {
+                            self.buffer.push(b);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn flush(&mut self) -> Option<Result<Nmea0183, NmeaError>> {
+        if self.in_sentence {
+            self.complete();
+        }
+        self.ready.pop_front()
+    }
+
+    pub fn is_idle(&self) -> bool {
+        !self.in_sentence && self.ready.is_empty()
+    }
+
+    fn start(&mut self, delimiter: u8) {
+        self.buffer.clear();
+        self.buffer.push(delimiter);
+        self.length = 1;
+        self.in_sentence = true;
+    }
+
+    fn complete(&mut self) {
+        self.in_sentence = false;
+        let result = if self.length > self.max_length.saturating_sub(2) {
+            Err(NmeaError::LineTooLong(self.length + 2))
+        } else {
+            let line = String::from_utf8_lossy(&self.buffer);
+            match &self.registry {
+                Some(registry) => registry.from_string_with(&line, self.policy),
+                None => Nmea0183::from_string_with(&line, self.policy),
+            }
+        };
+        self.buffer.clear();
+        self.length = 0;
+        self.ready.push_back(result);
+    }
+}
+
+impl Iterator for StreamDecoder {
+    type Item = Result<Nmea0183, NmeaError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.ready.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUBX: &[u8] = b"$PUBX,00,081350.00,4717.113210,N,00833.915187,E,546.589,G3,2.1,2.0,0.007,77.52,0.007,,0.92,1.19,0.77,9,0,0*5F\r\n";
+
+    const HDT: &[u8] = b"$HEHDT,274.07,T*19\r\n";
+
+    fn is_hdt(result: &Result<Nmea0183, NmeaError>) -> bool {
+        matches!(result, Ok(Nmea0183::HDT(hdt)) if hdt.heading().unwrap() == 274.07)
+    }
+
+    #[test]
+    fn decodes_byte_by_byte() {
+        let mut decoder = StreamDecoder::new();
+        let mut results = Vec::new();
+        for chunk in HDT.chunks(1) {
+            results.extend(decoder.feed(chunk));
+        }
+        assert_eq!(results.len(), 1);
+        assert!(is_hdt(&results[0]));
+        assert!(decoder.is_idle());
+    }
+
+    #[test]
+    fn accepts_any_line_ending() {
+        let mut decoder = StreamDecoder::new();
+        let input = b"$HEHDT,274.07,T*19\r$HEHDT,274.07,T*19\n$HEHDT,274.07,T*19\r\n\r\n";
+        let results = decoder.feed(input).collect::<Vec<_>>();
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(is_hdt));
+    }
+
+    #[test]
+    fn skips_garbage_between_sentences() {
+        let mut decoder = StreamDecoder::new();
+        let mut input = b"\x00\xffnoise\r\n".to_vec();
+        input.extend_from_slice(HDT);
+        input.extend_from_slice(b"more noise");
+        input.extend_from_slice(HDT);
+        let results = decoder.feed(&input).collect::<Vec<_>>();
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(is_hdt));
+    }
+
+    #[test]
+    fn resynchronises_on_new_delimiter() {
+        let mut decoder = StreamDecoder::new();
+        let mut input = b"$GPGGA,092750.000,5321".to_vec();
+        input.extend_from_slice(HDT);
+        let results = decoder.feed(&input).collect::<Vec<_>>();
+        assert!(matches!(results[0], Err(NmeaError::Truncated)));
+        assert!(is_hdt(&results[1]));
+    }
+
+    #[test]
+    fn reports_bad_checksums_and_continues() {
+        let mut decoder = StreamDecoder::new();
+        let mut input = b"$HEHDT,274.07,T*18\r\n".to_vec();
+        input.extend_from_slice(HDT);
+        let results = decoder.feed(&input).collect::<Vec<_>>();
+        assert!(matches!(results[0], Err(NmeaError::BadChecksum { .. })));
+        assert!(is_hdt(&results[1]));
+
+        let mut decoder = StreamDecoder::with_policy(ChecksumPolicy::Permissive);
+        assert_eq!(decoder.policy(), ChecksumPolicy::Permissive);
+        let results = decoder.feed(&input).collect::<Vec<_>>();
+        assert!(results.iter().all(is_hdt));
+
+        let input = b"$HEHDT,274.07,T\r\n";
+        let results = StreamDecoder::new().feed(input).collect::<Vec<_>>();
+        assert!(matches!(results[..], [Err(NmeaError::MissingChecksum)]));
+        let mut decoder = StreamDecoder::with_policy(ChecksumPolicy::Lenient);
+        assert!(decoder.feed(input).all(|r| is_hdt(&r)));
+    }
+
+    #[test]
+    fn flush_completes_unterminated_sentence() {
+        let mut decoder = StreamDecoder::new();
+        assert_eq!(decoder.feed(&HDT[..HDT.len() - 2]).count(), 0);
+        assert!(!decoder.is_idle());
+        assert!(is_hdt(&decoder.flush().unwrap()));
+        assert!(decoder.flush().is_none());
+        assert!(decoder.is_idle());
+    }
+
+    #[test]
+    fn pushed_sentences_are_iterated() {
+        let mut decoder = StreamDecoder::new();
+        decoder.push(HDT);
+        decoder.push(HDT);
+        assert!(!decoder.is_idle());
+        assert_eq!(decoder.by_ref().filter(is_hdt).count(), 2);
+        assert!(decoder.is_idle());
+    }
+
+    #[test]
+    fn enforces_sentence_length_limit() {
+        let mut decoder = StreamDecoder::new();
+        let results = decoder.feed(PUBX).collect::<Vec<_>>();
+        assert!(matches!(results[..], [Err(NmeaError::LineTooLong(111))]));
+    }
+
+    #[test]
+    fn length_limit_is_configurable() {
+        let mut decoder = StreamDecoder::new();
+        decoder.set_max_length(128);
+        assert_eq!(decoder.max_length(), 128);
+        let results = decoder.feed(PUBX).collect::<Vec<_>>();
+        match &results[..] {
+            [Ok(Nmea0183::Proprietary(base))] => assert_eq!(base.manufacturer(), Some("UBX")),
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use crate::{base::*, proprietary::DecoderRegistry, Nmea0183};
+
+use chrono::prelude::*;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Group {
+    pub sentence_number: usize,
+    pub total_sentences: usize,
+    pub group_id: u32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagBlock {
+    pub source: Option<String>,
+    pub destination: Option<String>,
+    pub unix_time: Option<i64>,
+    pub line_count: Option<u32>,
+    pub relative_time: Option<i64>,
+    pub group: Option<Group>,
+    pub text: Option<String>,
+}
+
+impl TagBlock {
+    pub fn from_string(block: &str) -> Result<TagBlock, NmeaError> {
+        let block = block.trim_matches('\\');
+        let (body, checksum) = block.split_once('*').ok_or(NmeaError::Truncated)?;
+        let expected = u8::from_str_radix(checksum, 16)
+            .map_err(|_| NmeaError::MalformedChecksum(checksum.to_string()))?;
+        let actual = calculate_checksum(body);
+        if expected != actual {
+            return Err(NmeaError::BadChecksum { expected, actual });
+        }
+        let mut tag_block = TagBlock::default();
+        for (index, parameter) in body.split(',').enumerate() {
+            let invalid = || NmeaError::InvalidField {
+                index,
+                raw: parameter.to_string(),
+            };
+            let (key, value) = parameter.split_once(':').ok_or_else(invalid)?;
+            match key {
+                "s" => tag_block.source = Some(value.to_string()),
+                "d" => tag_block.destination = Some(value.to_string()),
+                "c" => tag_block.unix_time = Some(value.parse().map_err(|_| invalid())?),
+                "n" => tag_block.line_count = Some(value.parse().map_err(|_| invalid())?),
+                "r" => tag_block.relative_time = Some(value.parse().map_err(|_| invalid())?),
+                "g" => tag_block.group = Some(parse_group(value).ok_or_else(invalid)?),
+                "t" => tag_block.text = Some(value.to_string()),
+                _ => {}
+            }
+        }
+        Ok(tag_block)
+    }
+
+    pub fn split(line: &str) -> Result<(Option<TagBlock>, &str), NmeaError> {
+        let line = line.trim_start();
+        match line.strip_prefix('\\') {
+            Some(rest) => {
+                let (block, sentence) = rest.split_once('\\').ok_or(NmeaError::Truncated)?;
+                Ok((Some(TagBlock::from_string(block)?), sentence))
+            }
+            None => Ok((None, line)),
+        }
+    }
+
+    pub fn timestamp(&self) -> Option<DateTime<Utc>> {
+        DateTime::from_timestamp(self.unix_time?, 0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == TagBlock::default()
+    }
+}
+
+impl fmt::Display for TagBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parameters = Vec::new();
+        if let Some(g) = &self.group {
+            parameters.push(format!(
+                "g:{}-{}-{}",
+                g.sentence_number, g.total_sentences, g.group_id
+            ));
+        }
+        if let Some(s) = &self.source {
+            parameters.push(format!("s:{}", s));
+        }
+        if let Some(d) = &self.destination {
+            parameters.push(format!("d:{}", d));
+        }
+        if let Some(n) = self.line_count {
+            parameters.push(format!("n:{}", n));
+        }
+        if let Some(c) = self.unix_time {
+            parameters.push(format!("c:{}", c));
+        }
+        if let Some(r) = self.relative_time {
+            parameters.push(format!("r:{}", r));
+        }
+        if let Some(t) = &self.text {
+            parameters.push(format!("t:{}", t));
+        }
+        let body = parameters.join(",");
+        write!(f, "\\{}*{:02X}\\", body, calculate_checksum(&body))
+    }
+}
+
+#[derive(Debug)]
+pub struct TaggedSentence {
+    pub tag_block: Option<TagBlock>,
+    pub sentence: Nmea0183,
+}
+
+impl TaggedSentence {
+    pub fn new(tag_block: TagBlock, sentence: Nmea0183) -> TaggedSentence {
+        TaggedSentence {
+            tag_block: Some(tag_block),
+            sentence,
+        }
+    }
+
+    pub fn from_string(line: &str) -> Result<TaggedSentence, NmeaError> {
+        TaggedSentence::from_string_with(line, ChecksumPolicy::default())
+    }
+
+    pub fn from_string_with(
+        line: &str,
+        policy: ChecksumPolicy,
+    ) -> Result<TaggedSentence, NmeaError> {
+        let (tag_block, sentence) = TagBlock::split(line)?;
+        Ok(TaggedSentence {
+            tag_block,
+            sentence: Nmea0183::from_string_with(sentence, policy)?,
+        })
+    }
+
+    pub fn from_string_with_registry(
+        line: &str,
+        policy: ChecksumPolicy,
+        registry: &DecoderRegistry,
+    ) -> Result<TaggedSentence, NmeaError> {
+        let (tag_block, sentence) = TagBlock::split(line)?;
+        Ok(TaggedSentence {
+            tag_block,
+            sentence: registry.from_string_with(sentence, policy)?,
+        })
+    }
+
+    pub fn to_sentence(&self) -> String {
+        match &self.tag_block {
+            Some(tag_block) if !tag_block.is_empty() => {
+                format!("{}{}", tag_block, self.sentence.to_sentence())
+            }
+            _ => self.sentence.to_sentence(),
+        }
+    }
+}
+
+fn calculate_checksum(body: &str) -> u8 {
+    body.bytes().fold(0, |checksum, c| checksum ^ c)
+}
+
+fn parse_group(value: &str) -> Option<Group> {
+    let mut parts = value.splitn(3, '-');
+    Some(Group {
+        sentence_number: parts.next()?.parse().ok()?,
+        total_sentences: parts.next()?.parse().ok()?,
+        group_id: parts.next()?.parse().ok()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TAGGED: &str = "\\g:1-2-73874,n:157036,s:r003669945,c:1241544035*4A\\!AIVDM,1,1,,B,15M67FC000G?ufbE`FepT@3n00Sa,0*5C";
+
+    #[test]
+    fn parses_tag_block_parameters() {
+        let tagged = TaggedSentence::from_string(TAGGED).unwrap();
+        let tag_block = tagged.tag_block.unwrap();
+        assert_eq!(
+            tag_block.group,
+            Some(Group {
+                sentence_number: 1,
+                total_sentences: 2,
+                group_id: 73874,
+            })
+        );
+        assert_eq!(tag_block.line_count, Some(157036));
+        assert_eq!(tag_block.source.as_deref(), Some("r003669945"));
+        assert_eq!(tag_block.destination, None);
+        assert_eq!(
+            tag_block.timestamp().unwrap().to_rfc3339(),
+            "2009-05-05T17:20:35+00:00"
+        );
+        assert!(matches!(tagged.sentence, Nmea0183::VDM(_)));
+    }
+
+    #[test]
+    fn generates_tag_blocks_in_order() {
+        let tag_block = TagBlock {
+            source: Some(String::from("r003669945")),
+            unix_time: Some(1241544035),
+            line_count: Some(157036),
+            group: Some(Group {
+                sentence_number: 1,
+                total_sentences: 2,
+                group_id: 73874,
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            tag_block.to_string(),
+            "\\g:1-2-73874,s:r003669945,n:157036,c:1241544035*4A\\"
+        );
+        let line = tag_block.to_string() + "$HEHDT,274.07,T*19";
+        let tagged = TaggedSentence::from_string(&line).unwrap();
+        assert_eq!(tagged.tag_block.as_ref(), Some(&tag_block));
+        assert_eq!(tagged.to_sentence(), format!("{line}\r\n"));
+    }
+
+    #[test]
+    fn untagged_and_empty_blocks_write_plain_sentences() {
+        let tagged = TaggedSentence::from_string("$HEHDT,274.07,T*19").unwrap();
+        assert!(tagged.tag_block.is_none());
+        assert_eq!(tagged.to_sentence(), "$HEHDT,274.07,T*19\r\n");
+        let tagged = TaggedSentence::new(TagBlock::default(), tagged.sentence);
+        assert_eq!(tagged.to_sentence(), "$HEHDT,274.07,T*19\r\n");
+    }
+
+    #[test]
+    fn rejects_bad_tag_blocks() {
+        assert!(matches!(
+            TagBlock::from_string("s:2573345,c:1425167761*0E"),
+            Err(NmeaError::BadChecksum {
+                expected: 0x0E,
+                actual: 0x0F,
+            })
+        ));
+        assert!(matches!(
+            TagBlock::from_string("s:2573345,c:1425167761"),
+            Err(NmeaError::Truncated)
+        ));
+        assert!(matches!(
+            TagBlock::from_string("s:2573345,c:1425167761*ZZ"),
+            Err(NmeaError::MalformedChecksum(_))
+        ));
+        assert!(matches!(
+            TaggedSentence::from_string("\\s:2573345,c:1425167761*0F$HEHDT,274.07,T*19"),
+            Err(NmeaError::Truncated)
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_values_and_ignores_unknown_keys() {
+        let block =
+            |body: &str| TagBlock::from_string(&format!("{body}*{:02X}", calculate_checksum(body)));
+        assert!(matches!(
+            block("s:a,c:soon"),
+            Err(NmeaError::InvalidField { index: 1, .. })
+        ));
+        assert!(matches!(
+            block("g:1-2"),
+            Err(NmeaError::InvalidField { index: 0, .. })
+        ));
+        assert!(matches!(
+            block("novalue"),
+            Err(NmeaError::InvalidField { index: 0, .. })
+        ));
+        let tag_block = block("x:1,t:hello,r:-5").unwrap();
+        assert_eq!(tag_block.text.as_deref(), Some("hello"));
+        assert_eq!(tag_block.relative_time, Some(-5));
+    }
+
+    #[test]
+    fn sentence_checksum_policy_applies_after_tag_block() {
+        let line = "\\s:2573345,c:1425167761*0F\\$HEHDT,274.07,T*18";
+        assert!(matches!(
+            TaggedSentence::from_string(line),
+            Err(NmeaError::BadChecksum { .. })
+        ));
+        let tagged = TaggedSentence::from_string_with(line, ChecksumPolicy::Permissive).unwrap();
+        assert_eq!(tagged.tag_block.unwrap().unix_time, Some(1425167761));
+    }
+}
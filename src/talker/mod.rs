@@ -0,0 +1,338 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TalkerId {
+    AB,
+    AD,
+    AG,
+    AI,
+    AN,
+    AP,
+    AR,
+    AS,
+    AT,
+    AX,
+    BI,
+    BN,
+    CA,
+    CD,
+    CR,
+    CS,
+    CT,
+    CV,
+    CX,
+    DF,
+    DU,
+    EC,
+    EI,
+    EP,
+    ER,
+    FD,
+    FS,
+    GA,
+    GB,
+    GI,
+    GL,
+    GN,
+    GP,
+    GQ,
+    HC,
+    HE,
+    HN,
+    II,
+    IN,
+    LC,
+    RA,
+    RC,
+    SD,
+    SN,
+    SS,
+    TI,
+    UP,
+    VD,
+    VM,
+    VR,
+    VW,
+    WI,
+    YX,
+    ZA,
+    ZC,
+    ZQ,
+    ZV,
+    Proprietary,
+    Other([u8; 2]),
+}
+
+impl TalkerId {
+    pub fn from_code(code: &str) -> Option<TalkerId> {
+        let talker = match code {
+            "AB" => TalkerId::AB,
+            "AD" => TalkerId::AD,
+            "AG" => TalkerId::AG,
+            "AI" => TalkerId::AI,
+            "AN" => TalkerId::AN,
+            "AP" => TalkerId::AP,
+            "AR" => TalkerId::AR,
+            "AS" => TalkerId::AS,
+            "AT" => TalkerId::AT,
+            "AX" => TalkerId::AX,
+            "BI" => TalkerId::BI,
+            "BN" => TalkerId::BN,
+            "CA" => TalkerId::CA,
+            "CD" => TalkerId::CD,
+            "CR" => TalkerId::CR,
+            "CS" => TalkerId::CS,
+            "CT" => TalkerId::CT,
+            "CV" => TalkerId::CV,
+            "CX" => TalkerId::CX,
+            "DF" => TalkerId::DF,
+            "DU" => TalkerId::DU,
+            "EC" => TalkerId::EC,
+            "EI" => TalkerId::EI,
+            "EP" => TalkerId::EP,
+            "ER" => TalkerId::ER,
+            "FD" => TalkerId::FD,
+            "FS" => TalkerId::FS,
+            "GA" => TalkerId::GA,
+            "GB" => TalkerId::GB,
+            "GI" => TalkerId::GI,
+            "GL" => TalkerId::GL,
+            "GN" => TalkerId::GN,
+            "GP" => TalkerId::GP,
+            "GQ" => TalkerId::GQ,
+            "HC" => TalkerId::HC,
+            "HE" => TalkerId::HE,
+            "HN" => TalkerId::HN,
+            "II" => TalkerId::II,
+            "IN" => TalkerId::IN,
+            "LC" => TalkerId::LC,
+            "RA" => TalkerId::RA,
+            "RC" => TalkerId::RC,
+            "SD" => TalkerId::SD,
+            "SN" => TalkerId::SN,
+            "SS" => TalkerId::SS,
+            "TI" => TalkerId::TI,
+            "UP" => TalkerId::UP,
+            "VD" => TalkerId::VD,
+            "VM" => TalkerId::VM,
+            "VR" => TalkerId::VR,
+            "VW" => TalkerId::VW,
+            "WI" => TalkerId::WI,
+            "YX" => TalkerId::YX,
+            "ZA" => TalkerId::ZA,
+            "ZC" => TalkerId::ZC,
+            "ZQ" => TalkerId::ZQ,
+            "ZV" => TalkerId::ZV,
+            "P" => TalkerId::Proprietary,
+            _ => match code.as_bytes() {
+                [a, b] => TalkerId::Other([*a, *b]),
+                _ => return None,
+            },
+        };
+        Some(talker)
+    }
+
+    pub fn code(&self) -> &str {
+        match self {
+            TalkerId::AB => "AB",
+            TalkerId::AD => "AD",
+            TalkerId::AG => "AG",
+            TalkerId::AI => "AI",
+            TalkerId::AN => "AN",
+            TalkerId::AP => "AP",
+            TalkerId::AR => "AR",
+            TalkerId::AS => "AS",
+            TalkerId::AT => "AT",
+            TalkerId::AX => "AX",
+            TalkerId::BI => "BI",
+            TalkerId::BN => "BN",
+            TalkerId::CA => "CA",
+            TalkerId::CD => "CD",
+            TalkerId::CR => "CR",
+            TalkerId::CS => "CS",
+            TalkerId::CT => "CT",
+            TalkerId::CV => "CV",
+            TalkerId::CX => "CX",
+            TalkerId::DF => "DF",
+            TalkerId::DU => "DU",
+            TalkerId::EC => "EC",
+            TalkerId::EI => "EI",
+            TalkerId::EP => "EP",
+            TalkerId::ER => "ER",
+            TalkerId::FD => "FD",
+            TalkerId::FS => "FS",
+            TalkerId::GA => "GA",
+            TalkerId::GB => "GB",
+            TalkerId::GI => "GI",
+            TalkerId::GL => "GL",
+            TalkerId::GN => "GN",
+            TalkerId::GP => "GP",
+            TalkerId::GQ => "GQ",
+            TalkerId::HC => "HC",
+            TalkerId::HE => "HE",
+            TalkerId::HN => "HN",
+            TalkerId::II => "II",
+            TalkerId::IN => "IN",
+            TalkerId::LC => "LC",
+            TalkerId::RA => "RA",
+            TalkerId::RC => "RC",
+            TalkerId::SD => "SD",
+            TalkerId::SN => "SN",
+            TalkerId::SS => "SS",
+            TalkerId::TI => "TI",
+            TalkerId::UP => "UP",
+            TalkerId::VD => "VD",
+            TalkerId::VM => "VM",
+            TalkerId::VR => "VR",
+            TalkerId::VW => "VW",
+            TalkerId::WI => "WI",
+            TalkerId::YX => "YX",
+            TalkerId::ZA => "ZA",
+            TalkerId::ZC => "ZC",
+            TalkerId::ZQ => "ZQ",
+            TalkerId::ZV => "ZV",
+            TalkerId::Proprietary => "P",
+            TalkerId::Other(code) => core::str::from_utf8(code).unwrap_or_default(),
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            TalkerId::AB => "Independent AIS base station",
+            TalkerId::AD => "Dependent AIS base station",
+            TalkerId::AG => "Autopilot, general",
+            TalkerId::AI => "Mobile AIS station",
+            TalkerId::AN => "AIS aid to navigation",
+            TalkerId::AP => "Autopilot, magnetic",
+            TalkerId::AR => "AIS receiving station",
+            TalkerId::AS => "AIS limited base station",
+            TalkerId::AT => "AIS transmitting station",
+            TalkerId::AX => "AIS simplex repeater",
+            TalkerId::BI => "Bilge system",
+            TalkerId::BN => "Bridge navigational watch alarm system",
+            TalkerId::CA => "Central alarm",
+            TalkerId::CD => "Digital selective calling",
+            TalkerId::CR => "Data receiver",
+            TalkerId::CS => "Satellite communications",
+            TalkerId::CT => "Radio telephone, MF/HF",
+            TalkerId::CV => "Radio telephone, VHF",
+            TalkerId::CX => "Scanning receiver",
+            TalkerId::DF => "Direction finder",
+            TalkerId::DU => "Duplex repeater station",
+            TalkerId::EC => "Electronic chart system",
+            TalkerId::EI => "Electronic chart display and information system",
+            TalkerId::EP => "Emergency position indicating radio beacon",
+            TalkerId::ER => "Engine room monitoring system",
+            TalkerId::FD => "Fire door",
+            TalkerId::FS => "Fire sprinkler",
+            TalkerId::GA => "Galileo receiver",
+            TalkerId::GB => "BeiDou receiver",
+            TalkerId::GI => "NavIC receiver",
+            TalkerId::GL => "GLONASS receiver",
+            TalkerId::GN => "Multi-constellation GNSS receiver",
+            TalkerId::GP => "GPS receiver",
+            TalkerId::GQ => "QZSS receiver",
+            TalkerId::HC => "Magnetic compass",
+            TalkerId::HE => "North seeking gyro compass",
+            TalkerId::HN => "Non north seeking gyro compass",
+            TalkerId::II => "Integrated instrumentation",
+            TalkerId::IN => "Integrated navigation",
+            TalkerId::LC => "Loran-C receiver",
+            TalkerId::RA => "Radar",
+            TalkerId::RC => "Propulsion machinery",
+            TalkerId::SD => "Echo sounder",
+            TalkerId::SN => "Electronic positioning system",
+            TalkerId::SS => "Scanning sounder",
+            TalkerId::TI => "Turn rate indicator",
+            TalkerId::UP => "Microprocessor controller",
+            TalkerId::VD => "Doppler velocity sensor",
+            TalkerId::VM => "Speed log, water, magnetic",
+            TalkerId::VR => "Voyage data recorder",
+            TalkerId::VW => "Speed log, water, mechanical",
+            TalkerId::WI => "Weather instruments",
+            TalkerId::YX => "Transducer",
+            TalkerId::ZA => "Atomic clock",
+            TalkerId::ZC => "Chronometer",
+            TalkerId::ZQ => "Quartz clock",
+            TalkerId::ZV => "Radio update clock",
+            TalkerId::Proprietary => "Proprietary",
+            TalkerId::Other(_) => "Unknown",
+        }
+    }
+
+    pub fn is_gnss(&self) -> bool {
+        matches!(
+            self,
+            TalkerId::GA
+                | TalkerId::GB
+                | TalkerId::GI
+                | TalkerId::GL
+                | TalkerId::GN
+                | TalkerId::GP
+                | TalkerId::GQ
+        )
+    }
+
+    pub fn gnss_system_id(&self) -> Option<u8> {
+        match self {
+            TalkerId::GP => Some(1),
+            TalkerId::GL => Some(2),
+            TalkerId::GA => Some(3),
+            TalkerId::GB => Some(4),
+            TalkerId::GQ => Some(5),
+            TalkerId::GI => Some(6),
+            _ => None,
+        }
+    }
+}
+
+impl core::fmt::Display for TalkerId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{SentenceFields, SentenceRef};
+
+    #[test]
+    fn codes_round_trip() {
+        for code in ["AI", "GP", "GN", "HE", "II", "SD", "WI", "ZV"] {
+            let talker = TalkerId::from_code(code).unwrap();
+            assert!(!matches!(talker, TalkerId::Other(_)), "{code}");
+            assert_eq!(talker.code(), code);
+            assert_ne!(talker.description(), "Unknown");
+        }
+        let other = TalkerId::from_code("QZ").unwrap();
+        assert_eq!(other, TalkerId::Other(*b"QZ"));
+        assert_eq!(other.code(), "QZ");
+        assert_eq!(other.description(), "Unknown");
+        assert_eq!(TalkerId::from_code("P"), Some(TalkerId::Proprietary));
+        assert_eq!(TalkerId::from_code("G"), None);
+        assert_eq!(TalkerId::from_code("GPS"), None);
+    }
+
+    #[test]
+    fn classifies_gnss_talkers() {
+        assert!(TalkerId::GN.is_gnss());
+        assert!(!TalkerId::HE.is_gnss());
+        assert_eq!(TalkerId::GP.gnss_system_id(), Some(1));
+        assert_eq!(TalkerId::GI.gnss_system_id(), Some(6));
+        assert_eq!(TalkerId::GN.gnss_system_id(), None);
+    }
+
+    #[test]
+    fn sentences_report_talker_and_delimiter() {
+        let sentence = SentenceRef::parse("$HEHDT,274.07,T*19").unwrap();
+        assert_eq!(sentence.talker_id(), Some(TalkerId::HE));
+        assert_eq!(sentence.delimiter(), '$');
+        let sentence =
+            SentenceRef::parse("!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C").unwrap();
+        assert_eq!(sentence.talker_id(), Some(TalkerId::AI));
+        assert_eq!(sentence.delimiter(), '!');
+        let sentence = SentenceRef::parse("$PGRME,15.0,M,45.0,M,25.0,M*1C").unwrap();
+        assert_eq!(sentence.talker_id(), Some(TalkerId::Proprietary));
+        assert_eq!(TalkerId::HE.to_string(), "HE");
+    }
+}
@@ -0,0 +1,440 @@
+use crate::{
+    base::*,
+    proprietary::DecoderRegistry,
+    tag_block::{Group, TagBlock, TaggedSentence},
+    Nmea0183,
+};
+
+use std::collections::HashMap;
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4, UdpSocket};
+use std::sync::Arc;
+
+pub const HEADER: &[u8] = b"UdPbC\0";
+pub const MAX_DATAGRAM: usize = 1472;
+pub const MAX_LINE_COUNT: u32 = 999;
+pub const MAX_GROUP_ID: u32 = 99;
+
+#[derive(Debug)]
+pub struct Datagram {
+    pub sentences: Vec<Result<TaggedSentence, NmeaError>>,
+}
+
+impl Datagram {
+    pub fn from_bytes(datagram: &[u8]) -> Result<Datagram, NmeaError> {
+        Datagram::from_bytes_with(datagram, ChecksumPolicy::default())
+    }
+
+    pub fn from_bytes_with(datagram: &[u8], policy: ChecksumPolicy) -> Result<Datagram, NmeaError> {
+        Datagram::decode(datagram, |line| {
+            TaggedSentence::from_string_with(line, policy)
+        })
+    }
+
+    pub fn from_bytes_with_registry(
+        datagram: &[u8],
+        policy: ChecksumPolicy,
+        registry: &DecoderRegistry,
+    ) -> Result<Datagram, NmeaError> {
+        Datagram::decode(datagram, |line| {
+            TaggedSentence::from_string_with_registry(line, policy, registry)
+        })
+    }
+
+    fn decode<F>(datagram: &[u8], parse: F) -> Result<Datagram, NmeaError>
+    where
+        F: Fn(&str) -> Result<TaggedSentence, NmeaError>,
+    {
+        let body = datagram
+            .strip_prefix(HEADER)
+            .ok_or(NmeaError::UnknownHeader)?;
+        let body = String::from_utf8_lossy(body);
+        let sentences = body
+            .split(['\r', '\n'])
+            .filter(|line| !line.trim().is_empty())
+            .map(parse)
+            .collect();
+        Ok(Datagram { sentences })
+    }
+
+    pub fn encode(sentences: &[TaggedSentence]) -> Vec<u8> {
+        let mut datagram = HEADER.to_vec();
+        for sentence in sentences {
+            datagram.extend_from_slice(sentence.to_sentence().as_bytes());
+        }
+        datagram
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SequenceStatus {
+    First,
+    InOrder,
+    Missed(u32),
+    Repeated,
+}
+
+#[derive(Debug, Default)]
+pub struct SequenceTracker {
+    last: HashMap<String, u32>,
+}
+
+impl SequenceTracker {
+    pub fn new() -> SequenceTracker {
+        SequenceTracker::default()
+    }
+
+    pub fn observe(&mut self, source: &str, line_count: u32) -> SequenceStatus {
+        match self.last.insert(source.to_string(), line_count) {
+            None => SequenceStatus::First,
+            Some(last) if last == line_count => SequenceStatus::Repeated,
+            Some(last) => {
+                let expected = last % MAX_LINE_COUNT + 1;
+                if line_count == expected {
+                    SequenceStatus::InOrder
+                } else {
+                    let missed = (line_count + MAX_LINE_COUNT - expected) % MAX_LINE_COUNT;
+                    SequenceStatus::Missed(missed)
+                }
+            }
+        }
+    }
+
+    pub fn forget(&mut self, source: &str) {
+        self.last.remove(source);
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct GroupAssembler {
+    pending: HashMap<(Option<String>, u32), Vec<TaggedSentence>>,
+}
+
+impl GroupAssembler {
+    pub fn new() -> GroupAssembler {
+        GroupAssembler::default()
+    }
+
+    pub fn push(&mut self, sentence: TaggedSentence) -> Option<Vec<TaggedSentence>> {
+        let tag_block = sentence.tag_block.as_ref();
+        let group = match tag_block.and_then(|t| t.group) {
+            Some(group) if group.total_sentences > 1 => group,
+            _ => return Some(vec![sentence]),
+        };
+        let key = (tag_block.and_then(|t| t.source.clone()), group.group_id);
+        if group.sentence_number == 1 {
+            self.pending.remove(&key);
+        }
+        let parts = self.pending.entry(key.clone()).or_default();
+        if parts.len() + 1 != group.sentence_number {
+            self.pending.remove(&key);
+            return None;
+        }
+        parts.push(sentence);
+        if parts.len() == group.total_sentences {
+            self.pending.remove(&key)
+        } else {
+            None
+        }
+    }
+
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+#[derive(Debug)]
+pub struct Received {
+    pub sentence: Result<TaggedSentence, NmeaError>,
+    pub sequence: Option<SequenceStatus>,
+    pub from: SocketAddr,
+}
+
+#[derive(Debug)]
+pub struct Receiver {
+    socket: UdpSocket,
+    identity: Option<String>,
+    policy: ChecksumPolicy,
+    registry: Option<Arc<DecoderRegistry>>,
+    tracker: SequenceTracker,
+}
+
+impl Receiver {
+    pub fn new(socket: UdpSocket) -> Receiver {
+        Receiver {
+            socket,
+            identity: None,
+            policy: ChecksumPolicy::default(),
+            registry: None,
+            tracker: SequenceTracker::new(),
+        }
+    }
+
+    pub fn join(group: Ipv4Addr, port: u16, interface: Ipv4Addr) -> Result<Receiver, NmeaError> {
+        let socket = UdpSocket::bind(SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, port))?;
+        socket.join_multicast_v4(&group, &interface)?;
+        Ok(Receiver::new(socket))
+    }
+
+    pub fn identity(mut self, identity: &str) -> Self {
+        self.identity = Some(String::from(identity));
+        self
+    }
+
+    pub fn checksum_policy(mut self, policy: ChecksumPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    pub fn registry(mut self, registry: Arc<DecoderRegistry>) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
+    pub fn socket(&self) -> &UdpSocket {
+        &self.socket
+    }
+
+    pub fn recv(&mut self) -> Result<Vec<Received>, NmeaError> {
+        let mut buffer = [0u8; MAX_DATAGRAM];
+        let (length, from) = self.socket.recv_from(&mut buffer)?;
+        let datagram = match &self.registry {
+            Some(registry) => {
+                Datagram::from_bytes_with_registry(&buffer[..length], self.policy, registry)?
+            }
+            None => Datagram::from_bytes_with(&buffer[..length], self.policy)?,
+        };
+        let mut received = Vec::new();
+        for sentence in datagram.sentences {
+            let sentence = match sentence {
+                Ok(sentence) => sentence,
+                Err(error) => {
+                    received.push(Received {
+                        sentence: Err(error),
+                        sequence: None,
+                        from,
+                    });
+                    continue;
+                }
+            };
+            let tag_block = sentence.tag_block.as_ref();
+            let destination = tag_block.and_then(|t| t.destination.as_deref());
+            if let (Some(destination), Some(identity)) = (destination, &self.identity) {
+                if destination != identity {
+                    continue;
+                }
+            }
+            let sequence = match tag_block.map(|t| (&t.source, t.line_count)) {
+                Some((Some(source), Some(n))) => Some(self.tracker.observe(source, n)),
+                _ => None,
+            };
+            received.push(Received {
+                sentence: Ok(sentence),
+                sequence,
+                from,
+            });
+        }
+        Ok(received)
+    }
+}
+
+#[derive(Debug)]
+pub struct Sender {
+    socket: UdpSocket,
+    destination: SocketAddr,
+    source: String,
+    line_count: u32,
+    group_id: u32,
+}
+
+impl Sender {
+    pub fn new(socket: UdpSocket, destination: SocketAddr, source: &str) -> Sender {
+        Sender {
+            socket,
+            destination,
+            source: String::from(source),
+            line_count: 0,
+            group_id: 0,
+        }
+    }
+
+    pub fn multicast(
+        group: Ipv4Addr,
+        port: u16,
+        interface: Ipv4Addr,
+        source: &str,
+    ) -> Result<Sender, NmeaError> {
+        let socket = UdpSocket::bind(SocketAddrV4::new(interface, 0))?;
+        socket.set_multicast_loop_v4(true)?;
+        let destination = SocketAddr::V4(SocketAddrV4::new(group, port));
+        Ok(Sender::new(socket, destination, source))
+    }
+
+    pub fn socket(&self) -> &UdpSocket {
+        &self.socket
+    }
+
+    pub fn send(&mut self, sentence: Nmea0183) -> Result<(), NmeaError> {
+        let line_count = self.line_count;
+        let tag_block = self.tag_block(None);
+        let datagram = Datagram::encode(&[TaggedSentence::new(tag_block, sentence)]);
+        if datagram.len() > MAX_DATAGRAM {
+            self.line_count = line_count;
+            return Err(NmeaError::DatagramTooLarge(datagram.len()));
+        }
+        self.socket.send_to(&datagram, self.destination)?;
+        Ok(())
+    }
+
+    pub fn send_group(&mut self, sentences: Vec<Nmea0183>) -> Result<(), NmeaError> {
+        let (line_count, group_id) = (self.line_count, self.group_id);
+        self.group_id = self.group_id % MAX_GROUP_ID + 1;
+        let total_sentences = sentences.len();
+        let mut tagged = Vec::new();
+        for (index, sentence) in sentences.into_iter().enumerate() {
+            let group = Group {
+                sentence_number: index + 1,
+                total_sentences,
+                group_id: self.group_id,
+            };
+            tagged.push(TaggedSentence::new(self.tag_block(Some(group)), sentence));
+        }
+        let datagram = Datagram::encode(&tagged);
+        if datagram.len() > MAX_DATAGRAM {
+            self.line_count = line_count;
+            self.group_id = group_id;
+            return Err(NmeaError::DatagramTooLarge(datagram.len()));
+        }
+        self.socket.send_to(&datagram, self.destination)?;
+        Ok(())
+    }
+
+    fn tag_block(&mut self, group: Option<Group>) -> TagBlock {
+        self.line_count = self.line_count % MAX_LINE_COUNT + 1;
+        TagBlock {
+            source: Some(self.source.clone()),
+            line_count: Some(self.line_count),
+            group,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn hdt() -> Nmea0183 {
+        Nmea0183::from_string(&String::from("$HEHDT,274.07,T*19")).unwrap()
+    }
+
+    fn loopback() -> (Sender, Receiver) {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        socket
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        let destination = socket.local_addr().unwrap();
+        let sender = Sender::new(
+            UdpSocket::bind("127.0.0.1:0").unwrap(),
+            destination,
+            "GP0001",
+        );
+        (sender, Receiver::new(socket).identity("EI0001"))
+    }
+
+    #[test]
+    fn sends_groups_over_loopback() {
+        let (mut sender, mut receiver) = loopback();
+        sender.send(hdt()).unwrap();
+        sender.send_group(vec![hdt(), hdt()]).unwrap();
+
+        let mut buffer = [0u8; MAX_DATAGRAM];
+        let (length, _) = receiver.socket().peek_from(&mut buffer).unwrap();
+        assert!(buffer[..length].starts_with(b"UdPbC\0\\s:GP0001,n:1*"));
+
+        let received = receiver.recv().unwrap();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].sequence, Some(SequenceStatus::First));
+
+        let received = receiver.recv().unwrap();
+        assert_eq!(received.len(), 2);
+        let mut assembler = GroupAssembler::new();
+        let mut group = None;
+        for (index, r) in received.into_iter().enumerate() {
+            assert_eq!(r.sequence, Some(SequenceStatus::InOrder));
+            let sentence = r.sentence.unwrap();
+            let tag_block = sentence.tag_block.as_ref().unwrap();
+            assert_eq!(tag_block.source.as_deref(), Some("GP0001"));
+            assert_eq!(tag_block.line_count, Some(index as u32 + 2));
+            assert_eq!(
+                tag_block.group,
+                Some(Group {
+                    sentence_number: index + 1,
+                    total_sentences: 2,
+                    group_id: 1,
+                })
+            );
+            group = assembler.push(sentence);
+        }
+        assert_eq!(group.unwrap().len(), 2);
+        assert_eq!(assembler.pending(), 0);
+    }
+
+    #[test]
+    fn rejects_oversized_groups() {
+        let (mut sender, mut receiver) = loopback();
+        let sentences = (0..60).map(|_| hdt()).collect();
+        assert!(matches!(
+            sender.send_group(sentences),
+            Err(NmeaError::DatagramTooLarge(length)) if length > MAX_DATAGRAM
+        ));
+        sender.send(hdt()).unwrap();
+        let received = receiver.recv().unwrap();
+        let sentence = received[0].sentence.as_ref().unwrap();
+        assert_eq!(sentence.tag_block.as_ref().unwrap().line_count, Some(1));
+    }
+
+    #[test]
+    fn tracks_line_count_sequence() {
+        let mut tracker = SequenceTracker::new();
+        assert_eq!(tracker.observe("GP0001", 998), SequenceStatus::First);
+        assert_eq!(tracker.observe("GP0001", 999), SequenceStatus::InOrder);
+        assert_eq!(tracker.observe("GP0001", 1), SequenceStatus::InOrder);
+        assert_eq!(tracker.observe("GP0001", 1), SequenceStatus::Repeated);
+        assert_eq!(tracker.observe("GP0001", 4), SequenceStatus::Missed(2));
+        assert_eq!(tracker.observe("EI0001", 4), SequenceStatus::First);
+        tracker.forget("GP0001");
+        assert_eq!(tracker.observe("GP0001", 5), SequenceStatus::First);
+    }
+
+    #[test]
+    fn filters_by_destination_and_header() {
+        let mut datagram = HEADER.to_vec();
+        datagram.extend_from_slice(b"\\d:EI0002*50\\$HEHDT,274.07,T*19\r\n$HEHDT,274.07,T*18\r\n");
+        let parsed = Datagram::from_bytes(&datagram).unwrap();
+        assert_eq!(parsed.sentences.len(), 2);
+        assert!(parsed.sentences[1].is_err());
+        assert!(matches!(
+            Datagram::from_bytes(b"RaUdP\0$HEHDT,274.07,T*19"),
+            Err(NmeaError::UnknownHeader)
+        ));
+
+        let (_, mut receiver) = loopback();
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let address = receiver.socket().local_addr().unwrap();
+        socket.send_to(&datagram, address).unwrap();
+        let mut accepted = HEADER.to_vec();
+        accepted.extend_from_slice(b"$HEHDT,274.07,T*19\r\n");
+        socket.send_to(&accepted, address).unwrap();
+        let received = receiver.recv().unwrap();
+        assert_eq!(received.len(), 1);
+        assert!(matches!(
+            received[0].sentence,
+            Err(NmeaError::BadChecksum { .. })
+        ));
+        let received = receiver.recv().unwrap();
+        assert_eq!(received.len(), 1);
+        assert!(received[0].sentence.is_ok());
+        assert_eq!(received[0].sequence, None);
+    }
+}
@@ -1,50 +1,144 @@
 use crate::base::*;
+use crate::inspect::Inspect;
 
 #[derive(Debug)]
-pub struct Mwd {
-    base: Nmea0183Base,
+pub struct Mwd<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Mwd {
-    pub fn new(base: Nmea0183Base) -> Mwd {
+    pub fn builder() -> MwdBuilder {
+        MwdBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Mwd<B> {
+    pub fn new(base: B) -> Mwd<B> {
         Mwd { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
     pub fn direction_true(&self) -> F32Error {
-        if self.base.parameters[1] == "T" {
+        if self.base.field_str(1) == "T" {
             self.base.parameter(0)
-        } else if self.base.parameters[3] == "T" {
+        } else if self.base.field_str(3) == "T" {
             self.base.parameter(2)
         } else {
-            Err(NmeaError("Not found".to_string()))
+            Err(NmeaError::NotPresent)
         }
     }
 
     pub fn direction_magnetic(&self) -> F32Error {
-        if self.base.parameters[1] == "M" {
+        if self.base.field_str(1) == "M" {
             self.base.parameter(0)
-        } else if self.base.parameters[3] == "M" {
+        } else if self.base.field_str(3) == "M" {
             self.base.parameter(2)
         } else {
-            Err(NmeaError("Not found".to_string()))
+            Err(NmeaError::NotPresent)
         }
     }
 
     pub fn wind_speed(&self) -> Result<Speed, NmeaError> {
-        if self.base.parameters[5] == "K" {
+        if self.base.field_str(5) == "K" {
             Ok(Speed::from_kph(self.base.parameter(4)?))
-        } else if self.base.parameters[7] == "K" {
+        } else if self.base.field_str(7) == "K" {
             Ok(Speed::from_kph(self.base.parameter(6)?))
         } else {
-            match self.base.parameters[5].as_str() {
+            match self.base.field_str(5) {
                 "M" => Ok(Speed::from_mph(self.base.parameter(4)?)),
                 "N" => Ok(Speed::from_knots(self.base.parameter(4)?)),
-                _ => match self.base.parameters[7].as_str() {
+                _ => match self.base.field_str(7) {
                     "M" => Ok(Speed::from_mph(self.base.parameter(6)?)),
                     "N" => Ok(Speed::from_knots(self.base.parameter(6)?)),
-                    _ => Err(NmeaError("Not found".to_string())),
+                    _ => Err(self.base.unknown_units(5)),
                 },
             }
         }
     }
 }
+
+impl<B: SentenceFields> Inspect for Mwd<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Mwd<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Mwd", 4)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("direction_true", &self.direction_true().ok().map(Degrees))?;
+        state.serialize_field(
+            "direction_magnetic",
+            &self.direction_magnetic().ok().map(Degrees),
+        )?;
+        state.serialize_field("wind_speed", &self.wind_speed().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct MwdBuilder {
+    talker: String,
+    direction_true: Option<f32>,
+    direction_magnetic: Option<f32>,
+    wind_speed: Option<Speed>,
+}
+
+#[cfg(feature = "std")]
+impl MwdBuilder {
+    pub fn new() -> MwdBuilder {
+        MwdBuilder {
+            talker: String::from("WI"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn direction_true(mut self, direction_true: f32) -> Self {
+        self.direction_true = Some(direction_true);
+        self
+    }
+
+    pub fn direction_magnetic(mut self, direction_magnetic: f32) -> Self {
+        self.direction_magnetic = Some(direction_magnetic);
+        self
+    }
+
+    pub fn wind_speed(mut self, wind_speed: Speed) -> Self {
+        self.wind_speed = Some(wind_speed);
+        self
+    }
+
+    pub fn build(self) -> Mwd {
+        let parameters = vec![
+            format_float(self.direction_true, 1),
+            String::from("T"),
+            format_float(self.direction_magnetic, 1),
+            String::from("M"),
+            format_float(self.wind_speed.map(|s| s.as_knots()), 1),
+            String::from("N"),
+            format_float(self.wind_speed.map(|s| s.as_mps()), 1),
+            String::from("M"),
+        ];
+        Mwd::new(Nmea0183Base::from_parts(&self.talker, "MWD", parameters))
+    }
+}
@@ -1,49 +1,163 @@
 use crate::base::*;
+use crate::inspect::Inspect;
 
 #[derive(Debug)]
-pub struct Mwv {
-    base: Nmea0183Base,
+pub struct Mwv<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Mwv {
-    pub fn new(base: Nmea0183Base) -> Mwv {
+    pub fn builder() -> MwvBuilder {
+        MwvBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Mwv<B> {
+    pub fn new(base: B) -> Mwv<B> {
         Mwv { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
     pub fn angle_relative(&self) -> F32Error {
-        if self.base.parameters[4] == "A" {
-            if self.base.parameters[1] == "R" {
+        if self.base.field_str(4) == "A" {
+            if self.base.field_str(1) == "R" {
                 self.base.parameter(0)
             } else {
-                Err(NmeaError("Not found.".to_string()))
+                Err(NmeaError::NotPresent)
             }
         } else {
-            Err(NmeaError("Data not valid.".to_string()))
+            Err(NmeaError::DataInvalid)
         }
     }
 
     pub fn angle_true(&self) -> F32Error {
-        if self.base.parameters[4] == "A" {
-            if self.base.parameters[1] == "T" {
+        if self.base.field_str(4) == "A" {
+            if self.base.field_str(1) == "T" {
                 self.base.parameter(0)
             } else {
-                Err(NmeaError("Not found.".to_string()))
+                Err(NmeaError::NotPresent)
             }
         } else {
-            Err(NmeaError("Data not valid.".to_string()))
+            Err(NmeaError::DataInvalid)
         }
     }
 
     pub fn wind_speed(&self) -> Result<Speed, NmeaError> {
-        if self.base.parameters[4] == "A" {
-            match self.base.parameters[3].as_str() {
+        if self.base.field_str(4) == "A" {
+            match self.base.field_str(3) {
                 "M" => Ok(Speed::from_mph(self.base.parameter(2)?)),
                 "N" => Ok(Speed::from_knots(self.base.parameter(2)?)),
                 "K" => Ok(Speed::from_kph(self.base.parameter(2)?)),
-                _ => Err(NmeaError("Not found".to_string())),
+                _ => Err(self.base.unknown_units(3)),
             }
         } else {
-            Err(NmeaError("Invalid data".to_string()))
+            Err(NmeaError::DataInvalid)
+        }
+    }
+}
+
+impl<B: SentenceFields> Inspect for Mwv<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Mwv<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Mwv", 4)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("angle_relative", &self.angle_relative().ok().map(Degrees))?;
+        state.serialize_field("angle_true", &self.angle_true().ok().map(Degrees))?;
+        state.serialize_field("wind_speed", &self.wind_speed().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct MwvBuilder {
+    talker: String,
+    angle: Option<f32>,
+    reference: char,
+    wind_speed: Option<Speed>,
+    valid: bool,
+}
+
+#[cfg(feature = "std")]
+impl MwvBuilder {
+    pub fn new() -> MwvBuilder {
+        MwvBuilder {
+            talker: String::from("WI"),
+            reference: 'R',
+            ..Default::default()
         }
     }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn angle_relative(mut self, angle: f32) -> Self {
+        self.angle = Some(angle);
+        self.reference = 'R';
+        self
+    }
+
+    pub fn angle_true(mut self, angle: f32) -> Self {
+        self.angle = Some(angle);
+        self.reference = 'T';
+        self
+    }
+
+    pub fn wind_speed(mut self, wind_speed: Speed) -> Self {
+        self.wind_speed = Some(wind_speed);
+        self
+    }
+
+    pub fn valid(mut self, valid: bool) -> Self {
+        self.valid = valid;
+        self
+    }
+
+    pub fn build(self) -> Mwv {
+        let parameters = vec![
+            format_float(self.angle, 1),
+            self.reference.to_string(),
+            format_float(self.wind_speed.map(|s| s.as_knots()), 1),
+            String::from("N"),
+            String::from(if self.valid { "A" } else { "V" }),
+        ];
+        Mwv::new(Nmea0183Base::from_parts(&self.talker, "MWV", parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_sets_reference() {
+        let mwv = Mwv::builder()
+            .angle_true(214.8)
+            .wind_speed(Speed::from_knots(10.0))
+            .valid(true)
+            .build();
+        assert_eq!(mwv.to_sentence(), "$WIMWV,214.8,T,10.0,N,A*1B\r\n");
+        assert_eq!(mwv.angle_true().unwrap(), 214.8);
+        assert!(mwv.angle_relative().is_err());
+    }
 }
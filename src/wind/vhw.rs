@@ -1,50 +1,144 @@
 use crate::base::*;
+use crate::inspect::Inspect;
 
 #[derive(Debug)]
-pub struct Vhw {
-    base: Nmea0183Base,
+pub struct Vhw<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Vhw {
-    pub fn new(base: Nmea0183Base) -> Vhw {
+    pub fn builder() -> VhwBuilder {
+        VhwBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Vhw<B> {
+    pub fn new(base: B) -> Vhw<B> {
         Vhw { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
     pub fn heading_true(&self) -> F32Error {
-        if self.base.parameters[1] == "T" {
+        if self.base.field_str(1) == "T" {
             self.base.parameter(0)
-        } else if self.base.parameters[3] == "T" {
+        } else if self.base.field_str(3) == "T" {
             self.base.parameter(2)
         } else {
-            Err(NmeaError("Not found.".to_string()))
+            Err(NmeaError::NotPresent)
         }
     }
 
     pub fn heading_magnetic(&self) -> F32Error {
-        if self.base.parameters[1] == "M" {
+        if self.base.field_str(1) == "M" {
             self.base.parameter(0)
-        } else if self.base.parameters[3] == "M" {
+        } else if self.base.field_str(3) == "M" {
             self.base.parameter(2)
         } else {
-            Err(NmeaError("Not found.".to_string()))
+            Err(NmeaError::NotPresent)
         }
     }
 
     pub fn water_speed(&self) -> Result<Speed, NmeaError> {
-        if self.base.parameters[5] == "K" {
+        if self.base.field_str(5) == "K" {
             Ok(Speed::from_kph(self.base.parameter(4)?))
-        } else if self.base.parameters[7] == "K" {
+        } else if self.base.field_str(7) == "K" {
             Ok(Speed::from_kph(self.base.parameter(6)?))
         } else {
-            match self.base.parameters[5].as_str() {
+            match self.base.field_str(5) {
                 "M" => Ok(Speed::from_mph(self.base.parameter(4)?)),
                 "N" => Ok(Speed::from_knots(self.base.parameter(4)?)),
-                _ => match self.base.parameters[7].as_str() {
+                _ => match self.base.field_str(7) {
                     "M" => Ok(Speed::from_mph(self.base.parameter(6)?)),
                     "N" => Ok(Speed::from_knots(self.base.parameter(6)?)),
-                    _ => Err(NmeaError("Not found".to_string())),
+                    _ => Err(self.base.unknown_units(5)),
                 },
             }
         }
     }
 }
+
+impl<B: SentenceFields> Inspect for Vhw<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Vhw<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Vhw", 4)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("heading_true", &self.heading_true().ok().map(Degrees))?;
+        state.serialize_field(
+            "heading_magnetic",
+            &self.heading_magnetic().ok().map(Degrees),
+        )?;
+        state.serialize_field("water_speed", &self.water_speed().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct VhwBuilder {
+    talker: String,
+    heading_true: Option<f32>,
+    heading_magnetic: Option<f32>,
+    water_speed: Option<Speed>,
+}
+
+#[cfg(feature = "std")]
+impl VhwBuilder {
+    pub fn new() -> VhwBuilder {
+        VhwBuilder {
+            talker: String::from("VW"),
+            ..Default::default()
+        }
+    }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn heading_true(mut self, heading_true: f32) -> Self {
+        self.heading_true = Some(heading_true);
+        self
+    }
+
+    pub fn heading_magnetic(mut self, heading_magnetic: f32) -> Self {
+        self.heading_magnetic = Some(heading_magnetic);
+        self
+    }
+
+    pub fn water_speed(mut self, water_speed: Speed) -> Self {
+        self.water_speed = Some(water_speed);
+        self
+    }
+
+    pub fn build(self) -> Vhw {
+        let parameters = vec![
+            format_float(self.heading_true, 1),
+            String::from("T"),
+            format_float(self.heading_magnetic, 1),
+            String::from("M"),
+            format_float(self.water_speed.map(|s| s.as_knots()), 1),
+            String::from("N"),
+            format_float(self.water_speed.map(|s| s.as_kph()), 1),
+            String::from("K"),
+        ];
+        Vhw::new(Nmea0183Base::from_parts(&self.talker, "VHW", parameters))
+    }
+}
@@ -1,20 +1,100 @@
 use crate::base::*;
+use crate::inspect::Inspect;
 
 #[derive(Debug)]
-pub struct Vwr {
-    base: Nmea0183Base,
+pub struct Vwr<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Vwr {
-    pub fn new(base: Nmea0183Base) -> Vwr {
+    pub fn builder() -> VwrBuilder {
+        VwrBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Vwr<B> {
+    pub fn new(base: B) -> Vwr<B> {
         Vwr { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
     pub fn wind_direction(&self) -> F32Error {
-        match self.base.parameters[1].as_str() {
-            "L" => Ok(-(self.base.parameter::<f32>(0)).unwrap()),
+        match self.base.field_str(1) {
+            "L" => Ok(-self.base.parameter::<f32>(0)?),
             "R" => self.base.parameter(0),
-            _ => Err(NmeaError("Invalid data".to_string())),
+            _ => Err(self.base.invalid_field(1)),
+        }
+    }
+}
+
+impl<B: SentenceFields> Inspect for Vwr<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Vwr<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Vwr", 2)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("wind_direction", &self.wind_direction().ok().map(Degrees))?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct VwrBuilder {
+    talker: String,
+    wind_direction: Option<f32>,
+    wind_speed: Option<Speed>,
+}
+
+#[cfg(feature = "std")]
+impl VwrBuilder {
+    pub fn new() -> VwrBuilder {
+        VwrBuilder {
+            talker: String::from("WI"),
+            ..Default::default()
         }
     }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn wind_direction(mut self, wind_direction: f32) -> Self {
+        self.wind_direction = Some(wind_direction);
+        self
+    }
+
+    pub fn wind_speed(mut self, wind_speed: Speed) -> Self {
+        self.wind_speed = Some(wind_speed);
+        self
+    }
+
+    pub fn build(self) -> Vwr {
+        let mut parameters = Vec::from(format_signed(self.wind_direction, 1, 'R', 'L'));
+        parameters.push(format_float(self.wind_speed.map(|s| s.as_knots()), 1));
+        parameters.push(String::from("N"));
+        parameters.push(format_float(self.wind_speed.map(|s| s.as_mps()), 1));
+        parameters.push(String::from("M"));
+        parameters.push(format_float(self.wind_speed.map(|s| s.as_kph()), 1));
+        parameters.push(String::from("K"));
+        Vwr::new(Nmea0183Base::from_parts(&self.talker, "VWR", parameters))
+    }
 }
@@ -1,38 +1,119 @@
 use crate::base::*;
+use crate::inspect::Inspect;
 
 #[derive(Debug)]
-pub struct Vwt {
-    base: Nmea0183Base,
+pub struct Vwt<B = DefaultFields> {
+    base: B,
 }
 
+#[cfg(feature = "std")]
 impl Vwt {
-    pub fn new(base: Nmea0183Base) -> Vwt {
+    pub fn builder() -> VwtBuilder {
+        VwtBuilder::new()
+    }
+}
+
+impl<B: SentenceFields> Vwt<B> {
+    pub fn new(base: B) -> Vwt<B> {
         Vwt { base }
     }
 
+    pub fn base(&self) -> &B {
+        &self.base
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_sentence(&self) -> String {
+        self.base.to_sentence()
+    }
+
     pub fn wind_direction(&self) -> F32Error {
-        match self.base.parameters[1].as_str() {
-            "L" => Ok(-(self.base.parameter::<f32>(0)).unwrap()),
+        match self.base.field_str(1) {
+            "L" => Ok(-self.base.parameter::<f32>(0)?),
             "R" => self.base.parameter(0),
-            _ => Err(NmeaError("Invalid data".to_string())),
+            _ => Err(self.base.invalid_field(1)),
         }
     }
 
     pub fn wind_speed(&self) -> Result<Speed, NmeaError> {
-        if self.base.parameters[3] == "M" {
+        if self.base.field_str(3) == "M" {
             Ok(Speed::from_mps(self.base.parameter::<f32>(2)?))
-        } else if self.base.parameters[5] == "M" {
+        } else if self.base.field_str(5) == "M" {
             Ok(Speed::from_mps(self.base.parameter::<f32>(4)?))
-        } else if self.base.parameters[7] == "M" {
+        } else if self.base.field_str(7) == "M" {
             Ok(Speed::from_mps(self.base.parameter::<f32>(6)?))
-        } else if self.base.parameters[3] == "K" {
+        } else if self.base.field_str(3) == "K" {
             Ok(Speed::from_kph(self.base.parameter(2)?))
-        } else if self.base.parameters[5] == "K" {
+        } else if self.base.field_str(5) == "K" {
             Ok(Speed::from_kph(self.base.parameter::<f32>(4)?))
-        } else if self.base.parameters[7] == "K" {
+        } else if self.base.field_str(7) == "K" {
             Ok(Speed::from_kph(self.base.parameter::<f32>(6)?))
         } else {
-            Err(NmeaError("Invalid data".to_string()))
+            Err(self.base.unknown_units(3))
+        }
+    }
+}
+
+impl<B: SentenceFields> Inspect for Vwt<B> {
+    type Fields = B;
+
+    fn fields(&self) -> &B {
+        &self.base
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<B: SentenceFields> serde::Serialize for Vwt<B> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Vwt", 3)?;
+        state.serialize_field("talker", &self.base.sender())?;
+        state.serialize_field("wind_direction", &self.wind_direction().ok().map(Degrees))?;
+        state.serialize_field("wind_speed", &self.wind_speed().ok())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct VwtBuilder {
+    talker: String,
+    wind_direction: Option<f32>,
+    wind_speed: Option<Speed>,
+}
+
+#[cfg(feature = "std")]
+impl VwtBuilder {
+    pub fn new() -> VwtBuilder {
+        VwtBuilder {
+            talker: String::from("WI"),
+            ..Default::default()
         }
     }
+
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn wind_direction(mut self, wind_direction: f32) -> Self {
+        self.wind_direction = Some(wind_direction);
+        self
+    }
+
+    pub fn wind_speed(mut self, wind_speed: Speed) -> Self {
+        self.wind_speed = Some(wind_speed);
+        self
+    }
+
+    pub fn build(self) -> Vwt {
+        let mut parameters = Vec::from(format_signed(self.wind_direction, 1, 'R', 'L'));
+        parameters.push(format_float(self.wind_speed.map(|s| s.as_knots()), 1));
+        parameters.push(String::from("N"));
+        parameters.push(format_float(self.wind_speed.map(|s| s.as_mps()), 1));
+        parameters.push(String::from("M"));
+        parameters.push(format_float(self.wind_speed.map(|s| s.as_kph()), 1));
+        parameters.push(String::from("K"));
+        Vwt::new(Nmea0183Base::from_parts(&self.talker, "VWT", parameters))
+    }
 }